@@ -0,0 +1,272 @@
+//! Derive macros for [`bankarr`](https://docs.rs/bankarr), re-exported by it
+//! under the `derive` feature.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Fields, parse_macro_input};
+
+
+/// Derives a structure-of-arrays bank for a struct with named fields.
+/// 
+/// For a struct `Row`, generates `RowBank<const C: usize>`, holding up to `C`
+/// rows as one [`BankArr`] per field, so a loop over a single field runs over
+/// contiguous memory.  The columns are only lent out as slices, keeping their
+/// lengths in step, while rows are pushed and removed whole:
+/// 
+/// * `new`, `len`, `is_empty` and `capacity`, as on a `BankArr`.
+/// * `push`, `try_push`, `pop`, `remove`, `swap_remove` and `clear`, moving
+///   whole `Row`s in and out.
+/// * A `field()` and `field_mut()` accessor per field, returning its column.
+/// * `get`, `get_mut`, `iter` and `iter_mut`, yielding a `RowRef<'_>` or
+///   `RowMut<'_>` of references to each field of a row.
+/// * `Default`, `Extend<Row>` and `FromIterator<Row>`.
+/// 
+/// The generated types share the struct's visibility, and the accessors and
+/// reference fields each share their field's.  The struct can't be generic or
+/// implement [`Drop`], as rows are split into their fields.
+/// 
+/// [`BankArr`]: https://docs.rs/bankarr/latest/bankarr/struct.BankArr.html
+/// 
+/// # Examples
+/// ```
+/// use bankarr::BankSoA;
+/// 
+/// #[derive(BankSoA, Debug, PartialEq)]
+/// struct Particle {
+///     pos: [f32; 2],
+///     vel: [f32; 2],
+///     ttl: u8,
+/// }
+/// 
+/// let mut particles = ParticleBank::<64>::new();
+/// particles.push(Particle { pos: [0.0, 0.0], vel: [1.0, 2.0], ttl: 1 });
+/// particles.push(Particle { pos: [5.0, 5.0], vel: [0.0, -1.0], ttl: 3 });
+/// 
+/// for p in particles.iter_mut() {
+///     p.pos[0] += p.vel[0];
+///     p.pos[1] += p.vel[1];
+/// }
+/// assert_eq!(particles.pos(), [[1.0, 2.0], [5.0, 4.0]]);
+/// 
+/// // Expire particles, moving the last into each freed row
+/// particles.ttl_mut().iter_mut().for_each(|ttl| *ttl -= 1);
+/// let expired = particles.swap_remove(0);
+/// assert_eq!(expired.ttl, 0);
+/// assert_eq!(particles.get(0).map(|p| *p.ttl), Some(2));
+/// ```
+/// 
+/// Only structs with named fields are supported.
+/// ```compile_fail
+/// use bankarr::BankSoA;
+/// 
+/// #[derive(BankSoA)]
+/// struct Point(f32, f32);
+/// ```
+#[proc_macro_derive(BankSoA)]
+pub fn derive_bank_soa(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    bank_soa(input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+fn bank_soa(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) if !fields.named.is_empty() => &fields.named,
+            _ => return Err(Error::new_spanned(&input.ident, "`BankSoA` requires a struct with named fields")),
+        },
+        _ => return Err(Error::new_spanned(&input.ident, "`BankSoA` can only be derived for structs")),
+    };
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(&input.generics, "`BankSoA` doesn't support generic structs"));
+    }
+
+    let vis = &input.vis;
+    let row = &input.ident;
+    let bank = format_ident!("{row}Bank");
+    let row_ref = format_ident!("{row}Ref");
+    let row_mut = format_ident!("{row}Mut");
+
+    let names: Vec<_> = fields.iter().map(|field| field.ident.as_ref().unwrap()).collect();
+    let names_mut: Vec<_> = names.iter().map(|name| format_ident!("{name}_mut")).collect();
+    let types: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+    let field_vis: Vec<_> = fields.iter().map(|field| &field.vis).collect();
+    let first = names[0];
+
+    // Zips every column's iterator together, destructuring the nested pairs
+    // back into one binding per field
+    let zipped = |method: TokenStream2| {
+        let mut iter = quote!(self.#first.#method());
+        let mut pat = quote!(#first);
+        for name in &names[1..] {
+            iter = quote!(#iter.zip(self.#name.#method()));
+            pat = quote!((#pat, #name));
+        }
+        (iter, pat)
+    };
+    let (iter, pat) = zipped(quote!(iter));
+    let (iter_mut, pat_mut) = zipped(quote!(iter_mut));
+
+    let bank_doc = format!("A structure-of-arrays bank of up to `C` [`{row}`]s, one column per field.");
+    let ref_doc = format!("References to the fields of a row in a [`{bank}`].");
+    let mut_doc = format!("Mutable references to the fields of a row in a [`{bank}`].");
+    let column_docs: Vec<_> = names.iter().map(|name| format!("Returns the `{name}` column.")).collect();
+    let column_mut_docs: Vec<_> = names.iter().map(|name| format!("Returns the `{name}` column as a mutable slice.")).collect();
+
+    Ok(quote! {
+        #[doc = #bank_doc]
+        #vis struct #bank<const C: usize> {
+            #(#names: ::bankarr::BankArr<#types, C>,)*
+        }
+
+        #[doc = #ref_doc]
+        #[derive(Clone, Copy)]
+        #[allow(dead_code)]
+        #vis struct #row_ref<'a> {
+            #(#field_vis #names: &'a #types,)*
+        }
+
+        #[doc = #mut_doc]
+        #[allow(dead_code)]
+        #vis struct #row_mut<'a> {
+            #(#field_vis #names: &'a mut #types,)*
+        }
+
+        impl<const C: usize> ::core::default::Default for #bank<C> {
+            #[inline]
+            fn default() -> Self { Self::new() }
+        }
+
+        impl<const C: usize> ::core::iter::Extend<#row> for #bank<C> {
+            #[track_caller]
+            fn extend<I: ::core::iter::IntoIterator<Item = #row>>(&mut self, rows: I) {
+                rows.into_iter().for_each(|row| self.push(row));
+            }
+        }
+
+        impl<const C: usize> ::core::iter::FromIterator<#row> for #bank<C> {
+            #[track_caller]
+            fn from_iter<I: ::core::iter::IntoIterator<Item = #row>>(rows: I) -> Self {
+                let mut bank = Self::new();
+                bank.extend(rows);
+                bank
+            }
+        }
+
+        impl<const C: usize> #bank<C> {
+
+            /// Constructs a new, empty bank.
+            #[inline]
+            pub const fn new() -> Self {
+                Self { #(#names: ::bankarr::BankArr::new(),)* }
+            }
+
+            /// Returns the number of rows in the bank.
+            #[inline]
+            pub const fn len(&self) -> usize { self.#first.len() }
+
+            /// Returns `true` if the bank contains no rows.
+            #[inline]
+            pub const fn is_empty(&self) -> bool { self.#first.is_empty() }
+
+            /// Returns the number of rows the bank can hold, `C`.
+            #[inline]
+            pub const fn capacity(&self) -> usize { C }
+
+            #(
+                #[doc = #column_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #field_vis fn #names(&self) -> &[#types] { self.#names.as_slice() }
+
+                #[doc = #column_mut_docs]
+                #[inline]
+                #[allow(dead_code)]
+                #field_vis fn #names_mut(&mut self) -> &mut [#types] { self.#names.as_mut_slice() }
+            )*
+
+            /// Appends a row to the back of the bank.
+            /// 
+            /// # Panics
+            /// 
+            /// Panics if the bank is full.
+            #[inline]
+            #[track_caller]
+            pub fn push(&mut self, row: #row) {
+                if self.try_push(row).is_err() {
+                    panic!("capacity exceeded during operation `push`: capacity is {}", C);
+                }
+            }
+
+            /// Attempts to append a row to the back of the bank, handing it
+            /// back inside the error if the bank is full.
+            #[inline]
+            pub fn try_push(&mut self, row: #row) -> ::core::result::Result<(), ::bankarr::BankFullError<#row>> {
+                if self.len() == C { return Err(::bankarr::__private::bank_full(row)) }
+                let #row { #(#names,)* } = row;
+                #(self.#names.push(#names);)*
+                Ok(())
+            }
+
+            /// Removes the last row of the bank and returns it, or `None` if
+            /// it's empty.
+            #[inline]
+            pub fn pop(&mut self) -> ::core::option::Option<#row> {
+                Some(#row { #(#names: self.#names.pop()?,)* })
+            }
+
+            /// Removes and returns the row at `index`, shifting all rows after
+            /// it up by one.
+            /// 
+            /// # Panics
+            /// 
+            /// Panics if `index` is out of bounds.
+            #[track_caller]
+            pub fn remove(&mut self, index: usize) -> #row {
+                #row { #(#names: self.#names.remove(index),)* }
+            }
+
+            /// Removes and returns the row at `index`, replacing it with the
+            /// last row.
+            /// 
+            /// # Panics
+            /// 
+            /// Panics if `index` is out of bounds.
+            #[track_caller]
+            pub fn swap_remove(&mut self, index: usize) -> #row {
+                #row { #(#names: self.#names.swap_remove(index),)* }
+            }
+
+            /// Removes all rows from the bank, dropping them.
+            #[inline]
+            pub fn clear(&mut self) { #(self.#names.clear();)* }
+
+            /// Returns references to the fields of the row at `index`, or
+            /// `None` if it's out of bounds.
+            #[inline]
+            pub fn get(&self, index: usize) -> ::core::option::Option<#row_ref<'_>> {
+                Some(#row_ref { #(#names: self.#names.get(index)?,)* })
+            }
+
+            /// Returns mutable references to the fields of the row at `index`,
+            /// or `None` if it's out of bounds.
+            #[inline]
+            pub fn get_mut(&mut self, index: usize) -> ::core::option::Option<#row_mut<'_>> {
+                Some(#row_mut { #(#names: self.#names.get_mut(index)?,)* })
+            }
+
+            /// Returns an iterator over references to the fields of each row.
+            #[inline]
+            pub fn iter(&self) -> impl ::core::iter::DoubleEndedIterator<Item = #row_ref<'_>> + ::core::iter::ExactSizeIterator {
+                #iter.map(|#pat| #row_ref { #(#names,)* })
+            }
+
+            /// Returns an iterator over mutable references to the fields of
+            /// each row.
+            #[inline]
+            pub fn iter_mut(&mut self) -> impl ::core::iter::DoubleEndedIterator<Item = #row_mut<'_>> + ::core::iter::ExactSizeIterator {
+                #iter_mut.map(|#pat_mut| #row_mut { #(#names,)* })
+            }
+        }
+    })
+}
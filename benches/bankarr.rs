@@ -1,3 +1,5 @@
+#![allow(clippy::unit_arg, clippy::redundant_closure)]
+
 use std::hint::black_box;
 
 use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
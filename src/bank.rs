@@ -0,0 +1,244 @@
+
+use crate::{BankArr, BankVec, errors::{BankFullError, InsertError}};
+
+
+/// The vec-like operations shared by [`BankArr`] and [`BankVec`], so code can
+/// accept either a strict or a spilling bank.
+/// 
+/// Each method behaves as the type's own method of the same name.  The one
+/// real difference between the two shows in [`try_push`](Bank::try_push),
+/// which fails when a `BankArr` is full but only when a `BankVec` can't grow;
+/// either way the error is a [`BankFullError`] handing back the element.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::{Bank, BankArr, BankVec};
+/// 
+/// fn push_squares<B: Bank<u32>>(bank: &mut B, count: u32) {
+///     for i in 0..count {
+///         bank.push(i * i);
+///     }
+/// }
+/// 
+/// let mut strict = BankArr::<u32, 4>::new();
+/// push_squares(&mut strict, 4);
+/// 
+/// let mut spilling = BankVec::<u32, 4>::new();
+/// push_squares(&mut spilling, 6);
+/// 
+/// assert_eq!(strict.as_slice(), &spilling[..4]);
+/// ```
+pub trait Bank<T>: AsRef<[T]> + AsMut<[T]> {
+
+    /// The error returned by [`try_push`](Bank::try_push).
+    type PushError;
+
+    /// Returns the number of elements in the bank.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the bank contains no elements.
+    #[inline]
+    fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Returns the number of elements the bank can hold without spilling or,
+    /// for a spilled bank, reallocating.
+    fn capacity(&self) -> usize;
+
+    /// Extracts a slice containing the entire bank.
+    fn as_slice(&self) -> &[T];
+
+    /// Extracts a mutable slice of the entire bank.
+    fn as_mut_slice(&mut self) -> &mut [T];
+
+    /// Appends an element to the back of the bank.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if a `BankArr` is full, or a `BankVec` can't grow.
+    fn push(&mut self, value: T);
+
+    /// Attempts to append an element to the back of the bank.
+    fn try_push(&mut self, value: T) -> Result<(), Self::PushError>;
+
+    /// Removes the last element of the bank and returns it, or `None` if it's
+    /// empty.
+    fn pop(&mut self) -> Option<T>;
+
+    /// Inserts an element at `index`, shifting all elements after it to the
+    /// right.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index > len`, if a `BankArr` is full, or a `BankVec` can't
+    /// grow.
+    fn insert(&mut self, index: usize, value: T);
+
+    /// Attempts to insert an element at `index`, handing it back inside the
+    /// error on failure.
+    fn try_insert(&mut self, index: usize, value: T) -> Result<(), InsertError<T>>;
+
+    /// Removes and returns the element at `index`, shifting all elements after
+    /// it to the left.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index` is out of bounds.
+    fn remove(&mut self, index: usize) -> T;
+
+    /// Removes and returns the element at `index`, replacing it with the last
+    /// element.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index` is out of bounds.
+    fn swap_remove(&mut self, index: usize) -> T;
+
+    /// Shortens the bank to `len` elements, dropping the rest.  Has no effect
+    /// if `len` is greater than the bank's length.
+    fn truncate(&mut self, len: usize);
+
+    /// Removes all elements from the bank, dropping them.
+    #[inline]
+    fn clear(&mut self) { self.truncate(0) }
+}
+
+impl<T, const C: usize> Bank<T> for BankArr<T, C> {
+    type PushError = BankFullError<T>;
+
+    #[inline]
+    fn len(&self) -> usize { self.len() }
+
+    #[inline]
+    fn capacity(&self) -> usize { C }
+
+    #[inline]
+    fn as_slice(&self) -> &[T] { self.as_slice() }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] { self.as_mut_slice() }
+
+    #[inline]
+    #[track_caller]
+    fn push(&mut self, value: T) { self.push(value) }
+
+    #[inline]
+    fn try_push(&mut self, value: T) -> Result<(), BankFullError<T>> { self.try_push(value) }
+
+    #[inline]
+    fn pop(&mut self) -> Option<T> { self.pop() }
+
+    #[inline]
+    #[track_caller]
+    fn insert(&mut self, index: usize, value: T) {
+        assert!(self.insert(index, value), "capacity exceeded during operation `insert`: capacity is {C}");
+    }
+
+    #[inline]
+    fn try_insert(&mut self, index: usize, value: T) -> Result<(), InsertError<T>> {
+        self.try_insert(index, value)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> T { self.remove(index) }
+
+    #[inline]
+    #[track_caller]
+    fn swap_remove(&mut self, index: usize) -> T { self.swap_remove(index) }
+
+    #[inline]
+    fn truncate(&mut self, len: usize) { self.truncate(len) }
+}
+
+impl<T, const C: usize> Bank<T> for BankVec<T, C> {
+    type PushError = BankFullError<T>;
+
+    #[inline]
+    fn len(&self) -> usize { self.len() }
+
+    #[inline]
+    fn capacity(&self) -> usize { self.capacity() }
+
+    #[inline]
+    fn as_slice(&self) -> &[T] { self.as_slice() }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] { self.as_mut_slice() }
+
+    #[inline]
+    #[track_caller]
+    fn push(&mut self, value: T) { self.push(value) }
+
+    #[inline]
+    fn try_push(&mut self, value: T) -> Result<(), BankFullError<T>> { self.try_push(value) }
+
+    #[inline]
+    fn pop(&mut self) -> Option<T> { self.pop() }
+
+    #[inline]
+    #[track_caller]
+    fn insert(&mut self, index: usize, value: T) { self.insert(index, value) }
+
+    #[inline]
+    fn try_insert(&mut self, index: usize, value: T) -> Result<(), InsertError<T>> {
+        self.try_insert(index, value)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> T { self.remove(index) }
+
+    #[inline]
+    #[track_caller]
+    fn swap_remove(&mut self, index: usize) -> T { self.swap_remove(index) }
+
+    #[inline]
+    fn truncate(&mut self, len: usize) { self.truncate(len) }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn exercise<B: Bank<i32> + Default>() -> B {
+        let mut bank = B::default();
+        assert!(bank.is_empty());
+        bank.push(1);
+        bank.push(3);
+        bank.insert(1, 2);
+        assert!(bank.try_insert(9, 4).is_err());
+        assert_eq!(bank.as_slice(), [1, 2, 3]);
+
+        bank.as_mut_slice()[0] = 0;
+        assert_eq!(bank.swap_remove(0), 0);
+        assert_eq!(bank.remove(0), 3);
+        assert_eq!(bank.pop(), Some(2));
+        assert!(bank.try_push(5).is_ok());
+        bank.push(6);
+        bank
+    }
+
+    #[test]
+    fn generic() {
+        let mut strict = exercise::<BankArr<i32, 3>>();
+        assert_eq!(Bank::capacity(&strict), 3);
+        assert_eq!(Bank::try_push(&mut strict, 7), Ok(()));
+        assert_eq!(Bank::try_push(&mut strict, 8).unwrap_err().element(), 8);
+
+        let mut spilling = exercise::<BankVec<i32, 1>>();
+        assert!(Bank::capacity(&spilling) >= 2);
+        Bank::truncate(&mut spilling, 1);
+        assert_eq!(spilling, [5]);
+        Bank::clear(&mut spilling);
+        assert!(Bank::is_empty(&spilling));
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity exceeded during operation `insert`: capacity is 1")]
+    fn insert_full() {
+        let mut bank = BankArr::<i32, 1>::from([1]);
+        Bank::insert(&mut bank, 0, 0);
+    }
+}
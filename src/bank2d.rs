@@ -0,0 +1,399 @@
+//! A fixed-size grid stored inline, see [`Bank2D`].
+
+use std::{array, fmt, iter::{FusedIterator, StepBy}, ops::{Index, IndexMut, Range}, slice};
+
+
+/// A `W` by `H` grid of elements stored inline, in row-major order.
+/// 
+/// Every cell of a `Bank2D` is always initialized, so it's built with
+/// [`from_fn`](Bank2D::from_fn), [`filled`](Bank2D::filled) or from an array
+/// of rows.  Cells are indexed by `(x, y)`, `x` being the column and `y` the
+/// row, and can be walked a row or a column at a time, or all at once through
+/// [`as_slice`](Bank2D::as_slice).
+/// 
+/// # Examples
+/// ```
+/// use bankarr::Bank2D;
+/// 
+/// let mut grid = Bank2D::<u8, 3, 2>::from_fn(|x, y| (y * 3 + x) as u8);
+/// grid[(2, 1)] = 9;
+/// 
+/// assert_eq!(grid.row(1), &[3, 4, 9]);
+/// assert!(grid.column(2).eq(&[2, 9]));
+/// assert_eq!(grid.as_slice(), [0, 1, 2, 3, 4, 9]);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Bank2D<T, const W: usize, const H: usize> {
+    rows: [[T; W]; H],
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: fmt::Debug, const W: usize, const H: usize> fmt::Debug for Bank2D<T, W, H> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.rows()).finish()
+    }
+}
+
+impl<T: Default, const W: usize, const H: usize> Default for Bank2D<T, W, H> {
+    #[inline]
+    fn default() -> Self { Self::from_fn(|_, _| T::default()) }
+}
+
+impl<T, const W: usize, const H: usize> From<[[T; W]; H]> for Bank2D<T, W, H> {
+    #[inline]
+    fn from(rows: [[T; W]; H]) -> Self { Self { rows } }
+}
+
+impl<T, const W: usize, const H: usize> From<Bank2D<T, W, H>> for [[T; W]; H] {
+    #[inline]
+    fn from(grid: Bank2D<T, W, H>) -> Self { grid.rows }
+}
+
+impl<T, const W: usize, const H: usize> Index<(usize, usize)> for Bank2D<T, W, H> {
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        match self.get(x, y) {
+            Some(value) => value,
+            None => panic!("index out of bounds: the size is {W}x{H} but the index is ({x}, {y})"),
+        }
+    }
+}
+
+impl<T, const W: usize, const H: usize> IndexMut<(usize, usize)> for Bank2D<T, W, H> {
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        match self.get_mut(x, y) {
+            Some(value) => value,
+            None => panic!("index out of bounds: the size is {W}x{H} but the index is ({x}, {y})"),
+        }
+    }
+}
+
+impl<T, const W: usize, const H: usize> Bank2D<T, W, H> {
+
+    /// Constructs a grid with each cell set to `f(x, y)`, calling it row by
+    /// row.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::Bank2D;
+    /// 
+    /// let identity = Bank2D::<f32, 3, 3>::from_fn(|x, y| if x == y { 1.0 } else { 0.0 });
+    /// assert_eq!(identity.row(1), &[0.0, 1.0, 0.0]);
+    /// ```
+    #[inline]
+    pub fn from_fn<F: FnMut(usize, usize) -> T>(mut f: F) -> Self {
+        Self { rows: array::from_fn(|y| array::from_fn(|x| f(x, y))) }
+    }
+
+    /// Constructs a grid with every cell set to a clone of `value`.
+    #[inline]
+    pub fn filled(value: T) -> Self where T: Clone {
+        Self::from_fn(|_, _| value.clone())
+    }
+
+    /// Returns the number of columns, `W`.
+    #[inline]
+    pub const fn width(&self) -> usize { W }
+
+    /// Returns the number of rows, `H`.
+    #[inline]
+    pub const fn height(&self) -> usize { H }
+
+    /// Returns the number of cells, `W * H`.
+    #[inline]
+    pub const fn len(&self) -> usize { W * H }
+
+    /// Returns `true` if the grid has no cells.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { W == 0 || H == 0 }
+
+    /// Returns a reference to the cell at `(x, y)`, or `None` if it's out of
+    /// bounds.
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.rows.get(y)?.get(x)
+    }
+
+    /// Returns a mutable reference to the cell at `(x, y)`, or `None` if it's
+    /// out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.rows.get_mut(y)?.get_mut(x)
+    }
+
+    /// Extracts a slice of every cell, in row-major order.
+    #[inline]
+    pub const fn as_slice(&self) -> &[T] { self.rows.as_flattened() }
+
+    /// Extracts a mutable slice of every cell, in row-major order.
+    #[inline]
+    pub const fn as_mut_slice(&mut self) -> &mut [T] { self.rows.as_flattened_mut() }
+
+    /// Consumes the grid, returning its rows.
+    #[inline]
+    pub fn into_rows(self) -> [[T; W]; H] { self.rows }
+
+    /// Returns the row at `y`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `y >= H`.
+    #[inline]
+    #[track_caller]
+    pub fn row(&self, y: usize) -> &[T; W] { &self.rows[y] }
+
+    /// Returns the row at `y` mutably.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `y >= H`.
+    #[inline]
+    #[track_caller]
+    pub fn row_mut(&mut self, y: usize) -> &mut [T; W] { &mut self.rows[y] }
+
+    /// Returns an iterator over the rows, from top to bottom.
+    #[inline]
+    pub fn rows(&self) -> slice::Iter<'_, [T; W]> { self.rows.iter() }
+
+    /// Returns an iterator over the rows mutably, from top to bottom.
+    #[inline]
+    pub fn rows_mut(&mut self) -> slice::IterMut<'_, [T; W]> { self.rows.iter_mut() }
+
+    /// Returns an iterator over the column at `x`, from top to bottom.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `x >= W`.
+    #[inline]
+    #[track_caller]
+    pub fn column(&self, x: usize) -> Column<'_, T> {
+        assert!(x < W, "column index (is {x}) should be < width (is {W})");
+        Column { inner: self.as_slice().get(x..).unwrap_or_default().iter().step_by(W) }
+    }
+
+    /// Returns an iterator over the column at `x` mutably, from top to bottom.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `x >= W`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::Bank2D;
+    /// 
+    /// let mut grid = Bank2D::<u8, 2, 3>::default();
+    /// grid.column_mut(1).for_each(|cell| *cell = 1);
+    /// assert_eq!(grid.into_rows(), [[0, 1], [0, 1], [0, 1]]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn column_mut(&mut self, x: usize) -> ColumnMut<'_, T> {
+        assert!(x < W, "column index (is {x}) should be < width (is {W})");
+        ColumnMut { inner: self.as_mut_slice().get_mut(x..).unwrap_or_default().iter_mut().step_by(W) }
+    }
+
+    /// Returns an iterator over the columns, from left to right, each itself
+    /// an iterator over its cells.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::Bank2D;
+    /// 
+    /// let grid = Bank2D::from([[1, 2], [3, 4]]);
+    /// let sums: Vec<i32> = grid.columns().map(Iterator::sum).collect();
+    /// assert_eq!(sums, [4, 6]);
+    /// ```
+    #[inline]
+    pub fn columns(&self) -> Columns<'_, T, W> {
+        Columns { cells: self.as_slice(), range: 0..W }
+    }
+
+    /// Sets every cell to a clone of `value`.
+    #[inline]
+    pub fn fill(&mut self, value: T) where T: Clone {
+        self.as_mut_slice().fill(value)
+    }
+
+    /// Swaps the cells at `a` and `b`, each an `(x, y)` pair.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if either is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn swap(&mut self, a: (usize, usize), b: (usize, usize)) {
+        for (x, y) in [a, b] {
+            assert!(x < W && y < H, "index out of bounds: the size is {W}x{H} but the index is ({x}, {y})");
+        }
+        self.as_mut_slice().swap(a.1 * W + a.0, b.1 * W + b.0)
+    }
+}
+
+
+/// An iterator over a column of a [`Bank2D`].
+/// 
+/// Created by [`Bank2D::column`] or [`Columns`].
+pub struct Column<'a, T> {
+    inner: StepBy<slice::Iter<'a, T>>,
+}
+
+impl<T> Clone for Column<'_, T> {
+    fn clone(&self) -> Self { Self { inner: self.inner.clone() } }
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: fmt::Debug> fmt::Debug for Column<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+/// A mutable iterator over a column of a [`Bank2D`].
+/// 
+/// Created by [`Bank2D::column_mut`].
+pub struct ColumnMut<'a, T> {
+    inner: StepBy<slice::IterMut<'a, T>>,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T> fmt::Debug for ColumnMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ColumnMut").field("remaining", &self.inner.len()).finish()
+    }
+}
+
+macro_rules! impl_column {
+    ($name:ident, $item:ty) => {
+        impl<'a, T> Iterator for $name<'a, T> {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> { self.inner.next() }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) { self.inner.size_hint() }
+        }
+
+        impl<T> DoubleEndedIterator for $name<'_, T> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> { self.inner.next_back() }
+        }
+
+        impl<T> ExactSizeIterator for $name<'_, T> {}
+        impl<T> FusedIterator for $name<'_, T> {}
+    };
+}
+
+impl_column!(Column, &'a T);
+impl_column!(ColumnMut, &'a mut T);
+
+/// An iterator over the columns of a [`Bank2D`], from left to right.
+/// 
+/// Created by [`Bank2D::columns`].
+pub struct Columns<'a, T, const W: usize> {
+    cells: &'a [T],
+    range: Range<usize>,
+}
+
+impl<T, const W: usize> Clone for Columns<'_, T, W> {
+    fn clone(&self) -> Self { Self { cells: self.cells, range: self.range.clone() } }
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, const W: usize> fmt::Debug for Columns<'_, T, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Columns").field("remaining", &self.range.len()).finish()
+    }
+}
+
+impl<'a, T, const W: usize> Columns<'a, T, W> {
+    #[inline]
+    fn column(&self, x: usize) -> Column<'a, T> {
+        // A grid without rows has no cells past the first column
+        Column { inner: self.cells.get(x..).unwrap_or_default().iter().step_by(W) }
+    }
+}
+
+impl<'a, T, const W: usize> Iterator for Columns<'a, T, W> {
+    type Item = Column<'a, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|x| self.column(x))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { self.range.size_hint() }
+}
+
+impl<T, const W: usize> DoubleEndedIterator for Columns<'_, T, W> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|x| self.column(x))
+    }
+}
+
+impl<T, const W: usize> ExactSizeIterator for Columns<'_, T, W> {}
+impl<T, const W: usize> FusedIterator for Columns<'_, T, W> {}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn indexing() {
+        let mut grid = Bank2D::<String, 3, 2>::from_fn(|x, y| format!("{x}{y}"));
+        assert_eq!(grid[(2, 1)], "21");
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(0, 2), None);
+
+        grid.get_mut(0, 1).unwrap().push('!');
+        grid.swap((0, 0), (0, 1));
+        assert_eq!(grid.row(0), &["01!", "10", "20"]);
+        assert_eq!(grid.rows().next_back().unwrap(), &["00", "11", "21"]);
+
+        grid.row_mut(1).reverse();
+        assert_eq!(grid.as_slice(), ["01!", "10", "20", "21", "11", "00"]);
+        assert_eq!((grid.width(), grid.height(), grid.len()), (3, 2, 6));
+    }
+
+    #[test]
+    fn columns() {
+        let mut grid = Bank2D::from([[1, 2, 3], [4, 5, 6]]);
+        assert!(grid.column(1).rev().eq(&[5, 2]));
+        assert_eq!(grid.column(2).len(), 2);
+
+        grid.column_mut(0).for_each(|cell| *cell *= 10);
+        let columns: Vec<Vec<i32>> = grid.columns().rev().map(|column| column.copied().collect()).collect();
+        assert_eq!(columns, [vec![3, 6], vec![2, 5], vec![10, 40]]);
+
+        grid.fill(0);
+        assert_eq!(<[[i32; 3]; 2]>::from(grid), [[0; 3]; 2]);
+    }
+
+    #[test]
+    fn empty() {
+        let grid = Bank2D::<u8, 0, 4>::default();
+        assert!(grid.is_empty());
+        assert_eq!(grid.columns().count(), 0);
+        assert_eq!(grid.rows().count(), 4);
+
+        let mut grid = Bank2D::<u8, 3, 0>::default();
+        assert!(grid.columns().all(|mut column| column.next().is_none()));
+        assert_eq!(grid.column_mut(2).len(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the size is 2x2 but the index is (2, 0)")]
+    fn index_out_of_bounds() {
+        let grid = Bank2D::<u8, 2, 2>::filled(1);
+        let _ = grid[(2, 0)];
+    }
+}
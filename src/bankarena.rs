@@ -0,0 +1,216 @@
+
+use std::{alloc::Layout, cell::{Cell, UnsafeCell}, fmt, mem::{self, MaybeUninit}, ptr::{self, NonNull}, str};
+use crate::errors::BankFullError;
+
+
+/// A bump allocator handing out values from `BYTES` bytes of inline storage.
+/// 
+/// Each allocation takes the next suitably aligned span of the arena's bytes,
+/// and lives until the arena is [`reset`](BankArena::reset) or dropped, which
+/// frees everything at once.  Allocating only needs a shared reference, so
+/// many values can be borrowed from the arena at the same time.  That suits
+/// per-frame scratch data in code which must never reach for the global
+/// allocator.
+/// 
+/// The arena never runs destructors: values holding resources of their own,
+/// like a `String`, are leaked when the arena is reset.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::BankArena;
+/// 
+/// let arena = BankArena::<64>::new();
+/// let point = arena.alloc([1.5f32, -2.0]);
+/// let name = arena.alloc_str("player");
+/// let count = arena.alloc(0u64);
+/// 
+/// *count += 2;
+/// point[0] *= 2.0;
+/// assert_eq!((*point, &*name, *count), ([3.0, -2.0], "player", 2));
+/// assert!(arena.try_alloc([0u8; 64]).is_err());
+/// ```
+pub struct BankArena<const BYTES: usize> {
+    bytes: UnsafeCell<[MaybeUninit<u8>; BYTES]>,
+    used: Cell<usize>,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<const BYTES: usize> fmt::Debug for BankArena<BYTES> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BankArena")
+            .field("used", &self.used())
+            .field("capacity", &BYTES)
+            .finish()
+    }
+}
+
+impl<const BYTES: usize> Default for BankArena<BYTES> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+
+impl<const BYTES: usize> BankArena<BYTES> {
+
+    /// Constructs a new, empty `BankArena<BYTES>`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { bytes: UnsafeCell::new([MaybeUninit::uninit(); BYTES]), used: Cell::new(0) }
+    }
+
+    /// Returns the number of bytes the arena holds, `BYTES`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { BYTES }
+
+    /// Returns the number of bytes taken by allocations so far, padding
+    /// included.
+    #[inline]
+    pub fn used(&self) -> usize { self.used.get() }
+
+    /// Returns the number of bytes left, though alignment may leave less of
+    /// it usable.
+    #[inline]
+    pub fn remaining(&self) -> usize { BYTES - self.used.get() }
+
+    // Takes the next span of bytes fitting `layout`, if there's room left.
+    // Zero-sized layouts must be handled by the caller.
+    #[inline]
+    fn bump(&self, layout: Layout) -> Option<NonNull<u8>> {
+        let base = self.bytes.get().cast::<u8>();
+        let used = self.used.get();
+        let padding = base.addr().wrapping_add(used).wrapping_neg() & (layout.align() - 1);
+        let start = used.checked_add(padding)?;
+        let end = start.checked_add(layout.size())?;
+        if end > BYTES { return None }
+
+        self.used.set(end);
+        // `start..end` lies within the arena and is handed out only once
+        Some(unsafe { NonNull::new_unchecked(base.add(start)) })
+    }
+
+    /// Moves `value` into the arena, returning a reference to it.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the arena lacks the room for `value`.
+    #[inline]
+    #[track_caller]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T>(&self, value: T) -> &mut T {
+        match self.try_alloc(value) {
+            Ok(value) => value,
+            Err(_) => panic!("capacity exceeded during operation `alloc`: capacity is {BYTES}"),
+        }
+    }
+
+    /// Attempts to move `value` into the arena, handing it back inside the
+    /// error if it doesn't fit.
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    pub fn try_alloc<T>(&self, value: T) -> Result<&mut T, BankFullError<T>> {
+        let ptr = match mem::size_of::<T>() {
+            0 => NonNull::dangling(),
+            _ => match self.bump(Layout::new::<T>()) {
+                Some(ptr) => ptr.cast(),
+                None => return Err(BankFullError::new(value)),
+            },
+        };
+        unsafe {
+            ptr.write(value);
+            Ok(&mut *ptr.as_ptr())
+        }
+    }
+
+    /// Copies `slice` into the arena, returning a reference to the copy.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the arena lacks the room for `slice`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankArena;
+    /// 
+    /// let arena = BankArena::<32>::new();
+    /// let evens = arena.alloc_slice_copy(&[2u32, 4, 6]);
+    /// evens.reverse();
+    /// assert_eq!(evens, [6, 4, 2]);
+    /// assert!(arena.used() >= 12);
+    /// ```
+    #[track_caller]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_copy<T: Copy>(&self, slice: &[T]) -> &mut [T] {
+        let layout = Layout::for_value(slice);
+        let ptr = match layout.size() {
+            0 => NonNull::dangling(),
+            _ => match self.bump(layout) {
+                Some(ptr) => ptr.cast(),
+                None => panic!("capacity exceeded during operation `alloc_slice_copy`: capacity is {BYTES}"),
+            },
+        };
+        unsafe {
+            ptr::copy_nonoverlapping(slice.as_ptr(), ptr.as_ptr(), slice.len());
+            &mut *ptr::slice_from_raw_parts_mut(ptr.as_ptr(), slice.len())
+        }
+    }
+
+    /// Copies `s` into the arena, returning a reference to the copy.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the arena lacks the room for `s`.
+    #[inline]
+    #[track_caller]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_str(&self, s: &str) -> &mut str {
+        // A copy of valid UTF-8 is valid UTF-8
+        unsafe { str::from_utf8_unchecked_mut(self.alloc_slice_copy(s.as_bytes())) }
+    }
+
+    /// Frees every allocation at once, without dropping them, so the arena's
+    /// bytes can be reused.
+    #[inline]
+    pub fn reset(&mut self) { self.used.set(0) }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn alignment() {
+        let mut arena = BankArena::<64>::new();
+        let byte = arena.alloc(1u8);
+        let word = arena.alloc(2u64);
+        let pair = arena.alloc((3u16, 4u32));
+        assert_eq!((*byte, *word, *pair), (1, 2, (3, 4)));
+        assert!((word as *mut u64).is_aligned());
+        assert!((pair as *mut (u16, u32)).is_aligned());
+
+        let used = arena.used();
+        assert!((17..=32).contains(&used));
+        arena.alloc(());
+        arena.alloc_slice_copy::<u64>(&[]);
+        assert_eq!(arena.used(), used);
+
+        arena.reset();
+        assert_eq!(arena.remaining(), 64);
+        assert_eq!(arena.alloc_str("reset"), "reset");
+    }
+
+    #[test]
+    fn full() {
+        let arena = BankArena::<8>::new();
+        arena.alloc(0u32);
+        assert_eq!(arena.try_alloc([1u8; 5]).unwrap_err().element(), [1; 5]);
+        assert_eq!(arena.try_alloc([1u8; 4]).map(|value| *value), Ok([1; 4]));
+        assert_eq!(arena.remaining(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity exceeded during operation `alloc_slice_copy`: capacity is 4")]
+    fn slice_too_long() {
+        BankArena::<4>::new().alloc_str("hello");
+    }
+}
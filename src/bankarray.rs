@@ -1,7 +1,10 @@
 
 
-use std::{mem::{ManuallyDrop, MaybeUninit}, ops::{self, Deref, DerefMut, Index, IndexMut}, ptr::{self, NonNull}, slice::{self, SliceIndex}};
-use crate::{drain, errors::BankFullError};
+use std::{borrow::{Borrow, BorrowMut, Cow}, fmt, marker::PhantomData, mem::{self, ManuallyDrop, MaybeUninit}, ops::{self, Deref, DerefMut, Index, IndexMut}, panic::{RefUnwindSafe, UnwindSafe}, ptr::{self, NonNull}, slice::{self, SliceIndex}};
+use crate::{chunks, drain::{self, DrainTarget}, errors::{BankFullError, InsertError}, into_iter::IntoIter, raw::{self, RawBank}, sort, spec, stack_guard};
+
+#[cfg(feature = "const-mut")]
+mod const_mut;
 
 
 /// A fixed-size contiguous growable array type.
@@ -89,6 +92,16 @@ use crate::{drain, errors::BankFullError};
 /// 
 /// ```
 /// 
+/// Because a bank borrows as a slice, a collection of banks can be concatenated
+/// or joined into a [`Vec`].
+/// ```
+/// use bankarr::BankArr;
+/// 
+/// let banks = [BankArr::<i32, 3>::from([1, 2]), BankArr::from([3])];
+/// assert_eq!(banks.concat(), [1, 2, 3]);
+/// assert_eq!(banks.join(&0), [1, 2, 0, 3]);
+/// ```
+/// 
 /// # Capacity
 /// 
 /// The capacity of a `BankArr` is determined by its generic, `C`.  At instantiation,
@@ -101,16 +114,48 @@ use crate::{drain, errors::BankFullError};
 /// may fail if the bank is already at capacity. Generally there are safe 
 /// alternatives, .i.e [`try_push`](Self::try_push) which return a [`Result`].
 /// 
-#[derive(Debug)]
+#[repr(C)]
 pub struct BankArr<T, const C: usize> {
     pub(crate) data: [MaybeUninit<T>; C],
     pub(crate) len: usize,
+    // Owns its `T`s as far as the drop checker is concerned, which the
+    // `MaybeUninit` array alone doesn't
+    pub(crate) marker: PhantomData<T>,
+}
+
+// Spelled out rather than left to the fields, so the banks match `Vec` however
+// their storage is represented.  Neither projects a pin onto its elements, so
+// pinning a bank never pins what's inside and it can be `Unpin` regardless.
+impl<T: UnwindSafe, const C: usize> UnwindSafe for BankArr<T, C> {}
+impl<T: RefUnwindSafe, const C: usize> RefUnwindSafe for BankArr<T, C> {}
+impl<T, const C: usize> Unpin for BankArr<T, C> {}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: fmt::Debug, const C: usize> fmt::Debug for BankArr<T, C> {
+
+    /// Formats the bank's elements and capacity.  The alternate form (`{:#?}`)
+    /// also shows its length and remaining capacity.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let alternate = f.alternate();
+        let name = fmt::format(format_args!("BankArr<T, {}>", C));
+        let mut debug = f.debug_struct(&name);
+        debug
+            .field("data", &self.as_slice())
+            .field("capacity", &C);
+
+        if alternate {
+            debug
+                .field("len", &self.len)
+                .field("remaining_capacity", &self.remaining_capacity());
+        }
+        debug.finish()
+    }
 }
 
 impl <T, const C: usize> Deref for BankArr<T, C> {
     type Target = [T];
     #[inline]
-    fn deref(&self) -> &Self::Target { &self.as_slice() }
+    fn deref(&self) -> &Self::Target { self.as_slice() }
 }
 
 impl <T, const C: usize> DerefMut for BankArr<T, C> {
@@ -118,10 +163,33 @@ impl <T, const C: usize> DerefMut for BankArr<T, C> {
     fn deref_mut(&mut self) -> &mut Self::Target { self.as_mut_slice() }
 }
 
+impl<T, const C: usize> AsRef<[T]> for BankArr<T, C> {
+    #[inline]
+    fn as_ref(&self) -> &[T] { self.as_slice() }
+}
+
+impl<T, const C: usize> AsMut<[T]> for BankArr<T, C> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] { self.as_mut_slice() }
+}
+
+// Borrowing as a slice is what lets `[bank1, bank2].concat()` and
+// `banks.join(&sep)` work through std's blanket `Concat`/`Join` impls.
+impl<T, const C: usize> Borrow<[T]> for BankArr<T, C> {
+    #[inline]
+    fn borrow(&self) -> &[T] { self.as_slice() }
+}
+
+impl<T, const C: usize> BorrowMut<[T]> for BankArr<T, C> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut [T] { self.as_mut_slice() }
+}
+
 impl<T, const C: usize, I: SliceIndex<[T]>> Index<I> for BankArr<T, C> {
     type Output = I::Output;
 
     #[inline]
+    #[track_caller]
     fn index(&self, index: I) -> &Self::Output {
         Index::index(&**self, index)
     }
@@ -129,11 +197,30 @@ impl<T, const C: usize, I: SliceIndex<[T]>> Index<I> for BankArr<T, C> {
 
 impl<T, const C: usize, I: SliceIndex<[T]>> IndexMut<I> for BankArr<T, C> {
     #[inline]
+    #[track_caller]
     fn index_mut(&mut self, index: I) -> &mut Self::Output {
         IndexMut::index_mut(&mut **self, index)
     }
 }
 
+impl<T, const C: usize> IntoIterator for BankArr<T, C> {
+    type Item = T;
+    type IntoIter = IntoIter<T, Self>;
+
+    /// Creates a consuming iterator, moving each element out of the bank.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let bank = BankArr::<String, 3>::from(["a".to_string(), "b".to_string()]);
+    /// let joined: String = bank.into_iter().rev().collect();
+    /// assert_eq!(joined, "ba");
+    /// ```
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { IntoIter::new(self) }
+}
+
 impl<'a, T, const C: usize> IntoIterator for &'a BankArr<T, C> {
     type Item = &'a T;
     type IntoIter = slice::Iter<'a, T>;
@@ -190,47 +277,73 @@ impl<T: PartialEq, const C: usize> PartialEq<&[T]> for BankArr<T, C> {
 impl<T: Clone, const C: usize> Clone for BankArr<T, C> {
     fn clone(&self) -> Self {
 
+        stack_guard::check_inline_size::<T, C>();
         let mut data = [const { MaybeUninit::<T>::uninit() }; C];
-
-        data.iter_mut()
-            .zip(self.iter())
-            .for_each(|(b, a)| { b.write(a.clone()); });
+        unsafe { spec::clone_to_uninit(self.as_slice(), data.as_mut_ptr().cast()) }
         
-        Self { data, len: self.len }
+        Self { data, len: self.len, marker: PhantomData }
     }
 }
 
 impl<T, const C: usize> Extend<T> for BankArr<T, C> {
+    #[track_caller]
     fn extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
 
-        let (mut ptr, mut end) = unsafe {
-            let ptr: *mut T = self.as_mut_ptr();
-            let end: *const T = if Self::IS_ZST { (ptr as usize + C - self.len) as _ } 
-                else { ptr.add(C) as _ };
-            (ptr.add(self.len), end)
-        };
+        let items = items.into_iter();
+        if let Some(slice) = spec::copy_slice(&items) {
+            if slice.len() > C - self.len { raw::capacity_exceeded("extend", C) }
+            unsafe { self.raw_extend_from_slice(slice) }
+            return
+        }
 
-        items.into_iter().for_each(|val| {
-            match (ptr == end as _, Self::IS_ZST) {
-                (true, _) => panic!("capacity exceeded during operation `extend`"),
-                (_, true) => { end = (end as usize - 1) as _; },
-                (_, false) => unsafe {
-                    ptr.write(val);
-                    ptr = ptr.add(1);
-                }
-            }
-            self.len += 1;
+        // The length lives in a guard while the items are written, so those
+        // written before the iterator panics, or the bank fills, stay owned
+        struct SetLenOnDrop<'a> {
+            len: &'a mut usize,
+            local_len: usize,
+        }
+
+        impl Drop for SetLenOnDrop<'_> {
+            fn drop(&mut self) { *self.len = self.local_len }
+        }
+
+        let ptr = self.as_mut_ptr();
+        let mut guard = SetLenOnDrop { local_len: self.len, len: &mut self.len };
+        items.for_each(|val| {
+            if guard.local_len == C { raw::capacity_exceeded("extend", C) }
+            unsafe { ptr.add(guard.local_len).write(val) }
+            guard.local_len += 1;
         });
     }
 }
 
-#[cfg(not(tarpaulin_include))] // Drain's drop implicitly tests this
-impl<'a, T, const C: usize> drain::Drainable<'a, T> for BankArr<T, C> {
-    fn drain_parts(&'a mut self) -> (ptr::NonNull<T>, &'a mut usize) {
-        (
-            unsafe { NonNull::new_unchecked(self.as_mut_ptr()) },
-            &mut self.len
-        )
+impl<'a, T: Copy + 'a, const C: usize> Extend<&'a T> for BankArr<T, C> {
+
+    /// Extends the bank by copying elements out of an iterator of references.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the new length would exceed `C`.
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, items: I) {
+        let items = items.into_iter();
+        match spec::ref_slice(&items) {
+            Some(slice) => {
+                if slice.len() > C - self.len { raw::capacity_exceeded("extend", C) }
+                unsafe { self.raw_extend_from_slice(slice) }
+            }
+            None => self.extend(items.copied()),
+        }
+    }
+}
+
+unsafe impl<T, const C: usize> RawBank<T> for BankArr<T, C> {
+    #[inline(always)]
+    fn raw_ptr(&self) -> *const T { self.as_ptr() }
+
+    #[inline(always)]
+    fn raw_parts(&mut self) -> (NonNull<T>, &mut usize, usize) {
+        (unsafe { NonNull::new_unchecked(self.as_mut_ptr()) }, &mut self.len, C)
     }
 }
 
@@ -256,25 +369,10 @@ impl <T, const C: usize, const N: usize> From<[T; N]> for BankArr<T, C> {
     /// 
     /// let bank = BankArr::<i32, 2>::from([1, 2, 3]); // Panics!
     /// ```
+    #[track_caller]
     fn from(arr: [T; N]) -> Self {
-        assert!(N <= C);
-        
-        let arr = ManuallyDrop::new(arr);
-        let mut bank = Self {
-            data: [const { MaybeUninit::uninit() }; C],
-            len: N
-        };
-
-        //for (idx, val) in arr.into_iter().enumerate() { unsafe {
-        //    *bank.data.get_unchecked_mut(idx) = MaybeUninit::new(val);
-        //}}
-        
-        unsafe { ptr::copy_nonoverlapping(
-            arr.as_ptr().cast(), 
-            bank.as_mut_ptr(), 
-            N
-        )}
-        bank
+        assert!(N <= C, "array length (is {N}) exceeds bank capacity (is {C})");
+        Self::from_array(arr)
     }
 }
 
@@ -300,43 +398,135 @@ impl <T, const C: usize> From<Vec<T>> for BankArr<T, C> {
     /// 
     /// let bank = BankArr::<i32, 2>::from(vec![1, 2, 3]); // Panics!
     /// ```
+    #[track_caller]
     fn from(vec: Vec<T>) -> Self {
         let len = vec.len();
-        assert!(len <= C);
+        assert!(len <= C, "vec length (is {len}) exceeds bank capacity (is {C})");
 
+        stack_guard::check_inline_size::<T, C>();
         let mut data = [const {MaybeUninit::uninit() }; C];
 
         for (idx, val) in vec.into_iter().enumerate() { unsafe { 
             *data.get_unchecked_mut(idx) = MaybeUninit::new(val);
         }}
         
-        Self { data, len }
+        Self { data, len, marker: PhantomData }
     }
 }
 
 impl <T, const C: usize> From<BankArr<T, C>> for Vec<T> {
     fn from(bank: BankArr<T, C>) -> Self {
-        unsafe { 
-            bank.data
-                .get_unchecked(..bank.len)
-                .iter()
-                .map(|v| v.assume_init_read())
-                .collect()
+        // The elements are moved out, so the bank mustn't drop them as well
+        let bank = ManuallyDrop::new(bank);
+        let mut vec = Vec::with_capacity(bank.len);
+        unsafe {
+            ptr::copy_nonoverlapping(bank.as_ptr(), vec.as_mut_ptr(), bank.len);
+            vec.set_len(bank.len);
         }
+        vec
     }
 }
 
-impl <T, const C: usize> Drop for BankArr<T, C> {
-    fn drop(&mut self) {
-        unsafe {
-            ptr::slice_from_raw_parts_mut(self.as_mut_ptr(), self.len).drop_in_place();
+impl<T, const C: usize, const N: usize> TryFrom<BankArr<T, C>> for [T; N] {
+    type Error = BankArr<T, C>;
+
+    /// Moves the elements of a bank holding exactly `N` of them into an array,
+    /// or hands the bank back if its length differs.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let bank = BankArr::<u8, 4>::from([1, 2, 3]);
+    /// let bank = <[u8; 4]>::try_from(bank).unwrap_err();
+    /// assert_eq!(<[u8; 3]>::try_from(bank), Ok([1, 2, 3]));
+    /// ```
+    fn try_from(bank: BankArr<T, C>) -> Result<Self, Self::Error> {
+        if bank.len != N { return Err(bank) }
+
+        // The elements are moved out, so the bank mustn't drop them as well
+        let bank = ManuallyDrop::new(bank);
+        Ok(unsafe { bank.as_ptr().cast::<[T; N]>().read() })
+    }
+}
+
+impl <T: Clone, const C: usize> From<&BankArr<T, C>> for Vec<T> {
+
+    /// Clones the bank's elements into a new [`Vec`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let bank = BankArr::<i32, 3>::from([1, 2]);
+    /// let vec = Vec::from(&bank);
+    /// assert_eq!(bank, vec);
+    /// ```
+    #[inline]
+    fn from(bank: &BankArr<T, C>) -> Self { bank.to_vec() }
+}
+
+impl <T: Clone, const C: usize> From<Cow<'_, [T]>> for BankArr<T, C> {
+
+    /// Create a new instance from a clone-on-write slice.
+    /// 
+    /// Owned data is moved into the bank, borrowed data is cloned.
+    /// 
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use bankarr::BankArr;
+    /// 
+    /// let bank = BankArr::<i32, 3>::from(Cow::Borrowed(&[1, 2][..]));
+    /// assert_eq!(bank, [1, 2]);
+    /// ```
+    /// # Panics
+    /// 
+    /// Panics if the length of the slice exceeds the bank's size.
+    #[track_caller]
+    fn from(cow: Cow<'_, [T]>) -> Self {
+        match cow {
+            Cow::Owned(vec) => Self::from(vec),
+            Cow::Borrowed(slice) => {
+                assert!(slice.len() <= C, "slice length (is {}) exceeds bank capacity (is {C})", slice.len());
+                let mut bank = Self::new();
+                for val in slice { unsafe { bank.push_unchecked(val.clone()) } }
+                bank
+            }
         }
     }
 }
 
+impl <'a, T: Clone, const C: usize> From<&'a BankArr<T, C>> for Cow<'a, [T]> {
+    #[inline]
+    fn from(bank: &'a BankArr<T, C>) -> Self { Cow::Borrowed(bank.as_slice()) }
+}
+
+impl <T, const C: usize> Default for BankArr<T, C> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+
+#[cfg(not(feature = "may-dangle"))]
+impl <T, const C: usize> Drop for BankArr<T, C> {
+    fn drop(&mut self) { self.drop_elements() }
+}
+
+// The elements are only dropped, never read, so any borrows they hold may
+// already have ended
+#[cfg(feature = "may-dangle")]
+unsafe impl <#[may_dangle] T, const C: usize> Drop for BankArr<T, C> {
+    fn drop(&mut self) { self.drop_elements() }
+}
+
 impl <T, const C: usize> BankArr<T, C> {
 
-    const IS_ZST: bool = std::mem::size_of::<T>() == 0;
+    #[inline]
+    fn drop_elements(&mut self) {
+        if mem::needs_drop::<T>() {
+            unsafe { ptr::slice_from_raw_parts_mut(self.as_mut_ptr(), self.len).drop_in_place() }
+        }
+    }
 
     /// Constructs a new, empty `BankArr<T, C>`
     /// 
@@ -349,12 +539,90 @@ impl <T, const C: usize> BankArr<T, C> {
     /// let mut bank = BankArr::<i32, 3>::new();
     /// ```
     pub const fn new() -> Self {
+        stack_guard::check_inline_size::<T, C>();
         Self {
             data: [const { MaybeUninit::uninit() }; C],
             len: 0,
+            marker: PhantomData,
         }
     }
 
+    /// Constructs a new `BankArr<T, C>` from an array, usable in `const` and
+    /// `static` contexts.
+    /// 
+    /// The array consumed may be smaller than the specified bank size `C`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `N` exceeds `C`.  In a `const` context this is a compile error.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// static SQUARES: BankArr<u32, 8> = BankArr::from_array([0, 1, 4, 9, 16]);
+    /// 
+    /// assert_eq!(SQUARES.len(), 5);
+    /// assert_eq!(SQUARES[3], 9);
+    /// ```
+    /// 
+    /// ```compile_fail
+    /// use bankarr::BankArr;
+    /// 
+    /// const BANK: BankArr<u8, 2> = BankArr::from_array([1, 2, 3]); // Too long!
+    /// ```
+    #[track_caller]
+    pub const fn from_array<const N: usize>(arr: [T; N]) -> Self {
+        assert!(N <= C, "array length exceeds bank capacity");
+
+        stack_guard::check_inline_size::<T, C>();
+        let arr = ManuallyDrop::new(arr);
+        let mut bank = Self {
+            data: [const { MaybeUninit::uninit() }; C],
+            len: N,
+            marker: PhantomData,
+        };
+
+        unsafe { ptr::copy_nonoverlapping(
+            (&raw const arr).cast::<T>(), 
+            bank.as_mut_ptr(), 
+            N
+        )}
+        bank
+    }
+
+    /// Constructs a new `BankArr<T, C>` from an array, handing the array back
+    /// if `N` exceeds `C`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// assert_eq!(BankArr::<i32, 3>::try_from_array([1, 2]).unwrap(), [1, 2]);
+    /// assert_eq!(BankArr::<i32, 2>::try_from_array([1, 2, 3]).unwrap_err(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub const fn try_from_array<const N: usize>(arr: [T; N]) -> Result<Self, [T; N]> {
+        if N > C { return Err(arr) }
+        Ok(Self::from_array(arr))
+    }
+
+    /// Constructs a new `BankArr<T, C>` from a vec, handing the vec back if
+    /// its length exceeds `C`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// assert_eq!(BankArr::<i32, 3>::try_from_vec(vec![1, 2]).unwrap(), [1, 2]);
+    /// assert_eq!(BankArr::<i32, 2>::try_from_vec(vec![1, 2, 3]).unwrap_err(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn try_from_vec(vec: Vec<T>) -> Result<Self, Vec<T>> {
+        if vec.len() > C { return Err(vec) }
+        Ok(Self::from(vec))
+    }
+
     /// Returns the length of the bank.
     /// 
     /// # Examples
@@ -370,6 +638,21 @@ impl <T, const C: usize> BankArr<T, C> {
     #[inline(always)]
     pub const fn len(&self) -> usize { self.len }
 
+    /// Returns `true` if the bank contains no elements.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let mut bank = BankArr::<i32, 3>::new();
+    /// assert!(bank.is_empty());
+    /// 
+    /// bank.push(5);
+    /// assert!(!bank.is_empty());
+    /// ```
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool { self.len == 0 }
+
     /// Returns the remaining capacity of the bank.
     /// 
     /// Simply, `C - BankArr::len`.
@@ -407,14 +690,18 @@ impl <T, const C: usize> BankArr<T, C> {
     /// 
     /// Takes *O*(1) time.
     #[inline]
+    #[track_caller]
+    #[cfg(not(feature = "const-mut"))]
     pub fn push(&mut self, value: T) {
-        assert!(self.len < C);
+        if self.len == C { raw::capacity_exceeded("push", C) }
         unsafe { self.push_unchecked(value) }
     }
 
     /// Attempts to append an element to the back of the collection.
     /// Returns a [`Result`] indicating success.
     /// 
+    /// If the bank is full the element is handed back inside the error.
+    /// 
     /// # Examples
     /// ```
     /// use bankarr::BankArr;
@@ -425,19 +712,40 @@ impl <T, const C: usize> BankArr<T, C> {
     /// assert!(ok.is_ok());
     /// 
     /// let err = bank.try_push(4);
-    /// assert!(err.is_err());
+    /// assert_eq!(err.unwrap_err().element(), 4);
     /// ```
     /// 
     /// # Time Complexity
     /// 
     /// Takes *O*(1) time.
     #[inline]
-    pub fn try_push(&mut self, value: T) -> Result<(), BankFullError> {
-        if self.len == C { return Err(BankFullError {}) }
+    pub const fn try_push(&mut self, value: T) -> Result<(), BankFullError<T>> {
+        if self.len == C { return Err(BankFullError::new(value)) }
         unsafe { self.push_unchecked(value) }
         Ok(())
     }
 
+    /// Attempts to append every element of `items` to the back of the bank,
+    /// in order, stopping once it's full.
+    /// 
+    /// The first element that doesn't fit is handed back inside the error.  The
+    /// elements before it stay in the bank and the rest of `items` is dropped.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let mut bank = BankArr::<i32, 3>::from([1]);
+    /// assert!(bank.try_extend([2]).is_ok());
+    /// 
+    /// let err = bank.try_extend([3, 4, 5]);
+    /// assert_eq!(err.unwrap_err().element(), 4);
+    /// assert_eq!(bank, [1, 2, 3]);
+    /// ```
+    pub fn try_extend<I: IntoIterator<Item = T>>(&mut self, items: I) -> Result<(), BankFullError<T>> {
+        items.into_iter().try_for_each(|value| self.try_push(value))
+    }
+
     #[inline(always)]
     const fn as_mut_ptr(&mut self) -> *mut T {
         self.data.as_mut_ptr() as _
@@ -469,9 +777,9 @@ impl <T, const C: usize> BankArr<T, C> {
     /// 
     /// Takes *O*(1) time.
     #[inline(always)]
-    pub unsafe fn push_unchecked(&mut self, value: T) {
+    pub const unsafe fn push_unchecked(&mut self, value: T) {
         let len = self.len;
-        debug_assert!(len < C);
+        strict_assert!(len < C, "push_unchecked called on a full bank");
         unsafe { self.as_mut_ptr().add(len).write(value); }
         self.len = len + 1;
     }
@@ -490,7 +798,7 @@ impl <T, const C: usize> BankArr<T, C> {
     /// 
     /// Takes *O*(1) time.
     #[inline]
-    pub fn pop(&mut self) -> Option<T> {
+    pub const fn pop(&mut self) -> Option<T> {
         match self.len == 0 {
             true => None,
             false => unsafe {
@@ -506,6 +814,7 @@ impl <T, const C: usize> BankArr<T, C> {
     /// # Panics
     /// 
     /// Panics if if `index > len` OR if `len == C`.
+    /// For a panic-free `insert`, see [`try_insert`](BankArr::try_insert).
     /// 
     /// # Examples
     /// 
@@ -524,19 +833,44 @@ impl <T, const C: usize> BankArr<T, C> {
     /// Takes *O*(`BankArr::len - index`) time. All items after the insertion 
     /// index must be shifted right. In the worst cast, all elements are 
     /// shifted when insertion index is 0.
+    #[track_caller]
     pub fn insert(&mut self, index: usize, element: T) -> bool {
-        assert!(index <= self.len, "Index out of bounds");
+        if index > self.len { raw::insert_out_of_bounds(index, self.len) }
         if self.len == C { return false }
 
-        unsafe {
-            let ptr = self.as_mut_ptr().add(index);
-            ptr.copy_to(ptr.add(1), self.len - index);
-            ptr.write(element);
-        }
-        self.len += 1;
+        unsafe { self.raw_insert(index, element) }
         true
     }
 
+    /// Attempts to insert an element at position `index` within the bank, 
+    /// shifting all elements after it to the right.
+    /// 
+    /// Never panics, on failure the element is handed back inside the error.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::{BankArr, InsertError};
+    /// 
+    /// let mut bank = BankArr::<i32, 3>::from([1, 3]);
+    /// 
+    /// assert_eq!(bank.try_insert(5, 2), Err(InsertError::OutOfBounds(2)));
+    /// assert_eq!(bank.try_insert(1, 2), Ok(()));
+    /// assert_eq!(bank.try_insert(0, 0), Err(InsertError::Full(0)));
+    /// assert_eq!(bank, [1, 2, 3]);
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(`BankArr::len - index`) time.
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), InsertError<T>> {
+        if index > self.len { return Err(InsertError::OutOfBounds(element)) }
+        if self.len == C { return Err(InsertError::Full(element)) }
+
+        unsafe { self.raw_insert(index, element) }
+        Ok(())
+    }
+
     /// Removes and returns the element at position `index` within the bank, 
     /// shifting all elements after it to the left.
     /// 
@@ -547,6 +881,7 @@ impl <T, const C: usize> BankArr<T, C> {
     /// # Panics
     /// 
     /// Panics if the `index` is out of bounds.
+    /// For a panic-free `remove`, see [`try_remove`](BankArr::try_remove).
     /// 
     /// # Examples
     /// 
@@ -557,15 +892,10 @@ impl <T, const C: usize> BankArr<T, C> {
     /// assert_eq!(bank.remove(1), 2);
     /// assert_eq!(bank, [1, 3]);
     /// ```
+    #[track_caller]
     pub fn remove(&mut self, index: usize) -> T {
-        assert!(index < self.len, "Index out of bounds");
-        self.len -= 1;
-        unsafe {
-            let removed = self.as_mut_ptr().add(index).read();
-            let ptr = self.as_mut_ptr().add(index);
-            ptr.add(1).copy_to(ptr, self.len - index);
-            removed
-        }
+        assert!(index < self.len, "removal index (is {index}) should be < len (is {})", self.len);
+        unsafe { self.raw_remove(index) }
     }
 
     /// Removes an element from the bank and returns it.
@@ -577,6 +907,7 @@ impl <T, const C: usize> BankArr<T, C> {
     /// # Panics
     /// 
     /// Panics if `index` is out of bounds.
+    /// For a panic-free `swap_remove`, see [`try_swap_remove`](BankArr::try_swap_remove).
     /// 
     /// # Examples 
     /// 
@@ -587,24 +918,14 @@ impl <T, const C: usize> BankArr<T, C> {
     /// assert_eq!(bank.swap_remove(2), 3);
     /// assert_eq!(bank, [1, 2, 5, 4]);
     /// ```
+    #[track_caller]
     pub fn swap_remove(&mut self, index: usize) -> T {
-        assert!(index < self.len, "Index out of bounds");
-        self.len -= 1;
-        unsafe {
-            let ptr = self.data.as_mut_ptr();
-            ptr.add(index).replace(ptr.add(self.len).read()).assume_init()
-        }
-
+        assert!(index < self.len, "swap_remove index (is {index}) should be < len (is {})", self.len);
+        unsafe { self.raw_swap_remove(index) }
     }
 
-    /// Removes all elements from the bank and returns a double-ended iterator over
-    /// the elements.
-    /// 
-    /// If the iterator is dropped before being fully consumed, it drops the
-    /// remaining elements.
-    /// 
-    /// The returned iterator keeps a mutable borrow on the bank to optimize its
-    /// implementation.
+    /// Removes and returns the element at position `index`, shifting all elements
+    /// after it to the left, or returns `None` if `index` is out of bounds.
     /// 
     /// # Examples
     /// 
@@ -612,92 +933,501 @@ impl <T, const C: usize> BankArr<T, C> {
     /// use bankarr::BankArr;
     /// 
     /// let mut bank = BankArr::<i32, 3>::from([1, 2, 3]);
-    /// let drained: Vec<_> = bank.drain(..).collect();
-    /// 
-    /// assert_eq!(drained, [1, 2, 3]);
-    /// assert_eq!(bank.len(), 0);
-    /// assert_eq!(bank, []);
+    /// assert_eq!(bank.try_remove(1), Some(2));
+    /// assert_eq!(bank.try_remove(2), None);
+    /// assert_eq!(bank, [1, 3]);
     /// ```
-    /// 
-    pub fn drain<R>(&mut self, range: R) -> drain::Drain<'_, T, Self> 
-    where 
-        R: ops::RangeBounds<usize>,
-    {
-        let ptr: *const T = self.as_ptr();
-        let len = self.len;
-        let ops::Range { start, end } = drain::slice_range(range, ..len);
-
-        unsafe {
-            self.len = start;
-            drain::Drain {
-                tail_start: end,
-                tail_len: len - end,
-                iter: slice::from_raw_parts(ptr.add(start), end - start).iter(),
-                bank: NonNull::new_unchecked(self)
-            }
-        }
+    #[inline]
+    pub fn try_remove(&mut self, index: usize) -> Option<T> {
+        (index < self.len).then(|| unsafe { self.raw_remove(index) })
     }
 
-    /// Extracts a slice containing the entire bank.
-    /// 
-    /// Equivalent to `&bank[..]`.
+    /// Removes an element from the bank and returns it, replacing it with the 
+    /// last element, or returns `None` if `index` is out of bounds.
     /// 
     /// # Examples
     /// 
     /// ```
-    /// use std::io::{self, Write};
     /// use bankarr::BankArr;
     /// 
-    /// let bank = BankArr::<u8, 3>::from([1, 2, 3]);
-    /// io::sink().write(bank.as_slice()).unwrap();
+    /// let mut bank = BankArr::<i32, 4>::from([1, 2, 3, 4]);
+    /// assert_eq!(bank.try_swap_remove(0), Some(1));
+    /// assert_eq!(bank.try_swap_remove(3), None);
+    /// assert_eq!(bank, [4, 2, 3]);
     /// ```
     #[inline]
-    pub const fn as_slice(&self) -> &[T] {
-        // We are tracking initialized values via len, ensuring the slice is not UB
-        unsafe { slice::from_raw_parts(self.as_ptr(), self.len) }
+    pub fn try_swap_remove(&mut self, index: usize) -> Option<T> {
+        (index < self.len).then(|| unsafe { self.raw_swap_remove(index) })
     }
 
-
-    /// Extracts a mutable slice containing the entire bank.
+    /// Replaces the element at position `index` with `value`, returning the
+    /// element it replaced.  No other element moves.
     /// 
-    /// Equivalent to `&mut bank[..]`.
+    /// # Panics
+    /// 
+    /// Panics if `index` is out of bounds.
     /// 
     /// # Examples
     /// 
     /// ```
-    /// use std::io::{self, Read};
     /// use bankarr::BankArr;
     /// 
-    /// let mut bank = BankArr::<u8, 3>::from([0; 3]);
-    /// io::repeat(0b101).read_exact(bank.as_mut_slice()).unwrap();
+    /// let mut bank = BankArr::<&str, 4>::from(["a", "b", "c"]);
+    /// assert_eq!(bank.replace(1, "x"), "b");
+    /// assert_eq!(bank, ["a", "x", "c"]);
     /// ```
     #[inline]
-    pub const fn as_mut_slice(&mut self) -> &mut [T] {
-        // We are tracking initialized values via len, ensuring the slice is not UB
-        unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) }
+    #[track_caller]
+    pub fn replace(&mut self, index: usize, value: T) -> T {
+        let len = self.len;
+        assert!(index < len, "replace index (is {index}) should be < len (is {len})");
+        unsafe { mem::replace(self.get_unchecked_mut(index), value) }
     }
 
-    #[inline]
-    fn truncate(&mut self, len: usize) {
-        if len > self.len { return }
-
-        unsafe {
-            let rem = self.len - len;
-            let s = ptr::slice_from_raw_parts_mut(self.as_mut_ptr().add(len), rem);
-            self.len = len;
-            s.drop_in_place();
+    /// Replaces the element at position `index` with `value`, returning the
+    /// element it replaced, or handing `value` back if `index` is out of bounds.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let mut bank = BankArr::<&str, 4>::from(["a", "b", "c"]);
+    /// assert_eq!(bank.try_replace(1, "x"), Ok("b"));
+    /// assert_eq!(bank.try_replace(3, "y"), Err("y"));
+    /// assert_eq!(bank, ["a", "x", "c"]);
+    /// ```
+    #[inline]
+    pub fn try_replace(&mut self, index: usize, value: T) -> Result<T, T> {
+        match self.get_mut(index) {
+            Some(slot) => Ok(mem::replace(slot, value)),
+            None => Err(value),
         }
     }
 
+    /// Removes all elements from the bank and returns a double-ended iterator over
+    /// the elements.
+    /// 
+    /// If the iterator is dropped before being fully consumed, it drops the
+    /// remaining elements.  If it's leaked instead, the bank is left holding only
+    /// the elements before the range.
+    /// 
+    /// The returned iterator keeps a mutable borrow on the bank to optimize its
+    /// implementation.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let mut bank = BankArr::<i32, 3>::from([1, 2, 3]);
+    /// let drained: Vec<_> = bank.drain(..).collect();
+    /// 
+    /// assert_eq!(drained, [1, 2, 3]);
+    /// assert_eq!(bank.len(), 0);
+    /// assert_eq!(bank, []);
+    /// ```
+    /// 
+    #[track_caller]
+    pub fn drain<R>(&mut self, range: R) -> drain::Drain<'_, T, Self> 
+    where 
+        R: ops::RangeBounds<usize>,
+    {
+        let range = drain::slice_range(range, ..self.len);
+        self.raw_drain(range)
+    }
+
+    /// Removes the elements in `range` from the bank and returns a double-ended
+    /// iterator over them, or returns `None` if the range is out of bounds.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let mut bank = BankArr::<i32, 4>::from([1, 2, 3, 4]);
+    /// assert!(bank.try_drain(2..5).is_none());
+    /// 
+    /// let drained: Vec<_> = bank.try_drain(1..3).unwrap().collect();
+    /// assert_eq!(drained, [2, 3]);
+    /// assert_eq!(bank, [1, 4]);
+    /// ```
+    pub fn try_drain<R>(&mut self, range: R) -> Option<drain::Drain<'_, T, Self>>
+    where 
+        R: ops::RangeBounds<usize>,
+    {
+        let range = drain::try_slice_range(range, ..self.len)?;
+        Some(self.raw_drain(range))
+    }
+
+    /// Removes all elements from the bank, yielding them in order as fixed-size
+    /// [`BankArr<T, K>`](crate::BankArr) chunks.  The last chunk holds the remainder and
+    /// may be partial.
+    /// 
+    /// If the iterator is dropped before being fully consumed, it drops the
+    /// remaining elements.  `K` must be non-zero, which is checked at compile time.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::{BankArr};
+    /// 
+    /// let mut bank = BankArr::<i32, 5>::from([1, 2, 3, 4, 5]);
+    /// let mut chunks = bank.drain_chunks::<2>();
+    /// 
+    /// assert_eq!(chunks.next().unwrap(), [1, 2]);
+    /// assert_eq!(chunks.next().unwrap(), [3, 4]);
+    /// assert_eq!(chunks.next().unwrap(), [5]);
+    /// assert!(chunks.next().is_none());
+    /// # drop(chunks);
+    /// assert!(bank.is_empty());
+    /// ```
     #[inline]
+    pub fn drain_chunks<const K: usize>(&mut self) -> drain::DrainChunks<'_, T, Self, K> {
+        drain::DrainChunks::new(self.drain(..))
+    }
+
+    /// Moves the elements in `range` onto the back of `dst` in one pass,
+    /// returning how many were moved.
+    /// 
+    /// A [`BankVec`](crate::BankVec) destination grows to take every element.
+    /// A fixed-size destination such as a [`BankArr`](crate::BankArr) takes as
+    /// many as it has room for, from the front of the range, and the rest are
+    /// left in this bank.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the starting point is greater than the end point or if the end
+    /// point is greater than the length of the bank.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::{BankArr, BankVec};
+    /// 
+    /// let mut bank = BankArr::<i32, 4>::from([1, 2, 3, 4]);
+    /// let mut full = BankArr::<i32, 3>::from([0]);
+    /// assert_eq!(bank.drain_into(1.., &mut full), 2);
+    /// assert_eq!((bank.as_slice(), full.as_slice()), (&[1, 4][..], &[0, 2, 3][..]));
+    /// 
+    /// let mut spilling = BankVec::<i32, 1>::new();
+    /// assert_eq!(bank.drain_into(.., &mut spilling), 2);
+    /// assert_eq!((bank.as_slice(), spilling.as_slice()), (&[][..], &[1, 4][..]));
+    /// ```
+    #[track_caller]
+    pub fn drain_into<R, D>(&mut self, range: R, dst: &mut D) -> usize
+    where
+        R: ops::RangeBounds<usize>,
+        D: DrainTarget<T>,
+    {
+        let range = drain::slice_range(range, ..self.len);
+        self.raw_drain_into(range, dst)
+    }
+
+    /// Moves the elements in `range` onto the back of `dst` as
+    /// [`drain_into`](Self::drain_into) does, or returns `None` if the range is
+    /// out of bounds.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::{BankArr, BankVec};
+    /// 
+    /// let mut bank = BankArr::<i32, 4>::from([1, 2, 3, 4]);
+    /// let mut dst = BankVec::<i32, 4>::new();
+    /// assert_eq!(bank.try_drain_into(2..5, &mut dst), None);
+    /// assert_eq!(bank.try_drain_into(1..3, &mut dst), Some(2));
+    /// assert_eq!((bank.as_slice(), dst.as_slice()), (&[1, 4][..], &[2, 3][..]));
+    /// ```
+    pub fn try_drain_into<R, D>(&mut self, range: R, dst: &mut D) -> Option<usize>
+    where
+        R: ops::RangeBounds<usize>,
+        D: DrainTarget<T>,
+    {
+        let range = drain::try_slice_range(range, ..self.len)?;
+        Some(self.raw_drain_into(range, dst))
+    }
+
+    /// Extracts a slice containing the entire bank.
+    /// 
+    /// Equivalent to `&bank[..]`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::io::{self, Write};
+    /// use bankarr::BankArr;
+    /// 
+    /// let bank = BankArr::<u8, 3>::from([1, 2, 3]);
+    /// io::sink().write(bank.as_slice()).unwrap();
+    /// ```
+    #[inline]
+    pub const fn as_slice(&self) -> &[T] {
+        // We are tracking initialized values via len, ensuring the slice is not UB
+        unsafe { slice::from_raw_parts(self.as_ptr(), self.len) }
+    }
+
+
+    /// Extracts a mutable slice containing the entire bank.
+    /// 
+    /// Equivalent to `&mut bank[..]`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::io::{self, Read};
+    /// use bankarr::BankArr;
+    /// 
+    /// let mut bank = BankArr::<u8, 3>::from([0; 3]);
+    /// io::repeat(0b101).read_exact(bank.as_mut_slice()).unwrap();
+    /// ```
+    #[inline]
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        // We are tracking initialized values via len, ensuring the slice is not UB
+        unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) }
+    }
+
+    /// Splits the bank's elements into arrays of `K`, starting at the front,
+    /// and the remainder of fewer than `K`.
+    /// 
+    /// The same as the slice method of the same name, which needs Rust 1.88.
+    /// `K` must be non-zero, which is checked at compile time.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let bank = BankArr::<u8, 8>::from([1, 2, 3, 4, 5]);
+    /// let (chunks, rest) = bank.as_chunks::<2>();
+    /// assert_eq!(chunks, [[1, 2], [3, 4]]);
+    /// assert_eq!(rest, [5]);
+    /// ```
+    #[inline]
+    pub const fn as_chunks<const K: usize>(&self) -> (&[[T; K]], &[T]) {
+        chunks::as_chunks(self.as_slice())
+    }
+
+    /// Splits the bank's elements into mutable arrays of `K`, starting at the
+    /// front, and the remainder of fewer than `K`.
+    /// 
+    /// `K` must be non-zero, which is checked at compile time.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let mut bank = BankArr::<u8, 8>::from([1, 2, 3, 4, 5]);
+    /// let (chunks, _) = bank.as_chunks_mut::<2>();
+    /// chunks.iter_mut().for_each(|chunk| chunk.reverse());
+    /// assert_eq!(bank, [2, 1, 4, 3, 5]);
+    /// ```
+    #[inline]
+    pub const fn as_chunks_mut<const K: usize>(&mut self) -> (&mut [[T; K]], &mut [T]) {
+        chunks::as_chunks_mut(self.as_mut_slice())
+    }
+
+    #[inline]
+    #[cfg(not(feature = "const-mut"))]
+    pub(crate) fn truncate(&mut self, len: usize) { self.raw_truncate(len) }
+
+    /// Removes all elements from the bank, dropping them.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let mut bank = BankArr::<i32, 3>::from([1, 2, 3]);
+    /// bank.clear();
+    /// assert!(bank.is_empty());
+    /// ```
+    #[inline]
+    #[cfg(not(feature = "const-mut"))]
     pub fn clear(&mut self) {
         self.truncate(0);
     }
 
 }
 
+impl<T: Copy, const C: usize> BankArr<T, C> {
+
+    /// Copies all elements of `other` onto the back of the bank.
+    /// 
+    /// Compiles down to a single `memcpy` since `T` is `Copy`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the new length would exceed `C`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let mut bank = BankArr::<u8, 4>::from([1]);
+    /// bank.extend_from_slice(&[2, 3, 4]);
+    /// assert_eq!(bank, [1, 2, 3, 4]);
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(`other.len()`) time.
+    #[inline]
+    #[track_caller]
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        let count = other.len();
+        assert!(
+            count <= self.remaining_capacity(), 
+            "capacity exceeded during operation `extend_from_slice`: {count} more elements \
+            requested with len {} and capacity {C}", self.len
+        );
+        unsafe { self.raw_extend_from_slice(other) }
+    }
+
+    /// Attempts to copy all elements of `other` onto the back of the bank.
+    /// 
+    /// Leaves the bank untouched and returns an error if `other` doesn't fit.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let mut bank = BankArr::<u8, 4>::from([1]);
+    /// assert!(bank.try_extend_from_slice(&[2, 3]).is_ok());
+    /// assert!(bank.try_extend_from_slice(&[4, 5]).is_err());
+    /// assert_eq!(bank, [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), BankFullError> {
+        if other.len() > self.remaining_capacity() { return Err(BankFullError::new(())) }
+        unsafe { self.raw_extend_from_slice(other) }
+        Ok(())
+    }
+}
+
+impl<T: Clone, const C: usize> BankArr<T, C> {
+
+    /// Clones the bank's elements into a new [`Vec`], leaving the bank intact.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let bank = BankArr::<String, 3>::from(["a".to_string(), "b".to_string()]);
+    /// let vec = bank.to_vec();
+    /// assert_eq!(bank, vec);
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(`len`) time.
+    #[inline]
+    pub fn to_vec(&self) -> Vec<T> { self.as_slice().to_vec() }
+}
+
+impl<T: Default, const C: usize> BankArr<T, C> {
+
+    /// Takes the element at position `index`, leaving `T::default()` in its
+    /// place.  No other element moves.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index` is out of bounds.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let mut bank = BankArr::<String, 4>::from(["a", "b", "c"].map(String::from));
+    /// assert_eq!(bank.take(0), "a");
+    /// assert_eq!(bank.as_slice(), ["", "b", "c"]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn take(&mut self, index: usize) -> T { self.replace(index, T::default()) }
+
+    /// Takes the element at position `index`, leaving `T::default()` in its
+    /// place, or returns `None` if `index` is out of bounds.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let mut bank = BankArr::<String, 4>::from(["a", "b", "c"].map(String::from));
+    /// assert_eq!(bank.try_take(0).as_deref(), Some("a"));
+    /// assert_eq!(bank.try_take(3), None);
+    /// assert_eq!(bank.as_slice(), ["", "b", "c"]);
+    /// ```
+    #[inline]
+    pub fn try_take(&mut self, index: usize) -> Option<T> {
+        self.get_mut(index).map(mem::take)
+    }
+}
+
+impl<T: Ord, const C: usize> BankArr<T, C> {
+
+    /// Sorts the bank without preserving the order of equal elements, using a
+    /// fixed sorting network when it holds at most 16 elements.
+    /// 
+    /// For the handful of elements banks are designed around, a network beats
+    /// the setup cost of [`slice::sort_unstable`], which longer banks fall
+    /// back to.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let mut bank = BankArr::<i32, 8>::from([5, -1, 3, 0, 3]);
+    /// bank.sort_small();
+    /// assert_eq!(bank, [-1, 0, 3, 3, 5]);
+    /// ```
+    #[inline]
+    pub fn sort_small(&mut self) {
+        sort::sort_small(self.as_mut_slice())
+    }
+}
+
 impl<T: PartialEq, const C: usize> BankArr<T, C> {
 
+    /// Returns the index of the first element equal to `value`, or `None` if
+    /// there is none.
+    /// 
+    /// With the `simd` feature enabled, banks of primitive integers are
+    /// searched a vector at a time.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let bank = BankArr::<i32, 4>::from([1, 2, 3, 2]);
+    /// assert_eq!(bank.position(&2), Some(1));
+    /// assert_eq!(bank.position(&5), None);
+    /// ```
+    #[inline]
+    pub fn position(&self, value: &T) -> Option<usize> {
+        spec::find(self.as_slice(), value)
+    }
+
+    /// Returns `true` if the bank contains an element equal to `value`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let bank = BankArr::<i32, 4>::from([1, 2, 3]);
+    /// assert!(bank.contains(&2));
+    /// assert!(!bank.contains(&5));
+    /// ```
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool { self.position(value).is_some() }
+
 
     /// Removes the item from the bank and returns true if the item existed,
     /// otherwise returns false.
@@ -717,23 +1447,13 @@ impl<T: PartialEq, const C: usize> BankArr<T, C> {
     /// 
     /// assert_eq!(bank, [1, 4, 3]);
     /// ```
-    ///
+    /// 
     #[inline]
     pub fn remove_item(&mut self, value: &T) -> bool {
-        unsafe {
-            let ptr: NonNull<T> = NonNull::new_unchecked(self.data.as_mut_ptr().cast());
-
-            for index in 0usize..self.len {
-                let cp_ptr = ptr.add(index);
-                if cp_ptr.as_ref() == value {
-                    self.len -= 1;
-                    cp_ptr.replace(ptr.add(self.len).read());
-                    return true
-                }
-            }
+        match self.position(value) {
+            Some(index) => { self.swap_remove(index); true }
+            None => false
         }
-
-        false
     }
 }
 
@@ -742,6 +1462,7 @@ impl<T: PartialEq, const C: usize> BankArr<T, C> {
 
 #[cfg(test)]
 mod tests {
+    use crate::BankVec;
     use super::*;
 
     type B = BankArr<u32, 4>;
@@ -780,7 +1501,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "capacity is 4")]
     fn push_to_full() {
         let mut bank = B::new();
         for i in 0..4 { bank.push(i); }
@@ -791,7 +1512,37 @@ mod tests {
     fn try_push() {
         let mut bank = B::from([3, 4, 5]);
         assert!(bank.try_push(6).is_ok());
-        assert!(bank.try_push(7).is_err());
+        assert_eq!(bank.try_push(7).unwrap_err().element(), 7);
+    }
+
+    #[test]
+    fn try_extend() {
+        let mut bank = BankArr::<String, 3>::from(["a".to_string()]);
+        assert!(bank.try_extend(["b".to_string()]).is_ok());
+
+        let err = bank.try_extend(["c", "d", "e"].map(String::from)).unwrap_err();
+        assert_eq!(err.element(), "d");
+        assert_eq!(bank.as_slice(), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn try_from_array_vec() {
+        assert_eq!(B::try_from_array([1, 2]).unwrap(), [1, 2]);
+        assert_eq!(B::try_from_array([0; 5]).unwrap_err(), [0; 5]);
+
+        assert_eq!(B::try_from_vec(vec![1, 2, 3, 4]).unwrap(), [1, 2, 3, 4]);
+        assert_eq!(B::try_from_vec(vec![0; 5]).unwrap_err(), [0; 5]);
+    }
+
+    #[test]
+    fn try_push_boxed_error() {
+        fn fill(bank: &mut B) -> Result<(), Box<dyn std::error::Error>> {
+            loop { bank.try_push(0)?; }
+        }
+        let mut bank = B::new();
+        let err = fill(&mut bank).unwrap_err();
+        assert_eq!(err.to_string(), "bank is full");
+        assert_eq!(bank.remaining_capacity(), 0);
     }
 
     #[test]
@@ -825,7 +1576,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "removal index (is 3) should be < len (is 3)")]
     fn remove_out_of_bounds() {
         let mut bank = B::from([3, 4, 5]);
         bank.remove(3);
@@ -838,8 +1589,8 @@ mod tests {
         let did_insert = bank.insert(1, 4);
         let didnt_insert = bank.insert(2, 0);
 
-        assert_eq!(did_insert, true);
-        assert_eq!(didnt_insert, false);
+        assert!(did_insert);
+        assert!(!didnt_insert);
         assert_eq!(bank, [3, 4, 5, 6]);
     }
 
@@ -851,6 +1602,172 @@ mod tests {
         bank.insert(3, 0);
     }
 
+    #[test]
+    fn replace_take() {
+        let mut bank = BankArr::<String, 4>::from(["a", "b", "c"].map(String::from));
+        assert_eq!(bank.replace(2, "d".to_string()), "c");
+        assert_eq!(bank.take(1), "b");
+        assert_eq!(bank.as_slice(), ["a", "", "d"]);
+
+        let result = std::panic::catch_unwind(move || bank.replace(3, String::new()));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn try_replace_take() {
+        let mut bank = BankArr::<String, 4>::from(["a", "b", "c"].map(String::from));
+        assert_eq!(bank.try_replace(2, "d".to_string()), Ok("c".to_string()));
+        assert_eq!(bank.try_replace(3, "e".to_string()), Err("e".to_string()));
+        assert_eq!(bank.try_take(1).as_deref(), Some("b"));
+        assert_eq!(bank.try_take(3), None);
+        assert_eq!(bank.as_slice(), ["a", "", "d"]);
+    }
+
+    #[test]
+    fn extend_panic_safe() {
+        use std::{cell::Cell, panic::{self, AssertUnwindSafe}};
+
+        // An iterator panicking partway leaves the items before it in the bank
+        let mut bank = BankArr::<String, 4>::from(["a".to_string()]);
+        let items = ["b", "c", "d"].into_iter().map(|s| if s == "d" { panic!() } else { s.to_string() });
+        assert!(panic::catch_unwind(AssertUnwindSafe(|| bank.extend(items))).is_err());
+        assert_eq!(bank.as_slice(), ["a", "b", "c"]);
+
+        // Zero-sized elements are dropped once, when the bank is
+        thread_local! { static DROPS: Cell<usize> = const { Cell::new(0) } }
+        struct Unit;
+        impl Drop for Unit {
+            fn drop(&mut self) { DROPS.with(|drops| drops.set(drops.get() + 1)) }
+        }
+
+        let mut bank = BankArr::<Unit, 3>::new();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| bank.extend((0..4).map(|_| Unit))));
+        assert!(result.is_err());
+        assert_eq!((bank.len(), DROPS.with(Cell::get)), (3, 1));
+        drop(bank);
+        assert_eq!(DROPS.with(Cell::get), 4);
+    }
+
+    #[test]
+    fn covariant() {
+        fn shorten<'short>(bank: BankArr<&'static str, 3>, _: &'short str) -> BankArr<&'short str, 3> { bank }
+
+        let local = String::from("c");
+        let mut bank = shorten(BankArr::from(["a", "b"]), &local);
+        bank.push(&local);
+        assert_eq!(bank, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn drain_into() {
+        let mut bank = BankArr::<String, 4>::from(["a", "b", "c", "d"].map(String::from));
+
+        // Only one element fits, the other stays put
+        let mut dst = BankArr::<String, 2>::from(["x".to_string()]);
+        assert_eq!(bank.drain_into(1..3, &mut dst), 1);
+        assert_eq!(bank.as_slice(), ["a", "c", "d"]);
+        assert_eq!(dst.as_slice(), ["x", "b"]);
+        assert_eq!(bank.drain_into(.., &mut dst), 0);
+
+        let mut dst = BankVec::<String, 1>::new();
+        assert_eq!(bank.drain_into(.., &mut dst), 3);
+        assert!(bank.is_empty());
+        assert_eq!(dst.as_slice(), ["a", "c", "d"]);
+    }
+
+    #[test]
+    fn try_drain_into() {
+        let mut bank = BankArr::<String, 4>::from(["a", "b", "c"].map(String::from));
+        let mut dst = BankArr::<String, 2>::new();
+
+        assert_eq!(bank.try_drain_into(2..4, &mut dst), None);
+        assert_eq!(bank.try_drain_into((ops::Bound::Excluded(2), ops::Bound::Excluded(2)), &mut dst), None);
+        assert_eq!(bank.len(), 3);
+
+        assert_eq!(bank.try_drain_into(.., &mut dst), Some(2));
+        assert_eq!(bank.as_slice(), ["c"]);
+        assert_eq!(dst.as_slice(), ["a", "b"]);
+    }
+
+    #[test]
+    fn try_into_array() {
+        let bank = BankArr::<String, 3>::from(["a", "b"].map(String::from));
+        let bank = <[String; 3]>::try_from(bank).unwrap_err();
+        assert_eq!(<[String; 2]>::try_from(bank).unwrap(), ["a", "b"]);
+    }
+
+    #[test]
+    fn from_array() {
+        const BANK: B = BankArr::from_array([1, 2, 3]);
+        assert_eq!(BANK, [1, 2, 3]);
+
+        let bank = BankArr::<String, 2>::from_array(["a".to_string()]);
+        assert_eq!(bank, ["a".to_string()]);
+    }
+
+    #[test]
+    fn const_push_pop() {
+        const BANK: B = {
+            let mut bank = B::new();
+            let _ = bank.try_push(1);
+            let _ = bank.try_push(2);
+            unsafe { bank.push_unchecked(3); }
+            bank.pop();
+            bank
+        };
+        assert_eq!(BANK, [1, 2]);
+        assert_eq!(BANK.as_slice(), [1, 2]);
+    }
+
+    #[test]
+    #[cfg(feature = "const-mut")]
+    fn const_push_clear() {
+        const NUMS: B = {
+            let mut bank = B::new();
+            bank.push(1);
+            bank.push(2);
+            bank.clear();
+            bank.push(3);
+            bank
+        };
+        assert_eq!(NUMS, [3]);
+
+        // Runtime use is unaffected by the `Destruct` bound
+        let mut bank = BankArr::<String, 2>::from(["a".to_string()]);
+        bank.clear();
+        assert!(bank.is_empty());
+    }
+
+    #[test]
+    fn try_insert() {
+        let mut bank = B::from([3, 5, 6]);
+
+        assert_eq!(bank.try_insert(4, 0), Err(InsertError::OutOfBounds(0)));
+        assert_eq!(bank.try_insert(1, 4), Ok(()));
+        assert_eq!(bank.try_insert(0, 0).unwrap_err().element(), 0);
+        assert_eq!(bank, [3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn try_remove() {
+        let mut bank = B::from([3, 4, 5]);
+
+        assert_eq!(bank.try_remove(3), None);
+        assert_eq!(bank.try_swap_remove(3), None);
+        assert_eq!(bank.try_remove(0), Some(3));
+        assert_eq!(bank.try_swap_remove(0), Some(4));
+        assert_eq!(bank, [5]);
+    }
+
+    #[test]
+    fn try_drain() {
+        let mut bank = B::from([1, 2, 3, 4]);
+
+        assert!(bank.try_drain(3..=4).is_none());
+        assert_eq!(bank.try_drain(1..).unwrap().collect::<Vec<_>>(), [2, 3, 4]);
+        assert_eq!(bank, [1]);
+    }
+
     #[test]
     fn extend() {
         let mut bank = BankArr::<i32, 16>::from([1, 2]);
@@ -863,6 +1780,45 @@ mod tests {
         assert_eq!(bank, [(); 6]);
     }
 
+    #[test]
+    fn extend_ref() {
+        let mut bank = BankArr::<i32, 4>::from([1]);
+        bank.extend([2, 3].iter());
+        bank.extend(&[4]);
+        assert_eq!(bank, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn extend_from_slice() {
+        let mut bank = BankArr::<i32, 4>::from([1]);
+        bank.extend_from_slice(&[2, 3, 4]);
+        assert_eq!(bank, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn extend_from_slice_panics() {
+        let mut bank = BankArr::<i32, 3>::from([1, 2]);
+        bank.extend_from_slice(&[3, 4]);
+    }
+
+    #[test]
+    fn extend_bulk() {
+        let mut bank = BankArr::<u8, 8>::new();
+        bank.extend([1, 2]);
+        bank.extend(vec![3, 4]);
+        bank.extend(&[5, 6]);
+        bank.extend([7].iter());
+        assert_eq!(bank, [1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity exceeded during operation `extend`: capacity is 3")]
+    fn extend_bulk_panics() {
+        let mut bank = BankArr::<u8, 3>::from([1, 2]);
+        bank.extend(&[3, 4]);
+    }
+
     #[test]
     #[should_panic]
     fn extend_panics() {
@@ -880,8 +1836,7 @@ mod tests {
     #[test]
     fn drain() {
         let mut bank = B::from([3, 4, 5]);
-        let drained = bank.drain(..)
-            .into_iter().collect::<Vec<u32>>();
+        let drained = bank.drain(..).collect::<Vec<u32>>();
 
         assert_eq!(bank.len(), 0);
         assert_eq!(drained, vec![3, 4, 5]);
@@ -903,11 +1858,77 @@ mod tests {
         assert_eq!(drain.next_back(), None);
     }
 
+    #[test]
+    #[cfg(any(debug_assertions, feature = "strict-asserts"))]
+    #[should_panic(expected = "push_unchecked called on a full bank")]
+    fn push_unchecked_checked() {
+        let mut bank = BankArr::<i32, 2>::from([1, 2]);
+        unsafe { bank.push_unchecked(3) };
+    }
+
+    #[test]
+    fn auto_traits() {
+        use std::{cell::Cell, marker::PhantomPinned, panic::{self, RefUnwindSafe, UnwindSafe}};
+
+        fn unwind_safe<T: UnwindSafe>() {}
+        fn ref_unwind_safe<T: RefUnwindSafe>() {}
+        fn unpin<T: Unpin>() {}
+
+        unwind_safe::<BankArr<Cell<i32>, 2>>();
+        ref_unwind_safe::<BankArr<String, 2>>();
+        unpin::<BankArr<PhantomPinned, 2>>();
+
+        let bank = BankArr::<i32, 2>::from([1, 2]);
+        assert_eq!(panic::catch_unwind(move || bank.len()).ok(), Some(2));
+    }
+
+    #[test]
+    #[cfg(feature = "may-dangle")]
+    fn may_dangle() {
+        // Declared first, so it's dropped after the strings it borrows
+        let mut bank = BankArr::<&mut String, 2>::new();
+        let (mut a, mut b) = ("a".to_string(), "b".to_string());
+        bank.push(&mut a);
+        bank.push(&mut b);
+        bank.iter_mut().for_each(|s| s.push('!'));
+        assert_eq!(bank.len(), 2);
+    }
+
+    #[test]
+    fn zst_large() {
+        thread_local! { static DROPS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) } }
+
+        // Zero-sized, but counts its drops
+        #[derive(Clone)]
+        struct Tick;
+        impl Drop for Tick {
+            fn drop(&mut self) { DROPS.set(DROPS.get() + 1) }
+        }
+
+        const N: usize = 1 << 20;
+        let mut bank = BankArr::<Tick, N>::new();
+        bank.extend(std::iter::repeat_n(Tick, N - 1));
+        bank.push(Tick);
+        assert_eq!(bank.remaining_capacity(), 0);
+        assert_eq!(bank.iter().count(), N);
+        assert_eq!(DROPS.get(), 0);
+
+        assert_eq!(bank.drain(10..N - 10).len(), N - 20);
+        assert_eq!(bank.len(), 20);
+        assert_eq!(DROPS.get(), N - 20);
+
+        let mut iter = bank.into_iter();
+        assert!(iter.next_back().is_some());
+        assert_eq!(iter.by_ref().skip(5).count(), 14);
+        drop(iter);
+        assert_eq!(DROPS.get(), N);
+    }
+
     #[test]
     fn iter() {
         let bank = B::from([3, 4, 5]);
         let collected = bank.iter()
-            .map(|v| *v)
+            .copied()
             .collect::<Vec<u32>>();
 
         assert_eq!(bank, collected); 
@@ -946,10 +1967,17 @@ mod tests {
 
         assert_eq!(popped, Some("bb".to_string()));
         assert_eq!(removed, "aa".to_string());
-        assert_eq!(inserted, true);
+        assert!(inserted);
         assert_eq!(bank, ["dd".to_string(), "ff".to_string()])
     }
 
+    #[test]
+    fn debug() {
+        let bank = BankArr::<i32, 4>::from([1, 2]);
+        assert_eq!(format!("{bank:?}"), "BankArr<T, 4> { data: [1, 2], capacity: 4 }");
+        assert!(format!("{bank:#?}").contains("remaining_capacity: 2"));
+    }
+
     #[test]
     fn clone() {
         let bank = BankArr::<_, 2>::from(["aa".to_string(), "bb".to_string()]);
@@ -963,6 +1991,38 @@ mod tests {
         let vec: Vec<i32> = bank.into();
         assert_eq!(vec, [1, 2, 3, 4]);
 
+        let bank = BankArr::<String, 4>::from(["aa".to_string(), "bb".to_string()]);
+        assert_eq!(bank, bank.to_vec());
+        assert_eq!(bank, Vec::from(&bank));
+
+        let vec = Vec::from(bank);
+        assert_eq!(vec, ["aa".to_string(), "bb".to_string()]);
+    }
+
+    #[test]
+    fn cow() {
+        let bank = BankArr::<String, 3>::from(Cow::Borrowed(&["aa".to_string()][..]));
+        assert_eq!(bank, ["aa".to_string()]);
+
+        let bank = BankArr::<i32, 3>::from(Cow::Owned(vec![1, 2, 3]));
+        assert_eq!(bank, [1, 2, 3]);
+
+        let cow: Cow<'_, [i32]> = (&bank).into();
+        assert!(matches!(cow, Cow::Borrowed([1, 2, 3])));
+    }
+
+    #[test]
+    #[should_panic]
+    fn cow_exceeds_capacity() {
+        let _ = BankArr::<i32, 2>::from(Cow::Borrowed(&[1, 2, 3][..]));
+    }
+
+    #[test]
+    fn concat_join() {
+        let banks = [BankArr::<i32, 3>::from([1, 2]), BankArr::from([3])];
+        assert_eq!(banks.concat(), [1, 2, 3]);
+        assert_eq!(banks.join(&0), [1, 2, 0, 3]);
+        assert_eq!(banks.join(&[0, 0][..]), [1, 2, 0, 0, 3]);
     }
 
     #[test]
@@ -992,6 +2052,18 @@ mod tests {
         assert_eq!(bank.len(), 0);
     }
 
+    #[test]
+    fn position() {
+        let bank = BankArr::<u8, 40>::from([7; 40]);
+        assert_eq!(bank.position(&7), Some(0));
+        assert!(!bank.contains(&8));
+
+        let mut bank = BankArr::<u8, 40>::from([0; 39]);
+        bank.push(1);
+        assert_eq!(bank.position(&1), Some(39));
+        assert!(bank.contains(&1));
+    }
+
     #[test]
     fn remove_item() {
         let mut bank = BankArr::<i32, 3>::from([1, 2, 3]);
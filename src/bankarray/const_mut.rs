@@ -0,0 +1,92 @@
+// Nightly `const` counterparts of methods which can't be `const` on stable.  Kept
+// in their own module as the `[const]` bound syntax is gated even when cfg'd out.
+
+use std::{intrinsics::const_eval_select, marker::Destruct, mem, ptr};
+
+use super::BankArr;
+use crate::raw;
+
+
+// Const panics can't format their message, so only a runtime panic reports the
+// capacity.
+#[cold]
+#[track_caller]
+const fn push_capacity_exceeded(capacity: usize) -> ! {
+    const fn in_const(_: usize) -> ! { panic!("capacity exceeded during operation `push`") }
+    #[track_caller]
+    fn at_runtime(capacity: usize) -> ! { raw::capacity_exceeded("push", capacity) }
+
+    const_eval_select((capacity,), in_const, at_runtime)
+}
+
+impl<T, const C: usize> BankArr<T, C> {
+
+    /// Appends an element to the back of the collection.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the new capacity exceeds the size, `C`.
+    /// For a panic-free `push`, see [`try_push`](BankArr::try_push).
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// const POWERS: BankArr<u32, 8> = {
+    ///     let mut bank = BankArr::new();
+    ///     let mut n = 1;
+    ///     while n < 100 {
+    ///         bank.push(n);
+    ///         n *= 3;
+    ///     }
+    ///     bank
+    /// };
+    /// assert_eq!(POWERS, [1, 3, 9, 27, 81]);
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(1) time.
+    #[inline]
+    #[track_caller]
+    pub const fn push(&mut self, value: T) {
+        if self.len == C { push_capacity_exceeded(C) }
+        unsafe { self.push_unchecked(value) }
+    }
+
+    #[inline]
+    pub(crate) const fn truncate(&mut self, len: usize) where T: [const] Destruct {
+        if len > self.len { return }
+
+        unsafe {
+            let rem = self.len - len;
+            let s = ptr::slice_from_raw_parts_mut(self.as_mut_ptr().add(len), rem);
+            self.len = len;
+            if mem::needs_drop::<T>() { s.drop_in_place() }
+        }
+    }
+
+    /// Removes all elements from the bank, dropping them.
+    /// 
+    /// Calling this in a `const` context requires `const_trait_impl`, as `T` must
+    /// be destructible at compile time.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// #![feature(const_trait_impl)]
+    /// use bankarr::BankArr;
+    /// 
+    /// const EMPTY: BankArr<i32, 3> = {
+    ///     let mut bank = BankArr::from_array([1, 2, 3]);
+    ///     bank.clear();
+    ///     bank
+    /// };
+    /// assert!(EMPTY.is_empty());
+    /// ```
+    #[inline]
+    pub const fn clear(&mut self) where T: [const] Destruct {
+        self.truncate(0);
+    }
+}
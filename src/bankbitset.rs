@@ -0,0 +1,446 @@
+//! A fixed-size set of bits, see [`BankBitSet`].
+
+use std::{fmt, iter::FusedIterator, mem, ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not}};
+
+
+/// A fixed-size bitset holding `BITS` flags inline, packed into `WORDS` 64-bit
+/// words.
+/// 
+/// Stable Rust can't size an array from an expression over `BITS`, so sets of
+/// more than 64 bits name their word count too, which must be at least
+/// `BITS.div_ceil(64)`.  Getting it wrong is a compile-time error.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::BankBitSet;
+/// 
+/// let mut flags = BankBitSet::<10>::new();
+/// flags.set(1);
+/// flags.set(7);
+/// assert!(flags.test(7));
+/// assert_eq!(flags.iter().collect::<Vec<_>>(), [1, 7]);
+/// 
+/// let wide = BankBitSet::<100, 2>::from_iter([3, 99]);
+/// assert_eq!(wide.count(), 2);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BankBitSet<const BITS: usize, const WORDS: usize = 1> {
+    words: [u64; WORDS],
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<const BITS: usize, const WORDS: usize> fmt::Debug for BankBitSet<BITS, WORDS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<const BITS: usize, const WORDS: usize> Default for BankBitSet<BITS, WORDS> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+
+impl<const BITS: usize, const WORDS: usize> Extend<usize> for BankBitSet<BITS, WORDS> {
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, indices: I) {
+        indices.into_iter().for_each(|index| { self.set(index); });
+    }
+}
+
+impl<const BITS: usize, const WORDS: usize> FromIterator<usize> for BankBitSet<BITS, WORDS> {
+    #[track_caller]
+    fn from_iter<I: IntoIterator<Item = usize>>(indices: I) -> Self {
+        let mut set = Self::new();
+        set.extend(indices);
+        set
+    }
+}
+
+impl<'a, const BITS: usize, const WORDS: usize> IntoIterator for &'a BankBitSet<BITS, WORDS> {
+    type Item = usize;
+    type IntoIter = Iter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+macro_rules! impl_bit_op {
+    ($op:ident::$method:ident, $op_assign:ident::$method_assign:ident, $symbol:tt) => {
+        impl<const BITS: usize, const WORDS: usize> $op_assign for BankBitSet<BITS, WORDS> {
+            #[inline]
+            fn $method_assign(&mut self, other: Self) {
+                self.words.iter_mut().zip(other.words).for_each(|(word, other)| *word $symbol other);
+            }
+        }
+
+        impl<const BITS: usize, const WORDS: usize> $op for BankBitSet<BITS, WORDS> {
+            type Output = Self;
+
+            #[inline]
+            fn $method(mut self, other: Self) -> Self::Output {
+                self.$method_assign(other);
+                self
+            }
+        }
+    };
+}
+
+impl_bit_op!(BitAnd::bitand, BitAndAssign::bitand_assign, &=);
+impl_bit_op!(BitOr::bitor, BitOrAssign::bitor_assign, |=);
+impl_bit_op!(BitXor::bitxor, BitXorAssign::bitxor_assign, ^=);
+
+impl<const BITS: usize, const WORDS: usize> Not for BankBitSet<BITS, WORDS> {
+    type Output = Self;
+
+    /// Flips every bit below `BITS`.
+    #[inline]
+    fn not(mut self) -> Self::Output {
+        self.words.iter_mut().for_each(|word| *word = !*word);
+        self.mask_tail();
+        self
+    }
+}
+
+impl<const BITS: usize, const WORDS: usize> BankBitSet<BITS, WORDS> {
+
+    const FITS: () = assert!(
+        BITS <= WORDS.saturating_mul(64),
+        "`WORDS` is too small to hold `BITS` bits, it must be at least `BITS.div_ceil(64)`",
+    );
+
+    /// Constructs a new `BankBitSet` with every bit clear.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankBitSet;
+    /// 
+    /// let flags = BankBitSet::<32>::new();
+    /// assert!(flags.is_empty());
+    /// ```
+    pub const fn new() -> Self {
+        let () = Self::FITS;
+        Self { words: [0; WORDS] }
+    }
+
+    /// Constructs a new `BankBitSet` with every bit set.
+    pub const fn full() -> Self {
+        let mut set = Self { words: [u64::MAX; WORDS] };
+        set.mask_tail();
+        set
+    }
+
+    // Clears the unused bits past `BITS` in the last words.
+    #[inline]
+    const fn mask_tail(&mut self) {
+        let () = Self::FITS;
+        let mut word = BITS / 64;
+        if !BITS.is_multiple_of(64) {
+            self.words[word] &= (1 << (BITS % 64)) - 1;
+            word += 1;
+        }
+        while word < WORDS {
+            self.words[word] = 0;
+            word += 1;
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    const fn locate(index: usize) -> (usize, u64) {
+        assert!(index < BITS, "bit index out of bounds");
+        (index / 64, 1 << (index % 64))
+    }
+
+    /// Returns the number of bits in the set, `BITS`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { BITS }
+
+    /// Sets the bit at `index`, returning `true` if it was previously clear.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index` isn't less than `BITS`.
+    #[inline]
+    #[track_caller]
+    pub const fn set(&mut self, index: usize) -> bool {
+        let (word, mask) = Self::locate(index);
+        let was_clear = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        was_clear
+    }
+
+    /// Clears the bit at `index`, returning `true` if it was previously set.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index` isn't less than `BITS`.
+    #[inline]
+    #[track_caller]
+    pub const fn clear(&mut self, index: usize) -> bool {
+        let (word, mask) = Self::locate(index);
+        let was_set = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        was_set
+    }
+
+    /// Flips the bit at `index`, returning its new value.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index` isn't less than `BITS`.
+    #[inline]
+    #[track_caller]
+    pub const fn toggle(&mut self, index: usize) -> bool {
+        let (word, mask) = Self::locate(index);
+        self.words[word] ^= mask;
+        self.words[word] & mask != 0
+    }
+
+    /// Returns `true` if the bit at `index` is set.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index` isn't less than `BITS`.
+    #[inline]
+    #[track_caller]
+    pub const fn test(&self, index: usize) -> bool {
+        let (word, mask) = Self::locate(index);
+        self.words[word] & mask != 0
+    }
+
+    /// Returns `true` if the bit at `index` is set, or `false` if it's clear or
+    /// out of bounds.
+    #[inline]
+    pub const fn get(&self, index: usize) -> bool { index < BITS && self.test(index) }
+
+    /// Clears every bit.
+    #[inline]
+    pub const fn clear_all(&mut self) { self.words = [0; WORDS] }
+
+    /// Returns the number of set bits.
+    #[inline]
+    pub fn count(&self) -> usize { self.words.iter().map(|word| word.count_ones() as usize).sum() }
+
+    /// Returns `true` if no bits are set.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.words.iter().all(|&word| word == 0) }
+
+    /// Returns `true` if every bit is set.
+    #[inline]
+    pub fn is_full(&self) -> bool { *self == Self::full() }
+
+    /// Returns `true` if every bit set in `self` is also set in `other`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankBitSet;
+    /// 
+    /// let a = BankBitSet::<8>::from_iter([1, 2]);
+    /// let b = BankBitSet::<8>::from_iter([1, 2, 5]);
+    /// assert!(a.is_subset(&b));
+    /// assert!(!b.is_subset(&a));
+    /// ```
+    #[inline]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.words.iter().zip(&other.words).all(|(word, other)| word & !other == 0)
+    }
+
+    /// Returns `true` if `self` and `other` have no bits set in common.
+    #[inline]
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.words.iter().zip(&other.words).all(|(word, other)| word & other == 0)
+    }
+
+    /// Returns the lowest set index, or `None` if no bits are set.
+    #[inline]
+    pub fn first(&self) -> Option<usize> { self.iter().next() }
+
+    /// Returns the highest set index, or `None` if no bits are set.
+    #[inline]
+    pub fn last(&self) -> Option<usize> { self.iter().next_back() }
+
+    /// Returns an iterator over the indices of the set bits, in ascending order.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankBitSet;
+    /// 
+    /// let flags = BankBitSet::<130, 3>::from_iter([129, 0, 64]);
+    /// assert_eq!(flags.iter().collect::<Vec<_>>(), [0, 64, 129]);
+    /// assert_eq!(flags.iter().rev().collect::<Vec<_>>(), [129, 64, 0]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_> {
+        let words = &self.words[..];
+        Iter {
+            front: words.first().copied().unwrap_or(0),
+            back: if words.len() > 1 { words[words.len() - 1] } else { 0 },
+            front_index: 0,
+            back_index: words.len().saturating_sub(1),
+            words,
+        }
+    }
+
+    /// Returns the set's words, bit `i` being bit `i % 64` of word `i / 64`.
+    #[inline]
+    pub const fn as_words(&self) -> &[u64; WORDS] { &self.words }
+}
+
+
+/// An iterator over the indices of the set bits in a [`BankBitSet`].
+/// 
+/// Created by [`BankBitSet::iter`].
+#[derive(Debug, Clone)]
+pub struct Iter<'a> {
+    words: &'a [u64],
+    // The unvisited bits of the words at `front_index` and `back_index`.  When
+    // the two meet, `front` holds the word's bits and `back` is unused.
+    front: u64,
+    back: u64,
+    front_index: usize,
+    back_index: usize,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.front != 0 {
+                let bit = self.front.trailing_zeros() as usize;
+                self.front &= self.front - 1;
+                return Some(self.front_index * 64 + bit);
+            }
+            if self.front_index >= self.back_index { return None }
+
+            self.front_index += 1;
+            self.front = match self.front_index == self.back_index {
+                true => mem::take(&mut self.back),
+                false => self.words[self.front_index],
+            };
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let mut len = self.front.count_ones() as usize;
+        if self.back_index > self.front_index {
+            len += self.back.count_ones() as usize;
+            len += self.words[self.front_index + 1..self.back_index].iter()
+                .map(|word| word.count_ones() as usize)
+                .sum::<usize>();
+        }
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Iter<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            let (bits, index) = match self.front_index == self.back_index {
+                true => (&mut self.front, self.front_index),
+                false => (&mut self.back, self.back_index),
+            };
+            if *bits != 0 {
+                let bit = 63 - bits.leading_zeros() as usize;
+                *bits &= !(1 << bit);
+                return Some(index * 64 + bit);
+            }
+            if self.front_index >= self.back_index { return None }
+
+            self.back_index -= 1;
+            if self.back_index != self.front_index {
+                self.back = self.words[self.back_index];
+            }
+        }
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+impl FusedIterator for Iter<'_> {}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn set_clear_test() {
+        let mut flags = BankBitSet::<70, 2>::new();
+        assert!(flags.set(3));
+        assert!(!flags.set(3));
+        assert!(flags.set(69));
+        assert!(flags.test(3) && flags.test(69));
+        assert!(!flags.test(4));
+        assert!(!flags.get(70));
+        assert_eq!(flags.count(), 2);
+
+        assert!(flags.clear(3));
+        assert!(!flags.clear(3));
+        assert!(flags.toggle(0));
+        assert!(!flags.toggle(0));
+        assert_eq!(flags.as_words(), &[0, 1 << 5]);
+
+        flags.clear_all();
+        assert!(flags.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "bit index out of bounds")]
+    fn set_out_of_bounds() {
+        BankBitSet::<10>::new().set(10);
+    }
+
+    #[test]
+    fn full_and_not() {
+        let full = BankBitSet::<70, 2>::full();
+        assert_eq!(full.count(), 70);
+        assert!(full.is_full());
+        assert!((!full).is_empty());
+
+        let odd = BankBitSet::<70, 2>::from_iter((1..70).step_by(2));
+        assert_eq!((!odd).count(), 35);
+        assert!((!odd).is_disjoint(&odd));
+        assert_eq!(!odd | odd, full);
+    }
+
+    #[test]
+    fn bulk_ops() {
+        let a = BankBitSet::<8>::from_iter([0, 1, 2]);
+        let b = BankBitSet::<8>::from_iter([1, 2, 3]);
+        assert_eq!((a & b).iter().collect::<Vec<_>>(), [1, 2]);
+        assert_eq!((a | b).iter().collect::<Vec<_>>(), [0, 1, 2, 3]);
+        assert_eq!((a ^ b).iter().collect::<Vec<_>>(), [0, 3]);
+        assert!((a & b).is_subset(&a));
+
+        let mut c = a;
+        c ^= b;
+        c |= BankBitSet::from_iter([7]);
+        c &= !BankBitSet::from_iter([0]);
+        assert_eq!(c.iter().collect::<Vec<_>>(), [3, 7]);
+    }
+
+    #[test]
+    fn iter() {
+        let flags = BankBitSet::<200, 4>::from_iter([0, 63, 64, 127, 150, 199]);
+        let mut iter = flags.iter();
+        assert_eq!(iter.len(), 6);
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next_back(), Some(199));
+        assert_eq!(iter.next_back(), Some(150));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(63));
+        assert_eq!(iter.next_back(), Some(127));
+        assert_eq!(iter.next(), Some(64));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        assert_eq!(flags.first(), Some(0));
+        assert_eq!(flags.last(), Some(199));
+        assert_eq!(BankBitSet::<0, 0>::new().iter().next(), None);
+        assert_eq!(BankBitSet::<1>::from_iter([0]).iter().rev().collect::<Vec<_>>(), [0]);
+    }
+}
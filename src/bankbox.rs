@@ -0,0 +1,280 @@
+
+use std::{fmt, marker::PhantomData, mem::{self, ManuallyDrop, MaybeUninit}, ops::{Deref, DerefMut}, ptr};
+
+
+/// A box storing its value inline when it fits in `BYTES` bytes, and on the
+/// heap otherwise.
+/// 
+/// `BankBox` does for single values what [`BankVec`](crate::BankVec) does for
+/// sequences, sparing small values a heap allocation.  `T` may be unsized, so
+/// closures and other `dyn Trait` objects can be boxed with the [`bank_box!`]
+/// macro, which takes care of the coercion that stable Rust doesn't allow
+/// custom pointers.  Values aligned to more than 16 bytes always go on the heap.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::{BankBox, bank_box};
+/// 
+/// let offset = 10;
+/// let add: BankBox<dyn Fn(i32) -> i32, 16> = bank_box!(move |x| x + offset);
+/// assert!(!add.on_heap());
+/// assert_eq!(add(5), 15);
+/// 
+/// let table = [0u64; 8];
+/// let sum: BankBox<dyn Fn() -> u64, 16> = bank_box!(move || table.iter().sum());
+/// assert!(sum.on_heap());
+/// assert_eq!(sum(), 0);
+/// ```
+pub struct BankBox<T: ?Sized, const BYTES: usize> {
+    // A heap value's pointer, or for an inline value a null pointer carrying
+    // only the metadata, as the value moves with the box
+    ptr: *mut T,
+    space: Space<BYTES>,
+    marker: PhantomData<T>,
+}
+
+#[repr(C, align(16))]
+struct Space<const BYTES: usize>([MaybeUninit<u8>; BYTES]);
+
+unsafe impl<T: ?Sized + Send, const BYTES: usize> Send for BankBox<T, BYTES> {}
+unsafe impl<T: ?Sized + Sync, const BYTES: usize> Sync for BankBox<T, BYTES> {}
+
+/// Boxes a value into a [`BankBox`], coercing it to the box's unsized type,
+/// such as a `dyn Trait`, where the type is annotated.
+/// 
+/// # Examples
+/// ```
+/// use std::fmt::Display;
+/// use bankarr::{BankBox, bank_box};
+/// 
+/// let items: [BankBox<dyn Display, 8>; 2] = [bank_box!(42), bank_box!("answer")];
+/// assert_eq!(format!("{} {}", items[1], items[0]), "answer 42");
+/// ```
+#[macro_export]
+macro_rules! bank_box {
+    ($value:expr) => {{
+        let value = $value;
+        let ptr = &value as *const _;
+        // The pointer was just coerced from the value itself
+        #[allow(unused_unsafe)]
+        unsafe { $crate::BankBox::new_unchecked(value, ptr) }
+    }};
+}
+
+// Replaces the address of a possibly wide pointer, keeping its metadata.  The
+// address is the first field of every wide pointer, and it's overwritten with
+// a pointer rather than an integer so `addr` keeps its provenance.
+#[inline]
+fn with_addr_of<T: ?Sized>(mut ptr: *mut T, addr: *mut u8) -> *mut T {
+    unsafe { *(&mut ptr as *mut *mut T as *mut *mut u8) = addr };
+    ptr
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: ?Sized + fmt::Debug, const BYTES: usize> fmt::Debug for BankBox<T, BYTES> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: ?Sized + fmt::Display, const BYTES: usize> fmt::Display for BankBox<T, BYTES> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&**self, f)
+    }
+}
+
+impl<T: Clone, const BYTES: usize> Clone for BankBox<T, BYTES> {
+    #[inline]
+    fn clone(&self) -> Self { Self::new((**self).clone()) }
+}
+
+impl<T: Default, const BYTES: usize> Default for BankBox<T, BYTES> {
+    #[inline]
+    fn default() -> Self { Self::new(T::default()) }
+}
+
+impl<T: ?Sized + PartialEq, const BYTES: usize> PartialEq for BankBox<T, BYTES> {
+    fn eq(&self, other: &Self) -> bool { **self == **other }
+}
+
+impl<T: ?Sized + Eq, const BYTES: usize> Eq for BankBox<T, BYTES> {}
+
+impl<T: ?Sized, const BYTES: usize> Deref for BankBox<T, BYTES> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T { unsafe { &*self.as_ptr() } }
+}
+
+impl<T: ?Sized, const BYTES: usize> DerefMut for BankBox<T, BYTES> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T { unsafe { &mut *self.as_mut_ptr() } }
+}
+
+impl<T: ?Sized, const BYTES: usize> AsRef<T> for BankBox<T, BYTES> {
+    #[inline]
+    fn as_ref(&self) -> &T { self }
+}
+
+impl<T: ?Sized, const BYTES: usize> AsMut<T> for BankBox<T, BYTES> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut T { self }
+}
+
+impl<T: ?Sized, const BYTES: usize> Drop for BankBox<T, BYTES> {
+    fn drop(&mut self) {
+        match self.on_heap() {
+            true => drop(unsafe { Box::from_raw(self.ptr) }),
+            false => unsafe { ptr::drop_in_place(self.as_mut_ptr()) },
+        }
+    }
+}
+
+impl<T, const BYTES: usize> BankBox<T, BYTES> {
+
+    /// Boxes `value`, inline if it fits in `BYTES` bytes.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankBox;
+    /// 
+    /// let small = BankBox::<u64, 8>::new(1);
+    /// assert!(!small.on_heap());
+    /// 
+    /// let large = BankBox::<[u64; 2], 8>::new([1, 2]);
+    /// assert!(large.on_heap());
+    /// ```
+    #[inline]
+    pub fn new(value: T) -> Self {
+        unsafe { Self::new_unchecked(value, ptr::null()) }
+    }
+
+    /// Moves the value out of the box.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        let mut bank = ManuallyDrop::new(self);
+        match bank.on_heap() {
+            true => *unsafe { Box::from_raw(bank.ptr) },
+            false => unsafe { bank.as_mut_ptr().read() },
+        }
+    }
+}
+
+impl<T: ?Sized, const BYTES: usize> BankBox<T, BYTES> {
+
+    /// Boxes `value` as a `T`, taking the metadata for `T`, such as a vtable,
+    /// from `ptr`.  Prefer the [`bank_box!`] macro, which calls this safely.
+    /// 
+    /// # Safety
+    /// 
+    /// `ptr` must have been created by coercing a pointer to `value`, or at
+    /// least carry metadata valid for a `U` viewed as a `T`.  Its address is
+    /// never used, so it may dangle.
+    pub unsafe fn new_unchecked<U>(value: U, ptr: *const T) -> Self {
+        let ptr = ptr.cast_mut();
+        let fits = mem::size_of::<U>() <= BYTES
+            && mem::align_of::<U>() <= mem::align_of::<Space<BYTES>>();
+
+        match fits {
+            true => {
+                let mut space = Space([MaybeUninit::uninit(); BYTES]);
+                unsafe { space.0.as_mut_ptr().cast::<U>().write(value) };
+                Self { ptr: with_addr_of(ptr, ptr::null_mut()), space, marker: PhantomData }
+            }
+            false => {
+                let heap = Box::into_raw(Box::new(value));
+                Self {
+                    ptr: with_addr_of(ptr, heap.cast()),
+                    space: Space([MaybeUninit::uninit(); BYTES]),
+                    marker: PhantomData,
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if the value didn't fit inline and was moved to the heap.
+    #[inline]
+    pub fn on_heap(&self) -> bool { !self.ptr.cast::<u8>().is_null() }
+
+    /// Returns a raw pointer to the value, valid until the box is moved or
+    /// dropped.
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        match self.on_heap() {
+            true => self.ptr,
+            false => with_addr_of(self.ptr, self.space.0.as_ptr().cast_mut().cast()),
+        }
+    }
+
+    /// Returns a raw mutable pointer to the value, valid until the box is moved
+    /// or dropped.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        match self.on_heap() {
+            true => self.ptr,
+            false => with_addr_of(self.ptr, self.space.0.as_mut_ptr().cast()),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use std::{any::Any, fmt::Display, rc::Rc};
+    use super::*;
+
+    #[test]
+    fn inline_or_heap() {
+        let mut small = BankBox::<String, 32>::new("bank".to_string());
+        assert!(!small.on_heap());
+        small.push_str("arr");
+        let moved = small;
+        assert_eq!(*moved, "bankarr");
+        assert_eq!(moved.clone().into_inner(), "bankarr");
+
+        let large = BankBox::<String, 8>::new("bank".to_string());
+        assert!(large.on_heap());
+        assert_eq!(large.into_inner(), "bank");
+
+        #[repr(align(32))]
+        struct Aligned(u8);
+        let aligned = BankBox::<Aligned, 64>::new(Aligned(1));
+        assert!(aligned.on_heap());
+        assert_eq!(aligned.0, 1);
+    }
+
+    #[test]
+    fn unsized_values() {
+        let values: [BankBox<dyn Display, 16>; 3] = [bank_box!(1u8), bank_box!('x'), bank_box!([0u8; 32].len())];
+        assert_eq!(values.iter().map(ToString::to_string).collect::<Vec<_>>(), ["1", "x", "32"]);
+
+        let slice: BankBox<[u32], 8> = bank_box!([1u32, 2]);
+        assert_eq!(*slice, [1, 2]);
+        let slice: BankBox<[u32], 8> = bank_box!([1u32, 2, 3]);
+        assert!(slice.on_heap());
+        assert_eq!(slice.len(), 3);
+
+        let any: BankBox<dyn Any, 8> = bank_box!(5i32);
+        assert_eq!(any.downcast_ref::<i32>(), Some(&5));
+
+        let mut counter = 0;
+        let mut increment: BankBox<dyn FnMut() + '_, 8> = bank_box!(|| counter += 1);
+        increment();
+        increment();
+        drop(increment);
+        assert_eq!(counter, 2);
+    }
+
+    #[test]
+    fn drops() {
+        let rc = Rc::new(());
+        let inline: BankBox<dyn Any, 8> = bank_box!(rc.clone());
+        let heap: BankBox<dyn Any, 8> = bank_box!([rc.clone(), rc.clone()]);
+        assert!(!inline.on_heap() && heap.on_heap());
+        assert_eq!(Rc::strong_count(&rc), 4);
+
+        drop((inline, heap));
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
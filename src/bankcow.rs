@@ -0,0 +1,203 @@
+
+use std::{borrow::{Borrow, Cow}, fmt, ops::Deref};
+use crate::BankVec;
+
+
+/// A clone-on-write slice, borrowing its elements until the first mutation
+/// copies them into a [`BankVec`].
+/// 
+/// `BankCow` is [`Cow<[T]>`](std::borrow::Cow) with a `BankVec` for its owned
+/// form, so the copy stays inline unless it's longer than `C`.  Code which
+/// usually passes its input through untouched, like a parser unescaping the
+/// odd string, then only pays for the copies it actually makes.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::BankCow;
+/// 
+/// fn unescape(input: &[u8]) -> BankCow<'_, u8, 16> {
+///     let mut output = BankCow::from(input);
+///     if input.contains(&b'+') {
+///         output.to_mut().iter_mut().filter(|byte| **byte == b'+').for_each(|byte| *byte = b' ');
+///     }
+///     output
+/// }
+/// 
+/// assert!(unescape(b"bank").is_borrowed());
+/// 
+/// let unescaped = unescape(b"bank+arr");
+/// assert!(unescaped.is_owned());
+/// assert_eq!(*unescaped, *b"bank arr");
+/// ```
+pub enum BankCow<'a, T, const C: usize> {
+    /// Borrowed data.
+    Borrowed(&'a [T]),
+    /// Owned data.
+    Owned(BankVec<T, C>),
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: fmt::Debug, const C: usize> fmt::Debug for BankCow<'_, T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<T: Clone, const C: usize> Clone for BankCow<'_, T, C> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Borrowed(slice) => Self::Borrowed(slice),
+            Self::Owned(owned) => Self::Owned(owned.clone()),
+        }
+    }
+}
+
+impl<T, const C: usize> Default for BankCow<'_, T, C> {
+
+    /// Creates an empty, borrowed `BankCow`.
+    #[inline]
+    fn default() -> Self { Self::Borrowed(&[]) }
+}
+
+impl<T, const C: usize> Deref for BankCow<'_, T, C> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Borrowed(slice) => slice,
+            Self::Owned(owned) => owned,
+        }
+    }
+}
+
+impl<T, const C: usize> AsRef<[T]> for BankCow<'_, T, C> {
+    #[inline]
+    fn as_ref(&self) -> &[T] { self }
+}
+
+impl<T, const C: usize> Borrow<[T]> for BankCow<'_, T, C> {
+    #[inline]
+    fn borrow(&self) -> &[T] { self }
+}
+
+impl<T: PartialEq, const C: usize> PartialEq for BankCow<'_, T, C> {
+    fn eq(&self, other: &Self) -> bool { **self == **other }
+}
+
+impl<T: Eq, const C: usize> Eq for BankCow<'_, T, C> {}
+
+impl<T: PartialEq, const C: usize> PartialEq<[T]> for BankCow<'_, T, C> {
+    fn eq(&self, other: &[T]) -> bool { **self == *other }
+}
+
+impl<T: PartialEq, const C: usize, const N: usize> PartialEq<[T; N]> for BankCow<'_, T, C> {
+    fn eq(&self, other: &[T; N]) -> bool { **self == *other }
+}
+
+impl<'a, T, const C: usize> From<&'a [T]> for BankCow<'a, T, C> {
+    #[inline]
+    fn from(slice: &'a [T]) -> Self { Self::Borrowed(slice) }
+}
+
+impl<'a, T, const C: usize, const N: usize> From<&'a [T; N]> for BankCow<'a, T, C> {
+    #[inline]
+    fn from(arr: &'a [T; N]) -> Self { Self::Borrowed(arr) }
+}
+
+impl<T, const C: usize> From<BankVec<T, C>> for BankCow<'_, T, C> {
+    #[inline]
+    fn from(owned: BankVec<T, C>) -> Self { Self::Owned(owned) }
+}
+
+impl<T: Clone, const C: usize> From<BankCow<'_, T, C>> for BankVec<T, C> {
+    #[inline]
+    fn from(cow: BankCow<'_, T, C>) -> Self { cow.into_owned() }
+}
+
+impl<T, const C: usize> BankCow<'_, T, C> {
+
+    /// Returns `true` if the data is borrowed.
+    #[inline]
+    pub const fn is_borrowed(&self) -> bool { matches!(self, Self::Borrowed(_)) }
+
+    /// Returns `true` if the data is owned.
+    #[inline]
+    pub const fn is_owned(&self) -> bool { matches!(self, Self::Owned(_)) }
+}
+
+impl<T: Clone, const C: usize> BankCow<'_, T, C> {
+
+    /// Returns a mutable reference to the owned data, first copying borrowed
+    /// data into a `BankVec`.  The copy moves onto the heap only if it's longer
+    /// than `C`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankCow;
+    /// 
+    /// let data = [1, 2, 3];
+    /// let mut cow = BankCow::<_, 4>::from(&data);
+    /// cow.to_mut().push(4);
+    /// 
+    /// assert!(cow.is_owned());
+    /// assert_eq!(cow, [1, 2, 3, 4]);
+    /// assert_eq!(data, [1, 2, 3]);
+    /// ```
+    pub fn to_mut(&mut self) -> &mut BankVec<T, C> {
+        if let Self::Borrowed(slice) = *self {
+            *self = Self::Owned(BankVec::from(Cow::Borrowed(slice)));
+        }
+        match self {
+            Self::Owned(owned) => owned,
+            Self::Borrowed(_) => unreachable!(),
+        }
+    }
+
+    /// Extracts the owned data, copying it if it's borrowed.
+    #[inline]
+    pub fn into_owned(self) -> BankVec<T, C> {
+        match self {
+            Self::Borrowed(slice) => BankVec::from(Cow::Borrowed(slice)),
+            Self::Owned(owned) => owned,
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn copy_on_write() {
+        let data = vec!["a".to_string(), "b".to_string()];
+        let mut cow = BankCow::<_, 2>::from(&data[..]);
+        assert!(cow.is_borrowed());
+        assert_eq!(cow.len(), 2);
+
+        let copy = cow.clone();
+        cow.to_mut()[0].push('!');
+        assert!(cow.is_owned());
+        assert!(!cow.to_mut().on_heap());
+        assert_eq!(cow[0], "a!");
+        assert_eq!(copy, *data);
+
+        cow.to_mut().push("c".to_string());
+        assert!(cow.to_mut().on_heap());
+        assert_eq!(BankVec::from(cow).iter().map(String::as_str).collect::<Vec<_>>(), ["a!", "b", "c"]);
+    }
+
+    #[test]
+    fn into_owned() {
+        let cow = BankCow::<i32, 4>::default();
+        assert!(cow.is_borrowed() && cow.is_empty());
+        assert_eq!(cow.into_owned(), []);
+
+        let owned = BankCow::<_, 2>::from(&[1, 2, 3]).into_owned();
+        assert!(owned.on_heap());
+        assert_eq!(owned, [1, 2, 3]);
+        assert_eq!(BankCow::from(owned.clone()), BankCow::<_, 2>::from(&owned[..]));
+    }
+}
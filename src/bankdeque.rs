@@ -0,0 +1,1044 @@
+//! A fixed-size double-ended queue, see [`BankDeque`].
+
+use std::{fmt, iter::FusedIterator, mem::MaybeUninit, ops::{self, Index, IndexMut}, ptr, slice};
+use crate::{drain, errors::BankFullError, raw};
+
+
+/// A fixed-size double-ended queue implemented with a ring buffer.
+/// 
+/// Like a [`VecDeque`](std::collections::VecDeque), elements can be pushed and
+/// popped from either end in constant time, but the bank's storage is inline and
+/// never grows past `C`.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::BankDeque;
+/// 
+/// let mut deque = BankDeque::<i32, 4>::new();
+/// deque.push_back(2);
+/// deque.push_back(3);
+/// deque.push_front(1);
+/// 
+/// assert_eq!(deque[0], 1);
+/// assert_eq!(deque.pop_back(), Some(3));
+/// assert_eq!(deque.pop_front(), Some(1));
+/// assert_eq!(deque.len(), 1);
+/// ```
+/// 
+/// # Capacity
+/// 
+/// As with [`BankArr`](crate::BankArr) the capacity is fixed by `C`.  Pushing onto a
+/// full deque panics, see [`try_push_back`](Self::try_push_back) and
+/// [`try_push_front`](Self::try_push_front) for the alternatives returning a
+/// [`Result`].
+pub struct BankDeque<T, const C: usize> {
+    data: [MaybeUninit<T>; C],
+    head: usize,
+    len: usize,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: fmt::Debug, const C: usize> fmt::Debug for BankDeque<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const C: usize> Index<usize> for BankDeque<T, C> {
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(value) => value,
+            None => raw::index_out_of_bounds(index, self.len),
+        }
+    }
+}
+
+impl<T, const C: usize> IndexMut<usize> for BankDeque<T, C> {
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len;
+        match self.get_mut(index) {
+            Some(value) => value,
+            None => raw::index_out_of_bounds(index, len),
+        }
+    }
+}
+
+impl<T: PartialEq, const C: usize> PartialEq for BankDeque<T, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq, const C: usize, const N: usize> PartialEq<[T; N]> for BankDeque<T, C> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.len == N && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const C: usize> Eq for BankDeque<T, C> {}
+
+impl<T: Clone, const C: usize> Clone for BankDeque<T, C> {
+    fn clone(&self) -> Self {
+        let mut deque = Self::new();
+        for value in self.iter() {
+            unsafe { deque.push_back_unchecked(value.clone()) }
+        }
+        deque
+    }
+}
+
+impl<T, const C: usize> Extend<T> for BankDeque<T, C> {
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        for value in items {
+            if self.len == C { raw::capacity_exceeded("extend", C) }
+            unsafe { self.push_back_unchecked(value) }
+        }
+    }
+}
+
+impl<T, const C: usize> IntoIterator for BankDeque<T, C> {
+    type Item = T;
+    type IntoIter = IntoIter<T, C>;
+
+    /// Creates a consuming iterator, moving each element out of the deque from
+    /// front to back.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankDeque;
+    /// 
+    /// let mut deque = BankDeque::<i32, 4>::from([2, 3]);
+    /// deque.push_front(1);
+    /// assert_eq!(deque.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { IntoIter { deque: self } }
+}
+
+impl<'a, T, const C: usize> IntoIterator for &'a BankDeque<T, C> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<'a, T, const C: usize> IntoIterator for &'a mut BankDeque<T, C> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter_mut() }
+}
+
+impl<T, const C: usize, const N: usize> From<[T; N]> for BankDeque<T, C> {
+
+    /// Creates a deque from an array, the first element at the front.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the array's length exceeds the deque's capacity.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankDeque;
+    /// 
+    /// let deque = BankDeque::<i32, 4>::from([1, 2, 3]);
+    /// assert_eq!(deque.front(), Some(&1));
+    /// ```
+    #[track_caller]
+    fn from(arr: [T; N]) -> Self {
+        assert!(N <= C, "array length (is {N}) exceeds bank capacity (is {C})");
+        let mut deque = Self::new();
+        deque.extend(arr);
+        deque
+    }
+}
+
+impl<T, const C: usize> Default for BankDeque<T, C> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+
+impl<T, const C: usize> Drop for BankDeque<T, C> {
+    fn drop(&mut self) {
+        let (front, back) = self.as_mut_slices();
+        unsafe {
+            ptr::drop_in_place(front);
+            ptr::drop_in_place(back);
+        }
+    }
+}
+
+impl<T, const C: usize> BankDeque<T, C> {
+
+    /// Constructs a new, empty `BankDeque<T, C>`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankDeque;
+    /// 
+    /// let deque = BankDeque::<i32, 8>::new();
+    /// assert!(deque.is_empty());
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            data: [const { MaybeUninit::uninit() }; C],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements in the deque.
+    #[inline]
+    pub const fn len(&self) -> usize { self.len }
+
+    /// Returns `true` if the deque contains no elements.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// Returns `true` if the deque has no room for further elements.
+    #[inline]
+    pub const fn is_full(&self) -> bool { self.len == C }
+
+    /// Returns the number of elements the deque can hold, `C`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { C }
+
+    /// Returns the number of elements which can still be pushed.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankDeque;
+    /// 
+    /// let deque = BankDeque::<i32, 4>::from([1]);
+    /// assert_eq!(deque.remaining_capacity(), 3);
+    /// ```
+    #[inline]
+    pub const fn remaining_capacity(&self) -> usize { C - self.len }
+
+    #[inline(always)]
+    const fn as_mut_ptr(&mut self) -> *mut T {
+        self.data.as_mut_ptr() as _
+    }
+
+    #[inline(always)]
+    const fn as_ptr(&self) -> *const T {
+        self.data.as_ptr() as _
+    }
+
+    // Maps an index in `0..2 * C` onto the buffer.  Avoids `%`, which would also
+    // divide by zero for `C == 0`.
+    #[inline(always)]
+    const fn wrap(index: usize) -> usize {
+        if index >= C { index - C } else { index }
+    }
+
+    // The buffer index of the `index`th element from the front.
+    #[inline(always)]
+    const fn physical(&self, index: usize) -> usize {
+        Self::wrap(self.head + index)
+    }
+
+    /// Appends an element to the back of the deque.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the deque is full.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankDeque;
+    /// 
+    /// let mut deque = BankDeque::<i32, 3>::from([1, 2]);
+    /// deque.push_back(3);
+    /// assert_eq!(deque, [1, 2, 3]);
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(1) time.
+    #[inline]
+    #[track_caller]
+    pub fn push_back(&mut self, value: T) {
+        if self.len == C { raw::capacity_exceeded("push_back", C) }
+        unsafe { self.push_back_unchecked(value) }
+    }
+
+    /// Prepends an element to the front of the deque.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the deque is full.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankDeque;
+    /// 
+    /// let mut deque = BankDeque::<i32, 3>::from([2, 3]);
+    /// deque.push_front(1);
+    /// assert_eq!(deque, [1, 2, 3]);
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(1) time.
+    #[inline]
+    #[track_caller]
+    pub fn push_front(&mut self, value: T) {
+        if self.len == C { raw::capacity_exceeded("push_front", C) }
+        unsafe { self.push_front_unchecked(value) }
+    }
+
+    /// Attempts to append an element to the back of the deque, handing it back
+    /// inside the error if the deque is full.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankDeque;
+    /// 
+    /// let mut deque = BankDeque::<i32, 2>::from([1]);
+    /// assert!(deque.try_push_back(2).is_ok());
+    /// assert_eq!(deque.try_push_back(3).unwrap_err().element(), 3);
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(1) time.
+    #[inline]
+    pub fn try_push_back(&mut self, value: T) -> Result<(), BankFullError<T>> {
+        if self.len == C { return Err(BankFullError::new(value)) }
+        unsafe { self.push_back_unchecked(value) }
+        Ok(())
+    }
+
+    /// Attempts to prepend an element to the front of the deque, handing it back
+    /// inside the error if the deque is full.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankDeque;
+    /// 
+    /// let mut deque = BankDeque::<i32, 2>::from([2]);
+    /// assert!(deque.try_push_front(1).is_ok());
+    /// assert_eq!(deque.try_push_front(0).unwrap_err().element(), 0);
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(1) time.
+    #[inline]
+    pub fn try_push_front(&mut self, value: T) -> Result<(), BankFullError<T>> {
+        if self.len == C { return Err(BankFullError::new(value)) }
+        unsafe { self.push_front_unchecked(value) }
+        Ok(())
+    }
+
+    /// Appends an element to the back of the deque without checking its capacity.
+    /// 
+    /// # Safety
+    /// 
+    /// Calling this method on a full deque is [undefined behavior](<https://doc.rust-lang.org/reference/behavior-considered-undefined.html>).
+    #[inline(always)]
+    pub unsafe fn push_back_unchecked(&mut self, value: T) {
+        strict_assert!(self.len < C, "push_back_unchecked called on a full deque");
+        let index = self.physical(self.len);
+        unsafe { self.as_mut_ptr().add(index).write(value) }
+        self.len += 1;
+    }
+
+    /// Prepends an element to the front of the deque without checking its capacity.
+    /// 
+    /// # Safety
+    /// 
+    /// Calling this method on a full deque is [undefined behavior](<https://doc.rust-lang.org/reference/behavior-considered-undefined.html>).
+    #[inline(always)]
+    pub unsafe fn push_front_unchecked(&mut self, value: T) {
+        strict_assert!(self.len < C, "push_front_unchecked called on a full deque");
+        self.head = Self::wrap(self.head + C - 1);
+        unsafe { self.as_mut_ptr().add(self.head).write(value) }
+        self.len += 1;
+    }
+
+    /// Removes the last element and returns it, or `None` if the deque is empty.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankDeque;
+    /// 
+    /// let mut deque = BankDeque::<i32, 3>::from([1, 2]);
+    /// assert_eq!(deque.pop_back(), Some(2));
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(1) time.
+    #[inline]
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 { return None }
+        self.len -= 1;
+        let index = self.physical(self.len);
+        Some(unsafe { self.as_ptr().add(index).read() })
+    }
+
+    /// Removes the first element and returns it, or `None` if the deque is empty.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankDeque;
+    /// 
+    /// let mut deque = BankDeque::<i32, 3>::from([1, 2]);
+    /// assert_eq!(deque.pop_front(), Some(1));
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(1) time.
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 { return None }
+        let value = unsafe { self.as_ptr().add(self.head).read() };
+        self.head = Self::wrap(self.head + 1);
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Returns a reference to the element at `index`, counting from the front,
+    /// or `None` if it's out of bounds.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankDeque;
+    /// 
+    /// let mut deque = BankDeque::<i32, 3>::from([2, 3]);
+    /// deque.push_front(1);
+    /// assert_eq!(deque.get(2), Some(&3));
+    /// assert_eq!(deque.get(3), None);
+    /// ```
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len { return None }
+        Some(unsafe { &*self.as_ptr().add(self.physical(index)) })
+    }
+
+    /// Returns a mutable reference to the element at `index`, counting from the
+    /// front, or `None` if it's out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len { return None }
+        let index = self.physical(index);
+        Some(unsafe { &mut *self.as_mut_ptr().add(index) })
+    }
+
+    /// Returns a reference to the front element, or `None` if the deque is empty.
+    #[inline]
+    pub fn front(&self) -> Option<&T> { self.get(0) }
+
+    /// Returns a mutable reference to the front element, or `None` if the deque
+    /// is empty.
+    #[inline]
+    pub fn front_mut(&mut self) -> Option<&mut T> { self.get_mut(0) }
+
+    /// Returns a reference to the back element, or `None` if the deque is empty.
+    #[inline]
+    pub fn back(&self) -> Option<&T> { self.get(self.len.wrapping_sub(1)) }
+
+    /// Returns a mutable reference to the back element, or `None` if the deque
+    /// is empty.
+    #[inline]
+    pub fn back_mut(&mut self) -> Option<&mut T> { self.get_mut(self.len.wrapping_sub(1)) }
+
+    /// Returns the deque's contents as a pair of slices, which in order hold
+    /// every element from front to back.  The second slice is empty unless the
+    /// elements wrap around the end of the buffer.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankDeque;
+    /// 
+    /// let mut deque = BankDeque::<i32, 4>::from([2, 3]);
+    /// deque.push_front(1);
+    /// assert_eq!(deque.as_slices(), (&[1][..], &[2, 3][..]));
+    /// ```
+    #[inline]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (front, back) = self.slice_ranges();
+        unsafe {
+            (
+                slice::from_raw_parts(self.as_ptr().add(self.head), front),
+                slice::from_raw_parts(self.as_ptr(), back),
+            )
+        }
+    }
+
+    /// Returns the deque's contents as a pair of mutable slices, see
+    /// [`as_slices`](Self::as_slices).
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (front, back) = self.slice_ranges();
+        let ptr = self.as_mut_ptr();
+        unsafe {
+            (
+                slice::from_raw_parts_mut(ptr.add(self.head), front),
+                slice::from_raw_parts_mut(ptr, back),
+            )
+        }
+    }
+
+    // Lengths of the contiguous runs starting at `head` and at the buffer's start.
+    #[inline(always)]
+    const fn slice_ranges(&self) -> (usize, usize) {
+        let tail = C - self.head;
+        if self.len <= tail { (self.len, 0) } else { (tail, self.len - tail) }
+    }
+
+    // The two slices holding the elements in `range`, in order.
+    #[inline]
+    fn range_slices(&self, range: ops::Range<usize>) -> (&[T], &[T]) {
+        let (front, back) = self.as_slices();
+        let split = front.len();
+        (
+            &front[range.start.min(split)..range.end.min(split)],
+            &back[range.start.saturating_sub(split)..range.end.saturating_sub(split)],
+        )
+    }
+
+    /// Returns a front-to-back iterator over the deque.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankDeque;
+    /// 
+    /// let mut deque = BankDeque::<i32, 4>::from([2, 3]);
+    /// deque.push_front(1);
+    /// assert_eq!(deque.iter().rev().collect::<Vec<_>>(), [&3, &2, &1]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+        Iter::new(front, back)
+    }
+
+    /// Returns a front-to-back iterator over the deque's mutable elements.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankDeque;
+    /// 
+    /// let mut deque = BankDeque::<i32, 4>::from([1, 2]);
+    /// deque.iter_mut().for_each(|value| *value *= 10);
+    /// assert_eq!(deque, [10, 20]);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (front, back) = self.as_mut_slices();
+        IterMut::new(front, back)
+    }
+
+    /// Returns an iterator over the elements within `range`, counting from the front.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the starting point is greater than the end point or if the end
+    /// point is greater than the length of the deque.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankDeque;
+    /// 
+    /// let mut deque = BankDeque::<i32, 4>::from([2, 3, 4]);
+    /// deque.push_front(1);
+    /// assert_eq!(deque.range(1..3).collect::<Vec<_>>(), [&2, &3]);
+    /// ```
+    #[track_caller]
+    pub fn range<R: ops::RangeBounds<usize>>(&self, range: R) -> Iter<'_, T> {
+        let (front, back) = self.range_slices(drain::slice_range(range, ..self.len));
+        Iter::new(front, back)
+    }
+
+    /// Returns a mutable iterator over the elements within `range`, counting from
+    /// the front.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the starting point is greater than the end point or if the end
+    /// point is greater than the length of the deque.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankDeque;
+    /// 
+    /// let mut deque = BankDeque::<i32, 4>::from([1, 2, 3]);
+    /// deque.range_mut(1..).for_each(|value| *value = 0);
+    /// assert_eq!(deque, [1, 0, 0]);
+    /// ```
+    #[track_caller]
+    pub fn range_mut<R: ops::RangeBounds<usize>>(&mut self, range: R) -> IterMut<'_, T> {
+        let range = drain::slice_range(range, ..self.len);
+        let (front, back) = self.as_mut_slices();
+        let split = front.len();
+        let front = &mut front[range.start.min(split)..range.end.min(split)];
+        let back = &mut back[range.start.saturating_sub(split)..range.end.saturating_sub(split)];
+        IterMut::new(front, back)
+    }
+
+    /// Removes the elements within `range` from the deque, returning them as an
+    /// iterator.  Elements after the range are moved up to close the gap once the
+    /// iterator is dropped, and any elements it didn't yield are dropped with it.
+    /// 
+    /// If the iterator is leaked the deque may lose the elements after the range
+    /// as well.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the starting point is greater than the end point or if the end
+    /// point is greater than the length of the deque.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankDeque;
+    /// 
+    /// let mut deque = BankDeque::<i32, 4>::from([2, 3, 4]);
+    /// deque.push_front(1);
+    /// 
+    /// assert_eq!(deque.drain(1..3).collect::<Vec<_>>(), [2, 3]);
+    /// assert_eq!(deque, [1, 4]);
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(`n`) time, where `n` is the number of elements from the start
+    /// of the range to the back of the deque.
+    #[track_caller]
+    pub fn drain<R: ops::RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, C> {
+        let ops::Range { start, end } = drain::slice_range(range, ..self.len);
+        let tail_len = self.len - end;
+        // Hide the range and everything behind it until the drain is dropped
+        self.len = start;
+        Drain { deque: self, front: start, back: end, tail_start: end, tail_len }
+    }
+
+    /// Shortens the deque, keeping the first `len` elements and dropping the rest.
+    /// 
+    /// Has no effect if `len` is greater than the deque's current length.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankDeque;
+    /// 
+    /// let mut deque = BankDeque::<i32, 4>::from([1, 2, 3]);
+    /// deque.truncate(1);
+    /// assert_eq!(deque, [1]);
+    /// ```
+    pub fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            drop(self.pop_back());
+        }
+    }
+
+    /// Removes every element from the deque.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+        self.head = 0;
+    }
+}
+
+
+/// An iterator over the elements of a [`BankDeque`].
+/// 
+/// Created by [`BankDeque::iter`] and [`BankDeque::range`].
+#[derive(Debug, Clone)]
+pub struct Iter<'a, T> {
+    front: slice::Iter<'a, T>,
+    back: slice::Iter<'a, T>,
+}
+
+/// A mutable iterator over the elements of a [`BankDeque`].
+/// 
+/// Created by [`BankDeque::iter_mut`] and [`BankDeque::range_mut`].
+#[derive(Debug)]
+pub struct IterMut<'a, T> {
+    front: slice::IterMut<'a, T>,
+    back: slice::IterMut<'a, T>,
+}
+
+// Both iterators walk the deque's front slice and then its back slice.
+macro_rules! impl_iter {
+    ($name:ident, $item:ty) => {
+        impl<'a, T> Iterator for $name<'a, T> {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.front.next().or_else(|| self.back.next())
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.front.len() + self.back.len();
+                (len, Some(len))
+            }
+        }
+
+        impl<T> DoubleEndedIterator for $name<'_, T> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.back.next_back().or_else(|| self.front.next_back())
+            }
+        }
+
+        impl<T> ExactSizeIterator for $name<'_, T> {}
+
+        impl<T> FusedIterator for $name<'_, T> {}
+    };
+}
+
+impl_iter!(Iter, &'a T);
+impl_iter!(IterMut, &'a mut T);
+
+impl<'a, T> Iter<'a, T> {
+    #[inline]
+    pub(crate) fn new(front: &'a [T], back: &'a [T]) -> Self {
+        Self { front: front.iter(), back: back.iter() }
+    }
+}
+
+impl<'a, T> IterMut<'a, T> {
+    #[inline]
+    pub(crate) fn new(front: &'a mut [T], back: &'a mut [T]) -> Self {
+        Self { front: front.iter_mut(), back: back.iter_mut() }
+    }
+}
+
+/// A consuming iterator over the elements of a [`BankDeque`].
+/// 
+/// Created by the `into_iter` method on [`BankDeque`], provided by the
+/// [`IntoIterator`] trait.
+#[derive(Debug, Clone)]
+pub struct IntoIter<T, const C: usize> {
+    deque: BankDeque<T, C>,
+}
+
+impl<T, const C: usize> Iterator for IntoIter<T, C> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> { self.deque.pop_front() }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.deque.len, Some(self.deque.len)) }
+}
+
+impl<T, const C: usize> DoubleEndedIterator for IntoIter<T, C> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> { self.deque.pop_back() }
+}
+
+impl<T, const C: usize> ExactSizeIterator for IntoIter<T, C> {}
+
+impl<T, const C: usize> FusedIterator for IntoIter<T, C> {}
+
+/// A draining iterator over a range of a [`BankDeque`].
+/// 
+/// Created by [`BankDeque::drain`].
+pub struct Drain<'a, T, const C: usize> {
+    deque: &'a mut BankDeque<T, C>,
+    // Logical indices of the elements not yet yielded, the deque's length
+    // marking where the drained range began.
+    front: usize,
+    back: usize,
+    tail_start: usize,
+    tail_len: usize,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: fmt::Debug, const C: usize> fmt::Debug for Drain<'_, T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let remaining: Vec<_> = (self.front..self.back)
+            .map(|i| unsafe { &*self.deque.as_ptr().add(self.deque.physical(i)) })
+            .collect();
+        f.debug_tuple("Drain").field(&remaining).finish()
+    }
+}
+
+impl<T, const C: usize> Drain<'_, T, C> {
+    #[inline(always)]
+    fn read(&mut self, index: usize) -> T {
+        unsafe { self.deque.as_ptr().add(self.deque.physical(index)).read() }
+    }
+}
+
+impl<T, const C: usize> Iterator for Drain<'_, T, C> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back { return None }
+        self.front += 1;
+        Some(self.read(self.front - 1))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<T, const C: usize> DoubleEndedIterator for Drain<'_, T, C> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back { return None }
+        self.back -= 1;
+        Some(self.read(self.back))
+    }
+}
+
+impl<T, const C: usize> ExactSizeIterator for Drain<'_, T, C> {}
+
+impl<T, const C: usize> FusedIterator for Drain<'_, T, C> {}
+
+impl<T, const C: usize> Drop for Drain<'_, T, C> {
+    fn drop(&mut self) {
+        self.for_each(drop);
+
+        let deque = &mut *self.deque;
+        let (start, tail) = (deque.len, self.tail_start);
+        if start != tail {
+            let ptr = deque.as_mut_ptr();
+            for i in 0..self.tail_len {
+                unsafe { ptr.add(deque.physical(start + i)).copy_from(ptr.add(deque.physical(tail + i)), 1) }
+            }
+        }
+        deque.len = start + self.tail_len;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn push_pop() {
+        let mut deque = BankDeque::<i32, 3>::new();
+        deque.push_back(2);
+        deque.push_front(1);
+        deque.push_back(3);
+
+        assert!(deque.is_full());
+        assert_eq!(deque, [1, 2, 3]);
+        assert_eq!(deque.pop_front(), Some(1));
+        assert_eq!(deque.pop_back(), Some(3));
+        assert_eq!(deque.pop_back(), Some(2));
+        assert_eq!(deque.pop_back(), None);
+        assert_eq!(deque.pop_front(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity exceeded during operation `push_front`: capacity is 2")]
+    fn push_to_full() {
+        let mut deque = BankDeque::<i32, 2>::from([1, 2]);
+        deque.push_front(0);
+    }
+
+    #[test]
+    fn try_push() {
+        let mut deque = BankDeque::<i32, 1>::new();
+        assert!(deque.try_push_front(1).is_ok());
+        assert_eq!(deque.try_push_back(2), Err(BankFullError::new(2)));
+        assert_eq!(deque.try_push_front(3), Err(BankFullError::new(3)));
+
+        let mut deque = BankDeque::<i32, 0>::new();
+        assert!(deque.try_push_back(1).is_err());
+        assert_eq!(deque.pop_front(), None);
+    }
+
+    #[test]
+    fn wrapping() {
+        let mut deque = BankDeque::<i32, 4>::new();
+        for i in 0..10 {
+            deque.push_back(i);
+            if deque.len() == 3 {
+                deque.pop_front();
+            }
+        }
+        assert_eq!(deque, [8, 9]);
+        assert_eq!(deque.front(), Some(&8));
+        assert_eq!(deque.back(), Some(&9));
+
+        deque.push_front(7);
+        deque.push_front(6);
+        assert_eq!(deque, [6, 7, 8, 9]);
+        let (front, back) = deque.as_slices();
+        assert_eq!(front.len() + back.len(), 4);
+        assert!(!back.is_empty());
+
+        *deque.back_mut().unwrap() = 10;
+        deque[0] = 5;
+        assert_eq!(deque, [5, 7, 8, 10]);
+    }
+
+    #[test]
+    fn index_out_of_bounds() {
+        let deque = BankDeque::<i32, 4>::from([1, 2]);
+        assert_eq!(deque.get(2), None);
+        assert_eq!(BankDeque::<i32, 4>::new().back(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 2 out of bounds for length 2")]
+    fn index_panics() {
+        let deque = BankDeque::<i32, 4>::from([1, 2]);
+        let _ = deque[2];
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 out of bounds for length 1")]
+    fn index_mut_panics() {
+        let mut deque = BankDeque::<i32, 4>::from([1]);
+        deque[3] = 0;
+    }
+
+    #[test]
+    fn dropping_types() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        let mut deque = BankDeque::<Rc<()>, 3>::new();
+        deque.push_back(rc.clone());
+        deque.push_back(rc.clone());
+        deque.pop_front();
+        deque.push_back(rc.clone());
+        deque.push_back(rc.clone());
+        assert_eq!(Rc::strong_count(&rc), 4);
+
+        let cloned = deque.clone();
+        assert_eq!(Rc::strong_count(&rc), 7);
+        drop(cloned);
+
+        deque.truncate(1);
+        assert_eq!(Rc::strong_count(&rc), 2);
+        drop(deque);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn clear() {
+        let mut deque = BankDeque::<String, 2>::from(["a".to_string()]);
+        deque.push_front("b".to_string());
+        deque.clear();
+        assert!(deque.is_empty());
+        deque.extend(["c".to_string(), "d".to_string()]);
+        assert_eq!(deque, ["c".to_string(), "d".to_string()]);
+    }
+
+    #[test]
+    fn iter() {
+        let mut deque = BankDeque::<i32, 4>::from([3, 4, 5]);
+        deque.pop_front();
+        deque.push_back(6);
+        deque.push_back(7);
+        assert_eq!(deque.as_slices(), (&[4, 5, 6][..], &[7][..]));
+
+        let mut iter = deque.iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next_back(), Some(&7));
+        assert_eq!(iter.next(), Some(&4));
+        assert_eq!(iter.collect::<Vec<_>>(), [&5, &6]);
+
+        for value in &mut deque {
+            *value += 1;
+        }
+        assert_eq!((&deque).into_iter().copied().collect::<Vec<_>>(), [5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn range() {
+        let mut deque = BankDeque::<i32, 4>::from([3, 4, 5]);
+        deque.push_front(2);
+
+        assert_eq!(deque.range(..).len(), 4);
+        assert_eq!(deque.range(1..=2).collect::<Vec<_>>(), [&3, &4]);
+        assert_eq!(deque.range(..1).collect::<Vec<_>>(), [&2]);
+        assert_eq!(deque.range(4..).next(), None);
+
+        deque.range_mut(..2).for_each(|value| *value = 0);
+        assert_eq!(deque, [0, 0, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 5 out of range for slice of length 4")]
+    fn range_out_of_bounds() {
+        let deque = BankDeque::<i32, 4>::from([1, 2, 3, 4]);
+        deque.range(..5);
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut deque = BankDeque::<String, 3>::from(["b".to_string(), "c".to_string()]);
+        deque.push_front("a".to_string());
+
+        let mut iter = deque.into_iter();
+        assert_eq!(iter.next_back().unwrap(), "c");
+        assert_eq!(iter.clone().collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(iter.next().unwrap(), "a");
+        assert_eq!(iter.len(), 1);
+    }
+
+    #[test]
+    fn drain() {
+        let mut deque = BankDeque::<String, 5>::new();
+        for s in ["c", "d", "e", "f"] {
+            deque.push_back(s.to_string());
+        }
+        deque.push_front("b".to_string());
+        deque.pop_back();
+        deque.push_back("f".to_string());
+
+        let mut drain = deque.drain(1..4);
+        assert_eq!(drain.len(), 3);
+        assert_eq!(drain.next_back().unwrap(), "e");
+        assert_eq!(drain.next().unwrap(), "c");
+        drop(drain);
+        assert_eq!(deque, ["b".to_string(), "f".to_string()]);
+
+        deque.drain(..);
+        assert!(deque.is_empty());
+        deque.push_front("a".to_string());
+        assert_eq!(deque.drain(1..).count(), 0);
+        assert_eq!(deque.len(), 1);
+    }
+
+    #[test]
+    fn drain_wrapping() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        let mut deque = BankDeque::<(i32, Rc<()>), 4>::new();
+        for i in 0..4 {
+            deque.push_front((i, rc.clone()));
+        }
+        deque.pop_back();
+        deque.push_front((4, rc.clone()));
+
+        // Drains across the end of the buffer, dropping what's left unyielded
+        let mut drain = deque.drain(1..3);
+        assert_eq!(drain.next().unwrap().0, 3);
+        drop(drain);
+        assert_eq!(deque.iter().map(|(i, _)| *i).collect::<Vec<_>>(), [4, 1]);
+        assert_eq!(Rc::strong_count(&rc), 3);
+
+        deque.push_back((5, rc.clone()));
+        assert_eq!(deque.iter().map(|(i, _)| *i).collect::<Vec<_>>(), [4, 1, 5]);
+    }
+}
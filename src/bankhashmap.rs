@@ -0,0 +1,812 @@
+//! A hash map which spills onto the heap, see [`BankHashMap`].
+
+use std::{
+    array,
+    borrow::Borrow,
+    collections::{HashMap, hash_map::{self, RandomState}},
+    fmt,
+    hash::{BuildHasher, Hash},
+    iter::{Flatten, FusedIterator},
+    mem,
+    ops::Index,
+    slice,
+};
+
+
+/// A hash map which keeps up to `C` pairs inline, moving onto the heap once it
+/// grows past `C`.
+/// 
+/// `BankHashMap` is to [`HashMap`] what [`BankVec`](crate::BankVec) is to
+/// [`Vec`]: while it holds `C` or fewer pairs they live in an inline table
+/// searched by linear probing, and past that it becomes a `HashMap` using the
+/// same hasher.  Inserts never fail for lack of room.
+/// 
+/// Like a `HashMap`, pairs are visited in an arbitrary order.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::BankHashMap;
+/// 
+/// let mut map = BankHashMap::<&str, i32, 2>::new();
+/// map.insert("a", 1);
+/// map.insert("b", 2);
+/// assert!(!map.on_heap());
+/// 
+/// map.insert("c", 3);
+/// assert!(map.on_heap());
+/// assert_eq!(map["a"], 1);
+/// assert_eq!(map.remove("b"), Some(2));
+/// ```
+#[derive(Clone)]
+pub struct BankHashMap<K, V, const C: usize, S = RandomState> {
+    buf: Buffer<K, V, C, S>,
+}
+
+#[derive(Clone)]
+enum Buffer<K, V, const C: usize, S> {
+    Inline(Table<K, V, C>, S),
+    Heap(HashMap<K, V, S>),
+}
+
+// An open-addressed table of `C` slots, resolving collisions by linear probing.
+#[derive(Clone)]
+struct Table<K, V, const C: usize> {
+    slots: [Option<(K, V)>; C],
+    len: usize,
+}
+
+impl<K, V, const C: usize> Table<K, V, C> {
+
+    const fn new() -> Self { Self { slots: [const { None }; C], len: 0 } }
+
+    #[inline]
+    const fn next(index: usize) -> usize { if index + 1 == C { 0 } else { index + 1 } }
+
+    #[inline]
+    fn home<Q: Hash + ?Sized, S: BuildHasher>(hasher: &S, key: &Q) -> usize {
+        (hasher.hash_one(key) % C as u64) as usize
+    }
+
+    // Returns `Ok` with the slot holding `key`, or `Err` with the first vacant
+    // slot along its probe sequence, `None` if the table is full.
+    fn find<Q, S>(&self, hasher: &S, key: &Q) -> Result<usize, Option<usize>>
+    where K: Borrow<Q>, Q: Hash + Eq + ?Sized, S: BuildHasher {
+        if C == 0 { return Err(None) }
+
+        let mut index = Self::home(hasher, key);
+        for _ in 0..C {
+            match &self.slots[index] {
+                None => return Err(Some(index)),
+                Some((k, _)) if k.borrow() == key => return Ok(index),
+                Some(_) => index = Self::next(index),
+            }
+        }
+        Err(None)
+    }
+
+    // Inserts a pair, handing it back if the key is new and the table is full.
+    fn insert<S: BuildHasher>(&mut self, hasher: &S, key: K, value: V) -> Result<Option<V>, (K, V)>
+    where K: Hash + Eq {
+        match self.find(hasher, &key) {
+            Ok(index) => Ok(self.slots[index].as_mut().map(|(_, v)| mem::replace(v, value))),
+            Err(Some(index)) => {
+                self.slots[index] = Some((key, value));
+                self.len += 1;
+                Ok(None)
+            }
+            Err(None) => Err((key, value)),
+        }
+    }
+
+    // Empties the slot at `index`, shifting later pairs in its probe run back so
+    // none are left stranded behind the hole.
+    fn remove<S: BuildHasher>(&mut self, hasher: &S, index: usize) -> Option<(K, V)>
+    where K: Hash {
+        let removed = self.slots[index].take()?;
+        self.len -= 1;
+
+        let mut hole = index;
+        let mut next = Self::next(hole);
+        while let Some((key, _)) = &self.slots[next] {
+            let home = Self::home(hasher, key);
+            let distance = |from: usize| (next + C - from) % C;
+            if distance(home) >= distance(hole) {
+                self.slots[hole] = self.slots[next].take();
+                hole = next;
+            }
+            next = Self::next(next);
+        }
+        Some(removed)
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<K: fmt::Debug, V: fmt::Debug, const C: usize, S> fmt::Debug for BankHashMap<K, V, C, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, const C: usize, S: Default> Default for BankHashMap<K, V, C, S> {
+    #[inline]
+    fn default() -> Self { Self::with_hasher(S::default()) }
+}
+
+impl<K: Hash + Eq, V: PartialEq, const C: usize, S: BuildHasher> PartialEq for BankHashMap<K, V, C, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().all(|(k, v)| other.get(k) == Some(v))
+    }
+}
+
+impl<K: Hash + Eq, V: Eq, const C: usize, S: BuildHasher> Eq for BankHashMap<K, V, C, S> {}
+
+impl<K, Q, V, const C: usize, S> Index<&Q> for BankHashMap<K, V, C, S>
+where K: Hash + Eq + Borrow<Q>, Q: Hash + Eq + ?Sized, S: BuildHasher {
+    type Output = V;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, key: &Q) -> &Self::Output {
+        self.get(key).expect("key not found in map")
+    }
+}
+
+impl<K: Hash + Eq, V, const C: usize, S: BuildHasher + Clone> Extend<(K, V)> for BankHashMap<K, V, C, S> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, pairs: I) {
+        let pairs = pairs.into_iter();
+        self.reserve(pairs.size_hint().0);
+        pairs.for_each(|(key, value)| { self.insert(key, value); });
+    }
+}
+
+impl<K: Hash + Eq, V, const C: usize, const N: usize> From<[(K, V); N]> for BankHashMap<K, V, C> {
+
+    /// Creates a map from an array of pairs, later pairs overwriting earlier
+    /// ones with the same key.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankHashMap;
+    /// 
+    /// let map = BankHashMap::<_, _, 4>::from([(1, 'a'), (2, 'b'), (1, 'c')]);
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map[&1], 'c');
+    /// ```
+    fn from(pairs: [(K, V); N]) -> Self {
+        let mut map = Self::new();
+        map.extend(pairs);
+        map
+    }
+}
+
+impl<K: Hash + Eq, V, const C: usize, S: BuildHasher + Clone + Default> FromIterator<(K, V)> for BankHashMap<K, V, C, S> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(pairs: I) -> Self {
+        let mut map = Self::default();
+        map.extend(pairs);
+        map
+    }
+}
+
+impl<K, V, const C: usize, S> IntoIterator for BankHashMap<K, V, C, S> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V, C>;
+
+    /// Creates a consuming iterator, moving each pair out of the map in an
+    /// arbitrary order.
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        let inner = match self.buf {
+            Buffer::Inline(table, _) => Inner::Inline(table.slots.into_iter().flatten()),
+            Buffer::Heap(heap) => Inner::Heap(heap.into_iter()),
+        };
+        IntoIter { inner, len }
+    }
+}
+
+impl<'a, K, V, const C: usize, S> IntoIterator for &'a BankHashMap<K, V, C, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<'a, K, V, const C: usize, S> IntoIterator for &'a mut BankHashMap<K, V, C, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter_mut() }
+}
+
+impl<K, V, const C: usize> BankHashMap<K, V, C> {
+
+    /// Constructs a new, empty `BankHashMap<K, V, C>`.  Doesn't allocate until
+    /// more than `C` pairs are inserted.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankHashMap;
+    /// 
+    /// let map = BankHashMap::<u32, char, 8>::new();
+    /// assert!(map.is_empty());
+    /// ```
+    #[inline]
+    pub fn new() -> Self { Self::with_hasher(RandomState::new()) }
+
+    /// Constructs a new, empty `BankHashMap<K, V, C>` with room for at least
+    /// `capacity` pairs.  Allocates on the heap only if `capacity` exceeds `C`.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hasher(capacity, RandomState::new())
+    }
+}
+
+impl<K, V, const C: usize, S> BankHashMap<K, V, C, S> {
+
+    /// Constructs a new, empty map which will hash keys using `hasher`.
+    #[inline]
+    pub const fn with_hasher(hasher: S) -> Self {
+        Self { buf: Buffer::Inline(Table::new(), hasher) }
+    }
+
+    /// Constructs a new, empty map with room for at least `capacity` pairs which
+    /// will hash keys using `hasher`.  Allocates on the heap only if `capacity`
+    /// exceeds `C`.
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> Self {
+        match capacity > C {
+            true => Self { buf: Buffer::Heap(HashMap::with_capacity_and_hasher(capacity, hasher)) },
+            false => Self::with_hasher(hasher),
+        }
+    }
+
+    /// Returns a reference to the map's hasher.
+    #[inline]
+    pub fn hasher(&self) -> &S {
+        match &self.buf {
+            Buffer::Inline(_, hasher) => hasher,
+            Buffer::Heap(heap) => heap.hasher(),
+        }
+    }
+
+    /// Returns `true` if the map's pairs have moved onto the heap.
+    #[inline]
+    pub const fn on_heap(&self) -> bool { matches!(self.buf, Buffer::Heap(_)) }
+
+    /// Returns the number of pairs in the map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        match &self.buf {
+            Buffer::Inline(table, _) => table.len,
+            Buffer::Heap(heap) => heap.len(),
+        }
+    }
+
+    /// Returns `true` if the map contains no pairs.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Returns the number of pairs the map can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        match &self.buf {
+            Buffer::Inline(..) => C,
+            Buffer::Heap(heap) => heap.capacity(),
+        }
+    }
+
+    /// Returns an iterator over the map's keys and values, in an arbitrary order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        let inner = match &self.buf {
+            Buffer::Inline(table, _) => Inner::Inline(table.slots.iter().flatten()),
+            Buffer::Heap(heap) => Inner::Heap(heap.iter()),
+        };
+        Iter { inner, len: self.len() }
+    }
+
+    /// Returns an iterator over the map's keys and mutable values, in an
+    /// arbitrary order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        let len = self.len();
+        let inner = match &mut self.buf {
+            Buffer::Inline(table, _) => Inner::Inline(table.slots.iter_mut().flatten()),
+            Buffer::Heap(heap) => Inner::Heap(heap.iter_mut()),
+        };
+        IterMut { inner, len }
+    }
+
+    /// Returns an iterator over the map's keys.
+    #[inline]
+    pub fn keys(&self) -> impl ExactSizeIterator<Item = &K> + Clone {
+        self.iter().map(|(key, _)| key)
+    }
+
+    /// Returns an iterator over the map's values.
+    #[inline]
+    pub fn values(&self) -> impl ExactSizeIterator<Item = &V> + Clone {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// Returns an iterator over the map's mutable values.
+    #[inline]
+    pub fn values_mut(&mut self) -> impl ExactSizeIterator<Item = &mut V> {
+        self.iter_mut().map(|(_, value)| value)
+    }
+
+    /// Removes every pair from the map, keeping any heap allocation.
+    pub fn clear(&mut self) {
+        match &mut self.buf {
+            Buffer::Inline(table, _) => *table = Table::new(),
+            Buffer::Heap(heap) => heap.clear(),
+        }
+    }
+}
+
+impl<K: Hash + Eq, V, const C: usize, S: BuildHasher> BankHashMap<K, V, C, S> {
+
+    /// Returns `true` if the map contains `key`.
+    #[inline]
+    pub fn contains_key<Q: Hash + Eq + ?Sized>(&self, key: &Q) -> bool where K: Borrow<Q> {
+        self.get_key_value(key).is_some()
+    }
+
+    /// Returns a reference to the value for `key`, or `None` if the map doesn't
+    /// contain it.
+    #[inline]
+    pub fn get<Q: Hash + Eq + ?Sized>(&self, key: &Q) -> Option<&V> where K: Borrow<Q> {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    /// Returns the stored key and value for `key`, or `None` if the map doesn't
+    /// contain it.
+    pub fn get_key_value<Q: Hash + Eq + ?Sized>(&self, key: &Q) -> Option<(&K, &V)> where K: Borrow<Q> {
+        match &self.buf {
+            Buffer::Inline(table, hasher) => {
+                let index = table.find(hasher, key).ok()?;
+                table.slots[index].as_ref().map(|(k, v)| (k, v))
+            }
+            Buffer::Heap(heap) => heap.get_key_value(key),
+        }
+    }
+
+    /// Returns a mutable reference to the value for `key`, or `None` if the map
+    /// doesn't contain it.
+    pub fn get_mut<Q: Hash + Eq + ?Sized>(&mut self, key: &Q) -> Option<&mut V> where K: Borrow<Q> {
+        match &mut self.buf {
+            Buffer::Inline(table, hasher) => {
+                let index = table.find(hasher, key).ok()?;
+                table.slots[index].as_mut().map(|(_, v)| v)
+            }
+            Buffer::Heap(heap) => heap.get_mut(key),
+        }
+    }
+
+    /// Removes `key` from the map, returning its value if it was present.
+    #[inline]
+    pub fn remove<Q: Hash + Eq + ?Sized>(&mut self, key: &Q) -> Option<V> where K: Borrow<Q> {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Removes `key` from the map, returning the stored key and value if it was
+    /// present.  The map stays on the heap once it's there, see
+    /// [`shrink_to_fit`](Self::shrink_to_fit).
+    pub fn remove_entry<Q: Hash + Eq + ?Sized>(&mut self, key: &Q) -> Option<(K, V)> where K: Borrow<Q> {
+        match &mut self.buf {
+            Buffer::Inline(table, hasher) => {
+                let index = table.find(hasher, key).ok()?;
+                table.remove(hasher, index)
+            }
+            Buffer::Heap(heap) => heap.remove_entry(key),
+        }
+    }
+
+    /// Retains only the pairs for which `f` returns `true`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankHashMap;
+    /// 
+    /// let mut map = BankHashMap::<_, _, 4>::from([(1, 'a'), (2, 'b'), (3, 'c')]);
+    /// map.retain(|key, _| key % 2 == 1);
+    /// assert_eq!(map.len(), 2);
+    /// assert!(!map.contains_key(&2));
+    /// ```
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) {
+        match &mut self.buf {
+            Buffer::Inline(table, hasher) => {
+                // Rebuild the table rather than repair probe runs after each
+                // removal.  Should `f` panic, the guard puts back the pair it
+                // was looking at along with every pair not yet visited.
+                struct Guard<'a, K: Hash + Eq, V, const C: usize, S: BuildHasher> {
+                    table: &'a mut Table<K, V, C>,
+                    hasher: &'a S,
+                    rest: array::IntoIter<Option<(K, V)>, C>,
+                    current: Option<(K, V)>,
+                }
+
+                impl<K: Hash + Eq, V, const C: usize, S: BuildHasher> Drop for Guard<'_, K, V, C, S> {
+                    fn drop(&mut self) {
+                        for (key, value) in self.current.take().into_iter().chain(self.rest.by_ref().flatten()) {
+                            let _ = self.table.insert(self.hasher, key, value);
+                        }
+                    }
+                }
+
+                let rest = mem::replace(table, Table::new()).slots.into_iter();
+                let mut guard = Guard { table, hasher, rest, current: None };
+                while let Some(pair) = guard.rest.by_ref().flatten().next() {
+                    let (key, value) = guard.current.insert(pair);
+                    let keep = f(key, value);
+                    if let Some((key, value)) = guard.current.take().filter(|_| keep) {
+                        let _ = guard.table.insert(guard.hasher, key, value);
+                    }
+                }
+            }
+            Buffer::Heap(heap) => heap.retain(f),
+        }
+    }
+}
+
+impl<K: Hash + Eq, V, const C: usize, S: BuildHasher + Clone> BankHashMap<K, V, C, S> {
+
+    /// Inserts a key-value pair into the map, returning the previous value if the
+    /// key was already present.  The stored key isn't replaced.
+    /// 
+    /// Moves the map onto the heap if the key is new and the map is full.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankHashMap;
+    /// 
+    /// let mut map = BankHashMap::<_, _, 1>::new();
+    /// assert_eq!(map.insert(1, "a"), None);
+    /// assert_eq!(map.insert(1, "b"), Some("a"));
+    /// assert!(!map.on_heap());
+    /// 
+    /// assert_eq!(map.insert(2, "c"), None);
+    /// assert!(map.on_heap());
+    /// ```
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match &mut self.buf {
+            Buffer::Inline(table, hasher) => match table.insert(hasher, key, value) {
+                Ok(previous) => previous,
+                Err((key, value)) => {
+                    self.spill(C + 1);
+                    self.insert(key, value)
+                }
+            },
+            Buffer::Heap(heap) => heap.insert(key, value),
+        }
+    }
+
+    /// Returns a mutable reference to the value for `key`, first inserting the
+    /// result of `f` if the map doesn't contain it.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankHashMap;
+    /// 
+    /// let mut counts = BankHashMap::<char, u32, 4>::new();
+    /// for c in "hello".chars() {
+    ///     *counts.get_or_insert_with(c, || 0) += 1;
+    /// }
+    /// assert_eq!(counts[&'l'], 2);
+    /// ```
+    pub fn get_or_insert_with<F: FnOnce() -> V>(&mut self, key: K, f: F) -> &mut V {
+        if let Buffer::Inline(table, hasher) = &self.buf
+            && table.len == C
+            && table.find(hasher, &key).is_err()
+        {
+            self.spill(C + 1);
+        }
+
+        match &mut self.buf {
+            Buffer::Inline(table, hasher) => {
+                let index = match table.find(hasher, &key) {
+                    Ok(index) => index,
+                    Err(Some(index)) => {
+                        table.slots[index] = Some((key, f()));
+                        table.len += 1;
+                        index
+                    }
+                    Err(None) => unreachable!("a full table spills before inserting"),
+                };
+                match &mut table.slots[index] {
+                    Some((_, value)) => value,
+                    None => unreachable!(),
+                }
+            }
+            Buffer::Heap(heap) => heap.entry(key).or_insert_with(f),
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more pairs, moving the map
+    /// onto the heap if they won't fit within `C`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the new capacity overflows `usize`.
+    #[track_caller]
+    pub fn reserve(&mut self, additional: usize) {
+        match &mut self.buf {
+            Buffer::Inline(table, _) if additional > C - table.len => {
+                let capacity = table.len.checked_add(additional).expect("capacity overflow");
+                self.spill(capacity);
+            }
+            Buffer::Inline(..) => (),
+            Buffer::Heap(heap) => heap.reserve(additional),
+        }
+    }
+
+    // Rehashes the inline pairs into a new `HashMap` with at least `capacity` room.
+    #[cold]
+    fn spill(&mut self, capacity: usize) {
+        let Buffer::Inline(table, hasher) = &mut self.buf else { return };
+        let table = mem::replace(table, Table::new());
+        let mut heap = HashMap::with_capacity_and_hasher(capacity.max(C.saturating_mul(2)), hasher.clone());
+        heap.extend(table.slots.into_iter().flatten());
+        self.buf = Buffer::Heap(heap);
+    }
+
+    /// Shrinks the map's heap allocation to fit its length, moving the pairs back
+    /// inline if they fit within `C`.  Does nothing for an inline map.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankHashMap;
+    /// 
+    /// let mut map = BankHashMap::<_, _, 2>::from([(1, 'a'), (2, 'b'), (3, 'c')]);
+    /// map.remove(&3);
+    /// map.shrink_to_fit();
+    /// assert!(!map.on_heap());
+    /// assert_eq!(map[&2], 'b');
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        let Buffer::Heap(heap) = &mut self.buf else { return };
+        if heap.len() > C { return heap.shrink_to_fit() }
+
+        let hasher = heap.hasher().clone();
+        let mut table = Table::new();
+        for (key, value) in heap.drain() {
+            let _ = table.insert(&hasher, key, value);
+        }
+        self.buf = Buffer::Inline(table, hasher);
+    }
+}
+
+
+// The source of an iterator's pairs, whichever buffer the map is using.
+#[derive(Debug, Clone)]
+enum Inner<I, H> {
+    Inline(I),
+    Heap(H),
+}
+
+type Slots<'a, K, V> = Flatten<slice::Iter<'a, Option<(K, V)>>>;
+type SlotsMut<'a, K, V> = Flatten<slice::IterMut<'a, Option<(K, V)>>>;
+type IntoSlots<K, V, const C: usize> = Flatten<array::IntoIter<Option<(K, V)>, C>>;
+
+macro_rules! impl_iter {
+    ($name:ident<$($lt:lifetime,)? K, V $(, const $c:ident)?>, $item:ty, $inline:expr) => {
+        impl<$($lt,)? K, V $(, const $c: usize)?> Iterator for $name<$($lt,)? K, V $(, $c)?> {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                let pair = match &mut self.inner {
+                    Inner::Inline(iter) => iter.next().map($inline),
+                    Inner::Heap(iter) => iter.next(),
+                };
+                self.len -= pair.is_some() as usize;
+                pair
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) { (self.len, Some(self.len)) }
+        }
+
+        impl<$($lt,)? K, V $(, const $c: usize)?> ExactSizeIterator for $name<$($lt,)? K, V $(, $c)?> {}
+
+        impl<$($lt,)? K, V $(, const $c: usize)?> FusedIterator for $name<$($lt,)? K, V $(, $c)?> {}
+    };
+}
+
+/// An iterator over the keys and values of a [`BankHashMap`].
+/// 
+/// Created by [`BankHashMap::iter`].
+#[derive(Debug)]
+pub struct Iter<'a, K, V> {
+    inner: Inner<Slots<'a, K, V>, hash_map::Iter<'a, K, V>>,
+    len: usize,
+}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    fn clone(&self) -> Self { Self { inner: self.inner.clone(), len: self.len } }
+}
+
+impl_iter!(Iter<'a, K, V>, (&'a K, &'a V), |(k, v)| (k, v));
+
+/// A mutable iterator over the keys and values of a [`BankHashMap`].
+/// 
+/// Created by [`BankHashMap::iter_mut`].
+#[derive(Debug)]
+pub struct IterMut<'a, K, V> {
+    inner: Inner<SlotsMut<'a, K, V>, hash_map::IterMut<'a, K, V>>,
+    len: usize,
+}
+
+impl_iter!(IterMut<'a, K, V>, (&'a K, &'a mut V), |(k, v)| (&*k, v));
+
+/// A consuming iterator over the pairs of a [`BankHashMap`].
+/// 
+/// Created by [`BankHashMap::into_iter`](IntoIterator::into_iter).
+#[derive(Debug)]
+pub struct IntoIter<K, V, const C: usize> {
+    inner: Inner<IntoSlots<K, V, C>, hash_map::IntoIter<K, V>>,
+    len: usize,
+}
+
+impl_iter!(IntoIter<K, V, const C>, (K, V), |pair| pair);
+
+
+#[cfg(test)]
+mod tests {
+
+    use std::{hash::{BuildHasherDefault, Hasher}, panic::{self, AssertUnwindSafe}};
+    use super::*;
+
+    // Hashes every key to the same value, so every pair collides.
+    #[derive(Default)]
+    struct Collide;
+
+    impl Hasher for Collide {
+        fn finish(&self) -> u64 { 0 }
+        fn write(&mut self, _: &[u8]) {}
+    }
+
+    type CollidingMap<K, V, const C: usize> = BankHashMap<K, V, C, BuildHasherDefault<Collide>>;
+
+    #[test]
+    fn insert_get_remove() {
+        let mut map = BankHashMap::<String, i32, 4>::new();
+        assert_eq!(map.insert("a".to_string(), 1), None);
+        assert_eq!(map.insert("b".to_string(), 2), None);
+        assert_eq!(map.insert("a".to_string(), 3), Some(1));
+        assert_eq!(map.len(), 2);
+
+        assert_eq!(map.get("a"), Some(&3));
+        assert_eq!(map["b"], 2);
+        assert_eq!(map.get("c"), None);
+        *map.get_mut("b").unwrap() += 1;
+        assert_eq!(map.get_key_value("b"), Some((&"b".to_string(), &3)));
+
+        assert_eq!(map.remove("a"), Some(3));
+        assert_eq!(map.remove("a"), None);
+        assert!(!map.contains_key("a"));
+        assert!(!map.on_heap());
+    }
+
+    #[test]
+    fn probe_runs_survive_removal() {
+        let mut map = CollidingMap::<i32, i32, 4>::default();
+        map.extend([(1, 1), (2, 2), (3, 3), (4, 4)]);
+        assert!(!map.on_heap());
+
+        // Every key shares a probe run, removing from its middle mustn't hide
+        // the pairs after it
+        assert_eq!(map.remove(&2), Some(2));
+        assert_eq!(map.get(&3), Some(&3));
+        assert_eq!(map.get(&4), Some(&4));
+        assert_eq!(map.remove(&1), Some(1));
+        assert_eq!(map.get(&4), Some(&4));
+        assert_eq!(map.insert(5, 5), None);
+        assert_eq!(map.len(), 3);
+        assert!(!map.on_heap());
+    }
+
+    #[test]
+    fn retain_panic() {
+        // A panicking closure loses neither the pair it was handed nor the
+        // pairs it never reached
+        let mut map = [(1, "a"), (2, "b"), (3, "c"), (4, "d")].map(|(k, v)| (k, v.to_string())).into_iter().collect::<CollidingMap<_, _, 4>>();
+        let mut seen = 0;
+        let result = panic::catch_unwind(AssertUnwindSafe(|| map.retain(|&key, _| {
+            seen += 1;
+            if seen == 3 { panic!() }
+            key != 1 && key != 2
+        })));
+        assert!(result.is_err());
+        assert_eq!(map.len(), 2);
+        assert_eq!((map.get(&3).map(String::as_str), map.get(&4).map(String::as_str)), (Some("c"), Some("d")));
+    }
+
+    #[test]
+    fn spill_and_shrink() {
+        let mut map = CollidingMap::<i32, char, 2>::default();
+        map.insert(1, 'a');
+        map.insert(2, 'b');
+        *map.get_or_insert_with(3, || 'c') = 'C';
+        assert!(map.on_heap());
+        assert_eq!(map.len(), 3);
+        assert_eq!(map[&3], 'C');
+
+        map.remove(&1);
+        map.shrink_to_fit();
+        assert!(!map.on_heap());
+        assert_eq!(map, CollidingMap::from_iter([(3, 'C'), (2, 'b')]));
+
+        let mut keys = map.keys().copied().collect::<Vec<_>>();
+        keys.sort();
+        assert_eq!(keys, [2, 3]);
+    }
+
+    #[test]
+    fn matches_hash_map() {
+        let mut map = BankHashMap::<u32, u32, 8>::new();
+        let mut expected = HashMap::new();
+
+        // A small key space so inserts and removals keep hitting the same probe runs
+        let mut state = 0x2545_f491_u32;
+        for _ in 0..2000 {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            let key = state % 12;
+            match state % 3 {
+                0 => assert_eq!(map.remove(&key), expected.remove(&key)),
+                _ => assert_eq!(map.insert(key, state), expected.insert(key, state)),
+            }
+            if map.len() <= 8 { map.shrink_to_fit() }
+            assert_eq!(map.len(), expected.len());
+            assert!((0..12).all(|key| map.get(&key) == expected.get(&key)));
+        }
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut map = BankHashMap::<i32, i32, 0>::new();
+        assert_eq!(map.get(&1), None);
+        assert_eq!(map.insert(1, 1), None);
+        assert!(map.on_heap());
+        assert_eq!(map.get(&1), Some(&1));
+    }
+
+    #[test]
+    fn iter() {
+        let mut map = BankHashMap::<i32, i32, 4>::from([(1, 10), (2, 20), (3, 30)]);
+        assert_eq!(map.iter().len(), 3);
+        map.values_mut().for_each(|value| *value += 1);
+        for (_, value) in &mut map {
+            *value *= 2;
+        }
+        map.retain(|&key, _| key != 2);
+
+        let mut pairs = map.clone().into_iter().collect::<Vec<_>>();
+        pairs.sort();
+        assert_eq!(pairs, [(1, 22), (3, 62)]);
+
+        map.extend([(4, 0), (5, 0), (6, 0)]);
+        assert!(map.on_heap());
+        map.retain(|&key, _| key < 4);
+        let mut pairs = map.into_iter().collect::<Vec<_>>();
+        pairs.sort();
+        assert_eq!(pairs, [(1, 22), (3, 62)]);
+    }
+
+    #[test]
+    fn dropping_types() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        let mut map = BankHashMap::<i32, Rc<()>, 2>::new();
+        for key in 0..2 {
+            map.insert(key, rc.clone());
+        }
+        map.remove(&0);
+        assert_eq!(Rc::strong_count(&rc), 2);
+        map.insert(1, rc.clone());
+        assert_eq!(Rc::strong_count(&rc), 2);
+        drop(map);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
@@ -0,0 +1,379 @@
+//! A fixed-size priority queue, see [`BankHeap`].
+
+use std::{fmt, iter::FusedIterator, slice};
+use crate::{BankArr, errors::BankFullError};
+
+
+/// A fixed-size priority queue holding up to `C` elements inline, implemented
+/// as a binary max-heap over a [`BankArr`].
+/// 
+/// Like [`BinaryHeap`](std::collections::BinaryHeap), the greatest element is
+/// always at the top; wrap elements in [`Reverse`](std::cmp::Reverse) for a
+/// min-heap.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::BankHeap;
+/// 
+/// let mut heap = BankHeap::<i32, 4>::new();
+/// heap.push(3);
+/// heap.push(5);
+/// heap.push(1);
+/// 
+/// assert_eq!(heap.peek(), Some(&5));
+/// assert_eq!(heap.pop(), Some(5));
+/// assert_eq!(heap.into_sorted_bank(), [1, 3]);
+/// ```
+#[derive(Clone)]
+pub struct BankHeap<T, const C: usize> {
+    data: BankArr<T, C>,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: fmt::Debug, const C: usize> fmt::Debug for BankHeap<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const C: usize> Default for BankHeap<T, C> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+
+impl<T: Ord, const C: usize> From<BankArr<T, C>> for BankHeap<T, C> {
+
+    /// Converts a bank into a heap, rearranging its elements in place.
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(`n`) time.
+    fn from(data: BankArr<T, C>) -> Self {
+        let mut heap = Self { data };
+        for index in (0..heap.len() / 2).rev() {
+            heap.sift_down(index, heap.len());
+        }
+        heap
+    }
+}
+
+impl<T: Ord, const C: usize, const N: usize> From<[T; N]> for BankHeap<T, C> {
+
+    /// Creates a heap from an array.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `N` exceeds `C`.
+    #[track_caller]
+    fn from(arr: [T; N]) -> Self { Self::from(BankArr::from(arr)) }
+}
+
+impl<T, const C: usize> From<BankHeap<T, C>> for BankArr<T, C> {
+
+    /// Converts a heap into a bank without sorting it.
+    #[inline]
+    fn from(heap: BankHeap<T, C>) -> Self { heap.data }
+}
+
+impl<T: Ord, const C: usize> Extend<T> for BankHeap<T, C> {
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        items.into_iter().for_each(|value| self.push(value));
+    }
+}
+
+impl<T, const C: usize> IntoIterator for BankHeap<T, C> {
+    type Item = T;
+    type IntoIter = crate::IntoIter<T, BankArr<T, C>>;
+
+    /// Creates a consuming iterator, moving each element out of the heap in an
+    /// arbitrary order.  See [`into_sorted_bank`](BankHeap::into_sorted_bank)
+    /// for priority order.
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.data.into_iter() }
+}
+
+impl<'a, T, const C: usize> IntoIterator for &'a BankHeap<T, C> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<T, const C: usize> BankHeap<T, C> {
+
+    /// Constructs a new, empty `BankHeap<T, C>`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankHeap;
+    /// 
+    /// let heap = BankHeap::<u32, 8>::new();
+    /// assert!(heap.is_empty());
+    /// ```
+    pub const fn new() -> Self { Self { data: BankArr::new() } }
+
+    /// Returns the number of elements in the heap.
+    #[inline]
+    pub const fn len(&self) -> usize { self.data.len() }
+
+    /// Returns `true` if the heap contains no elements.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.data.is_empty() }
+
+    /// Returns the number of elements the heap can hold, `C`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { C }
+
+    /// Returns the greatest element in the heap, or `None` if it's empty.
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(1) time.
+    #[inline]
+    pub const fn peek(&self) -> Option<&T> { self.data.as_slice().first() }
+
+    /// Returns the heap's elements in their internal, arbitrary order.
+    #[inline]
+    pub const fn as_slice(&self) -> &[T] { self.data.as_slice() }
+
+    /// Returns an iterator over the heap in an arbitrary order.
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, T> { self.data.as_slice().iter() }
+
+    /// Removes every element from the heap.
+    #[inline]
+    pub fn clear(&mut self) { self.data.clear() }
+}
+
+impl<T: Ord, const C: usize> BankHeap<T, C> {
+
+    /// Pushes an element onto the heap.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the heap is full.
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(log `n`) time.
+    #[inline]
+    #[track_caller]
+    pub fn push(&mut self, value: T) {
+        if self.try_push(value).is_err() {
+            panic!("capacity exceeded during operation `push`: capacity is {C}")
+        }
+    }
+
+    /// Attempts to push an element onto the heap, handing it back inside the
+    /// error if the heap is full.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankHeap;
+    /// 
+    /// let mut heap = BankHeap::<i32, 1>::new();
+    /// assert!(heap.try_push(1).is_ok());
+    /// assert_eq!(heap.try_push(2).unwrap_err().element(), 2);
+    /// ```
+    pub fn try_push(&mut self, value: T) -> Result<(), BankFullError<T>> {
+        self.data.try_push(value)?;
+        self.sift_up(self.len() - 1);
+        Ok(())
+    }
+
+    /// Removes the greatest element from the heap and returns it, or `None` if
+    /// it's empty.
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(log `n`) time.
+    pub fn pop(&mut self) -> Option<T> {
+        let len = self.len();
+        if len == 0 { return None }
+        self.data.as_mut_slice().swap(0, len - 1);
+        let top = self.data.pop();
+        self.sift_down(0, self.len());
+        top
+    }
+
+    /// Pushes an element onto the heap, then pops the greatest, which may be the
+    /// element just given.  Works on a full heap, and is faster than a `push`
+    /// followed by a `pop`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankHeap;
+    /// 
+    /// let mut heap = BankHeap::<i32, 2>::from([1, 4]);
+    /// assert_eq!(heap.push_pop(3), 4);
+    /// assert_eq!(heap.push_pop(5), 5);
+    /// assert_eq!(heap.into_sorted_bank(), [1, 3]);
+    /// ```
+    pub fn push_pop(&mut self, mut value: T) -> T {
+        if let Some(top) = self.data.as_mut_slice().first_mut()
+            && *top > value
+        {
+            std::mem::swap(top, &mut value);
+            self.sift_down(0, self.len());
+        }
+        value
+    }
+
+    /// Returns an iterator which removes elements from the heap in priority
+    /// order, greatest first.  Any elements the iterator doesn't reach are
+    /// dropped along with it, leaving the heap empty.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankHeap;
+    /// 
+    /// let mut heap = BankHeap::<i32, 4>::from([2, 4, 1, 3]);
+    /// assert_eq!(heap.drain_sorted().take(2).collect::<Vec<_>>(), [4, 3]);
+    /// assert!(heap.is_empty());
+    /// ```
+    #[inline]
+    pub fn drain_sorted(&mut self) -> DrainSorted<'_, T, C> { DrainSorted { heap: self } }
+
+    /// Consumes the heap, returning a bank with its elements in ascending order.
+    /// Sorts in place without allocating.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankHeap;
+    /// 
+    /// let heap = BankHeap::<i32, 4>::from([2, 4, 1, 3]);
+    /// assert_eq!(heap.into_sorted_bank(), [1, 2, 3, 4]);
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(`n` log `n`) time.
+    pub fn into_sorted_bank(mut self) -> BankArr<T, C> {
+        for end in (1..self.len()).rev() {
+            self.data.as_mut_slice().swap(0, end);
+            self.sift_down(0, end);
+        }
+        self.data
+    }
+
+    // Moves the element at `index` up until its parent is no smaller.
+    fn sift_up(&mut self, mut index: usize) {
+        let data = self.data.as_mut_slice();
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if data[index] <= data[parent] { break }
+            data.swap(index, parent);
+            index = parent;
+        }
+    }
+
+    // Moves the element at `index` down until no child within `end` is greater.
+    fn sift_down(&mut self, mut index: usize, end: usize) {
+        let data = &mut self.data.as_mut_slice()[..end];
+        loop {
+            let left = 2 * index + 1;
+            if left >= end { break }
+            let right = left + 1;
+            let child = if right < end && data[right] > data[left] { right } else { left };
+            if data[index] >= data[child] { break }
+            data.swap(index, child);
+            index = child;
+        }
+    }
+}
+
+
+/// A draining iterator over a [`BankHeap`] in priority order.
+/// 
+/// Created by [`BankHeap::drain_sorted`].
+#[derive(Debug)]
+pub struct DrainSorted<'a, T: Ord, const C: usize> {
+    heap: &'a mut BankHeap<T, C>,
+}
+
+impl<T: Ord, const C: usize> Iterator for DrainSorted<'_, T, C> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> { self.heap.pop() }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.heap.len(), Some(self.heap.len())) }
+}
+
+impl<T: Ord, const C: usize> ExactSizeIterator for DrainSorted<'_, T, C> {}
+
+impl<T: Ord, const C: usize> FusedIterator for DrainSorted<'_, T, C> {}
+
+impl<T: Ord, const C: usize> Drop for DrainSorted<'_, T, C> {
+    fn drop(&mut self) { self.heap.clear() }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use std::cmp::Reverse;
+    use super::*;
+
+    #[test]
+    fn push_pop() {
+        let mut heap = BankHeap::<i32, 8>::new();
+        for value in [5, 1, 8, 3, 9, 2, 7] {
+            heap.push(value);
+        }
+        assert_eq!(heap.peek(), Some(&9));
+        assert_eq!(heap.len(), 7);
+
+        let mut popped = vec![];
+        while let Some(value) = heap.pop() {
+            popped.push(value);
+        }
+        assert_eq!(popped, [9, 8, 7, 5, 3, 2, 1]);
+        assert_eq!(heap.pop(), None);
+    }
+
+    #[test]
+    fn full() {
+        let mut heap = BankHeap::<i32, 2>::from([1, 2]);
+        assert_eq!(heap.try_push(3), Err(BankFullError::new(3)));
+        assert_eq!(heap.push_pop(0), 2);
+        assert_eq!(heap.push_pop(3), 3);
+        assert_eq!(heap.push_pop(1), 1);
+        assert_eq!(heap.into_sorted_bank(), [0, 1]);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity exceeded during operation `push`: capacity is 1")]
+    fn push_to_full() {
+        let mut heap = BankHeap::<i32, 1>::from([1]);
+        heap.push(2);
+    }
+
+    #[test]
+    fn sorted() {
+        let heap = BankHeap::<_, 6>::from(BankArr::from([3, 1, 4, 1, 5, 9]));
+        assert_eq!(heap.clone().into_sorted_bank(), [1, 1, 3, 4, 5, 9]);
+
+        let mut min_heap = BankHeap::<_, 6>::new();
+        min_heap.extend(heap.into_iter().map(Reverse));
+        assert_eq!(min_heap.drain_sorted().map(|Reverse(value)| value).collect::<Vec<_>>(), [1, 1, 3, 4, 5, 9]);
+        assert!(min_heap.is_empty());
+    }
+
+    #[test]
+    fn drain_sorted_drops_rest() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        let mut heap = BankHeap::<_, 4>::from([(1, rc.clone()), (2, rc.clone()), (3, rc.clone())]);
+        let mut drain = heap.drain_sorted();
+        assert_eq!(drain.len(), 3);
+        assert_eq!(drain.next().unwrap().0, 3);
+        drop(drain);
+        assert!(heap.is_empty());
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
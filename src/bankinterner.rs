@@ -0,0 +1,277 @@
+//! A small byte-string interner, see [`BankInterner`].
+
+use std::{fmt, iter::FusedIterator, ops::Range};
+use crate::BankVec;
+
+
+/// The dense id a [`BankInterner`] hands out for an interned string, counting
+/// up from zero in the order strings were first interned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(u32);
+
+impl Symbol {
+
+    /// Returns the symbol's index, usable to key a side table of per-symbol
+    /// data.
+    #[inline]
+    pub const fn index(self) -> usize { self.0 as usize }
+}
+
+impl From<Symbol> for u32 {
+    #[inline]
+    fn from(symbol: Symbol) -> Self { symbol.0 }
+}
+
+
+/// An interner mapping byte strings to dense [`Symbol`]s, storing up to `B`
+/// bytes of strings and `N` symbols inline before spilling onto the heap.
+/// 
+/// Interned strings are packed end to end into a single `BankVec<u8, B>`, each
+/// symbol recording where its string ends, and found again by linear scan.
+/// That suits the symbol tables of small parsers and config formats, where
+/// identifiers are short and few enough for an interner to never allocate.
+/// Anything can be interned as long as it's viewable as bytes, `str` included.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::BankInterner;
+/// 
+/// let mut symbols = BankInterner::<64, 8>::new();
+/// let x = symbols.intern("x");
+/// let y = symbols.intern("y");
+/// 
+/// assert_eq!(symbols.intern("x"), x);
+/// assert_eq!(y.index(), 1);
+/// assert_eq!(symbols.resolve(y), Some(&b"y"[..]));
+/// assert_eq!(symbols.get("z"), None);
+/// assert!(!symbols.on_heap());
+/// ```
+#[derive(Clone, Default)]
+pub struct BankInterner<const B: usize, const N: usize> {
+    bytes: BankVec<u8, B>,
+    // The end of each symbol's string within `bytes`, its start being the
+    // previous symbol's end
+    ends: BankVec<u32, N>,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<const B: usize, const N: usize> fmt::Debug for BankInterner<B, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.iter().map(|(symbol, bytes)| (symbol.0, String::from_utf8_lossy(bytes))))
+            .finish()
+    }
+}
+
+impl<'a, const B: usize, const N: usize> IntoIterator for &'a BankInterner<B, N> {
+    type Item = (Symbol, &'a [u8]);
+    type IntoIter = Iter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<S: AsRef<[u8]>, const B: usize, const N: usize> Extend<S> for BankInterner<B, N> {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, strings: I) {
+        strings.into_iter().for_each(|s| { self.intern(s); });
+    }
+}
+
+impl<S: AsRef<[u8]>, const B: usize, const N: usize> FromIterator<S> for BankInterner<B, N> {
+    fn from_iter<I: IntoIterator<Item = S>>(strings: I) -> Self {
+        let mut interner = Self::new();
+        interner.extend(strings);
+        interner
+    }
+}
+
+impl<const B: usize, const N: usize> BankInterner<B, N> {
+
+    /// Constructs a new, empty `BankInterner<B, N>`.
+    #[inline]
+    pub const fn new() -> Self { Self { bytes: BankVec::new(), ends: BankVec::new() } }
+
+    /// Returns the number of symbols interned.
+    #[inline]
+    pub const fn len(&self) -> usize { self.ends.len() }
+
+    /// Returns `true` if nothing has been interned.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.ends.is_empty() }
+
+    /// Returns the total length in bytes of the interned strings.
+    #[inline]
+    pub const fn byte_len(&self) -> usize { self.bytes.len() }
+
+    /// Returns `true` if either the strings or the symbols have outgrown
+    /// their inline budget and moved onto the heap.
+    #[inline]
+    pub const fn on_heap(&self) -> bool { self.bytes.on_heap() || self.ends.on_heap() }
+
+    #[inline]
+    fn range(&self, index: usize) -> Range<usize> {
+        let start = match index { 0 => 0, _ => self.ends[index - 1] as usize };
+        start..self.ends[index] as usize
+    }
+
+    /// Returns the symbol for `s` if it has been interned.
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(`n`) time in the number of symbols, only comparing the bytes
+    /// of strings the same length as `s`.
+    pub fn get<S: AsRef<[u8]> + ?Sized>(&self, s: &S) -> Option<Symbol> {
+        let s = s.as_ref();
+        (0..self.len())
+            .find(|&index| {
+                let range = self.range(index);
+                range.len() == s.len() && self.bytes[range] == *s
+            })
+            .map(|index| Symbol(index as u32))
+    }
+
+    /// Returns the symbol for `s`, interning it first if need be.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the strings' total length or the number of symbols would
+    /// exceed `u32::MAX`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankInterner;
+    /// 
+    /// let mut symbols = BankInterner::<4, 2>::new();
+    /// let ids: Vec<usize> = ["let", "x", "let", "mut", "x"]
+    ///     .into_iter()
+    ///     .map(|ident| symbols.intern(ident).index())
+    ///     .collect();
+    /// 
+    /// assert_eq!(ids, [0, 1, 0, 2, 1]);
+    /// assert!(symbols.on_heap());
+    /// ```
+    #[track_caller]
+    pub fn intern<S: AsRef<[u8]>>(&mut self, s: S) -> Symbol {
+        let s = s.as_ref();
+        if let Some(symbol) = self.get(s) { return symbol }
+
+        let symbol = u32::try_from(self.len()).expect("symbol count exceeds `u32::MAX`");
+        let end = u32::try_from(self.bytes.len() + s.len()).expect("interned bytes exceed `u32::MAX`");
+        self.bytes.extend_from_slice(s);
+        self.ends.push(end);
+        Symbol(symbol)
+    }
+
+    /// Returns the string interned as `symbol`, or `None` if it came from
+    /// another interner holding fewer symbols.
+    #[inline]
+    pub fn resolve(&self, symbol: Symbol) -> Option<&[u8]> {
+        (symbol.index() < self.len()).then(|| &self.bytes[self.range(symbol.index())])
+    }
+
+    /// Returns an iterator over the symbols and their strings, in the order
+    /// they were interned.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_> {
+        Iter { bytes: &self.bytes, ends: &self.ends, front: 0, back: self.ends.len() }
+    }
+
+    /// Removes every symbol, so ids count from zero again.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+        self.ends.clear();
+    }
+}
+
+
+/// An iterator over the symbols of a [`BankInterner`] and their strings.
+/// 
+/// Created by [`BankInterner::iter`].
+#[derive(Debug, Clone)]
+pub struct Iter<'a> {
+    bytes: &'a [u8],
+    ends: &'a [u32],
+    front: usize,
+    back: usize,
+}
+
+impl<'a> Iter<'a> {
+    #[inline]
+    fn item(&self, index: usize) -> (Symbol, &'a [u8]) {
+        let start = match index { 0 => 0, _ => self.ends[index - 1] as usize };
+        (Symbol(index as u32), &self.bytes[start..self.ends[index] as usize])
+    }
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (Symbol, &'a [u8]);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back { return None }
+        self.front += 1;
+        Some(self.item(self.front - 1))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Iter<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back { return None }
+        self.back -= 1;
+        Some(self.item(self.back))
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+impl FusedIterator for Iter<'_> {}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn intern() {
+        let mut symbols = BankInterner::<8, 4>::new();
+        let empty = symbols.intern("");
+        let ab = symbols.intern(b"ab");
+        let a = symbols.intern(vec![b'a']);
+        assert_eq!((empty.index(), ab.index(), a.index()), (0, 1, 2));
+        assert_eq!(symbols.intern(""), empty);
+        assert_eq!(symbols.get("a"), Some(a));
+        assert_eq!(symbols.get("b"), None);
+
+        assert_eq!(symbols.resolve(empty), Some(&b""[..]));
+        assert_eq!(symbols.resolve(Symbol(3)), None);
+        assert_eq!(u32::from(a), 2);
+        assert_eq!(symbols.byte_len(), 3);
+        assert!(!symbols.on_heap());
+
+        let strings: Vec<_> = symbols.iter().rev().map(|(_, s)| s).collect();
+        assert_eq!(strings, [&b"a"[..], b"ab", b""]);
+
+        symbols.clear();
+        assert_eq!(symbols.intern("ab").index(), 0);
+    }
+
+    #[test]
+    fn spills() {
+        let words = ["alpha", "beta", "gamma", "beta", "delta", "alpha"];
+        let symbols: BankInterner<8, 2> = words.into_iter().collect();
+        assert_eq!(symbols.len(), 4);
+        assert!(symbols.on_heap());
+        for word in words {
+            let symbol = symbols.get(word).unwrap();
+            assert_eq!(symbols.resolve(symbol), Some(word.as_bytes()));
+        }
+    }
+}
@@ -0,0 +1,1017 @@
+//! A slab of values addressed by generational keys, see [`Banklist`].
+
+use std::{fmt, iter::{Enumerate, FusedIterator}, mem, slice};
+
+use crate::BankVec;
+
+#[cfg(feature = "serde")]
+mod serialize;
+
+
+/// A handle to a value stored in a [`Banklist`].
+/// 
+/// Keys pair a slot index with the generation of the value that occupied it, so
+/// a key to a removed value never resolves to whatever reuses its slot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Key {
+    index: u32,
+    generation: u32,
+}
+
+impl Key {
+
+    /// Returns the slot index the key refers to.
+    #[inline]
+    pub const fn index(&self) -> usize { self.index as usize }
+
+    /// Returns the generation of the key's slot when it was issued.
+    #[inline]
+    pub const fn generation(&self) -> u32 { self.generation }
+}
+
+
+#[derive(Debug, Clone)]
+enum Slot<T> {
+    Occupied { generation: u32, value: T },
+    Vacant { generation: u32, next_free: Option<u32> },
+}
+
+impl<T> Slot<T> {
+
+    #[inline]
+    fn occupied(index: usize, slot: Self) -> Option<(Key, T)> {
+        match slot {
+            Slot::Occupied { generation, value } => Some((Key { index: index as u32, generation }, value)),
+            Slot::Vacant { .. } => None,
+        }
+    }
+
+    #[inline]
+    fn as_ref(&self) -> Slot<&T> {
+        match self {
+            Slot::Occupied { generation, value } => Slot::Occupied { generation: *generation, value },
+            Slot::Vacant { generation, next_free } => Slot::Vacant { generation: *generation, next_free: *next_free },
+        }
+    }
+
+    #[inline]
+    fn as_mut(&mut self) -> Slot<&mut T> {
+        match self {
+            Slot::Occupied { generation, value } => Slot::Occupied { generation: *generation, value },
+            Slot::Vacant { generation, next_free } => Slot::Vacant { generation: *generation, next_free: *next_free },
+        }
+    }
+}
+
+
+/// A slab of values addressed by stable [`Key`]s.
+/// 
+/// Values live in a [`BankVec`] of slots, so up to `C` slots are stored inline
+/// before spilling onto the heap.  Removed slots are threaded onto a free-list and
+/// reused in *O*(1) by later inserts.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::Banklist;
+/// 
+/// let mut list = Banklist::<&str, 4>::new();
+/// let a = list.insert("a");
+/// let b = list.insert("b");
+/// 
+/// assert_eq!(list.remove(a), Some("a"));
+/// assert_eq!(list.get(a), None);
+/// assert_eq!(list[b], "b");
+/// 
+/// // The vacated slot is reused, but under a new generation
+/// let c = list.insert("c");
+/// assert_eq!(c.index(), a.index());
+/// assert_ne!(c, a);
+/// ```
+#[derive(Clone)]
+pub struct Banklist<T, const C: usize> {
+    slots: BankVec<Slot<T>, C>,
+    free: Option<u32>,
+    len: usize,
+    // Generation handed to newly appended slots; raised past that of any slot 
+    // trimmed off the end so stale keys into it stay invalid.
+    base_generation: u32,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: fmt::Debug, const C: usize> fmt::Debug for Banklist<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const C: usize> Default for Banklist<T, C> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+
+impl<T, const C: usize> std::ops::Index<Key> for Banklist<T, C> {
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, key: Key) -> &Self::Output {
+        self.get(key).expect("invalid banklist key")
+    }
+}
+
+impl<T, const C: usize> std::ops::IndexMut<Key> for Banklist<T, C> {
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, key: Key) -> &mut Self::Output {
+        self.get_mut(key).expect("invalid banklist key")
+    }
+}
+
+impl<'a, T, const C: usize> IntoIterator for &'a Banklist<T, C> {
+    type Item = (Key, &'a T);
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<'a, T, const C: usize> IntoIterator for &'a mut Banklist<T, C> {
+    type Item = (Key, &'a mut T);
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter_mut() }
+}
+
+impl<T, const C: usize> IntoIterator for Banklist<T, C> {
+    type Item = (Key, T);
+    type IntoIter = IntoIter<T, C>;
+
+    /// Creates a consuming iterator over the list's keys and values, in slot order.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::Banklist;
+    /// 
+    /// let mut list = Banklist::<String, 4>::new();
+    /// let a = list.insert("a".to_string());
+    /// 
+    /// let entries: Vec<_> = list.into_iter().collect();
+    /// assert_eq!(entries, [(a, "a".to_string())]);
+    /// ```
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { len: self.len, inner: self.slots.into_iter().enumerate() }
+    }
+}
+
+impl<T, const C: usize> Banklist<T, C> {
+
+    /// Constructs a new, empty `Banklist<T, C>`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::Banklist;
+    /// 
+    /// let list = Banklist::<i32, 8>::new();
+    /// assert!(list.is_empty());
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        Self { slots: BankVec::new(), free: None, len: 0, base_generation: 0 }
+    }
+
+    /// Constructs a new, empty `Banklist<T, C>` with room for at least `slots`
+    /// values before reallocating.  Allocates on the heap only if `slots` exceeds `C`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::Banklist;
+    /// 
+    /// let list = Banklist::<i32, 4>::with_capacity(16);
+    /// assert!(list.capacity() >= 16);
+    /// ```
+    #[track_caller]
+    pub fn with_capacity(slots: usize) -> Self {
+        let mut list = Self::new();
+        list.slots.reserve_exact(slots);
+        list
+    }
+
+    /// Returns the number of slots the list can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize { self.slots.capacity() }
+
+    /// Returns the number of values in the list.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::Banklist;
+    /// 
+    /// let mut list = Banklist::<i32, 8>::new();
+    /// let key = list.insert(1);
+    /// assert_eq!(list.len(), 1);
+    /// list.remove(key);
+    /// assert_eq!(list.len(), 0);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize { self.len }
+
+    /// Returns `true` if the list contains no values.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// Inserts a value into the list, returning its key.
+    /// 
+    /// Reuses the most recently vacated slot if there is one, otherwise appends a
+    /// new slot, moving the list onto the heap once it exceeds `C` slots.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the number of slots would exceed `u32::MAX`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::Banklist;
+    /// 
+    /// let mut list = Banklist::<i32, 8>::new();
+    /// let key = list.insert(5);
+    /// assert_eq!(list.get(key), Some(&5));
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(1) time.
+    #[track_caller]
+    pub fn insert(&mut self, value: T) -> Key {
+        let key = match self.free {
+            Some(index) => {
+                let slot = &mut self.slots[index as usize];
+                let Slot::Vacant { generation, next_free } = *slot else {
+                    unreachable!("free-list points to an occupied slot")
+                };
+                self.free = next_free;
+                *slot = Slot::Occupied { generation, value };
+                Key { index, generation }
+            }
+            None => {
+                let index = u32::try_from(self.slots.len())
+                    .expect("banklist slot count exceeds u32::MAX");
+                let generation = self.base_generation;
+                self.slots.push(Slot::Occupied { generation, value });
+                Key { index, generation }
+            }
+        };
+        self.len += 1;
+        key
+    }
+
+    /// Removes the value for `key` from the list, returning it if the key was valid.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::Banklist;
+    /// 
+    /// let mut list = Banklist::<i32, 8>::new();
+    /// let key = list.insert(5);
+    /// assert_eq!(list.remove(key), Some(5));
+    /// assert_eq!(list.remove(key), None);
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(1) time.
+    pub fn remove(&mut self, key: Key) -> Option<T> {
+        match self.slots.get(key.index as usize)? {
+            Slot::Occupied { generation, .. } if *generation == key.generation => {
+                self.vacate(key.index as usize).map(|(_, value)| value)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the value for `key`, or `None` if the key is invalid.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::Banklist;
+    /// 
+    /// let mut list = Banklist::<i32, 8>::new();
+    /// let key = list.insert(5);
+    /// assert_eq!(list.get(key), Some(&5));
+    /// ```
+    #[inline]
+    pub fn get(&self, key: Key) -> Option<&T> {
+        match self.slots.get(key.index as usize)? {
+            Slot::Occupied { generation, value } if *generation == key.generation => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the value for `key`, or `None` if the key
+    /// is invalid.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::Banklist;
+    /// 
+    /// let mut list = Banklist::<i32, 8>::new();
+    /// let key = list.insert(5);
+    /// *list.get_mut(key).unwrap() += 1;
+    /// assert_eq!(list[key], 6);
+    /// ```
+    #[inline]
+    pub fn get_mut(&mut self, key: Key) -> Option<&mut T> {
+        match self.slots.get_mut(key.index as usize)? {
+            Slot::Occupied { generation, value } if *generation == key.generation => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns mutable references to the values for each of `keys` at once, or
+    /// `None` if any key is invalid or two keys refer to the same slot.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::Banklist;
+    /// 
+    /// let mut list = Banklist::<i32, 8>::new();
+    /// let a = list.insert(1);
+    /// let b = list.insert(2);
+    /// 
+    /// let [x, y] = list.get_disjoint_mut([a, b]).unwrap();
+    /// std::mem::swap(x, y);
+    /// assert_eq!((list[a], list[b]), (2, 1));
+    /// 
+    /// assert!(list.get_disjoint_mut([a, a]).is_none());
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(`N`²) time.
+    pub fn get_disjoint_mut<const N: usize>(&mut self, keys: [Key; N]) -> Option<[&mut T; N]> {
+        let slots = self.slots.get_disjoint_mut(keys.map(|key| key.index as usize)).ok()?;
+        let valid = slots.iter().zip(keys).all(|(slot, key)| {
+            matches!(slot, Slot::Occupied { generation, .. } if *generation == key.generation)
+        });
+        if !valid { return None; }
+
+        Some(slots.map(|slot| match slot {
+            Slot::Occupied { value, .. } => value,
+            Slot::Vacant { .. } => unreachable!(),
+        }))
+    }
+
+    /// Returns `true` if `key` refers to a value in the list.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::Banklist;
+    /// 
+    /// let mut list = Banklist::<i32, 8>::new();
+    /// let key = list.insert(5);
+    /// assert!(list.contains_key(key));
+    /// list.remove(key);
+    /// assert!(!list.contains_key(key));
+    /// ```
+    #[inline]
+    pub fn contains_key(&self, key: Key) -> bool { self.get(key).is_some() }
+
+    /// Returns an iterator over the list's keys and values, in slot order.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::Banklist;
+    /// 
+    /// let mut list = Banklist::<i32, 4>::new();
+    /// let a = list.insert(1);
+    /// let b = list.insert(2);
+    /// 
+    /// let entries: Vec<_> = list.iter().collect();
+    /// assert_eq!(entries, [(a, &1), (b, &2)]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { len: self.len, inner: self.slots.iter().enumerate() }
+    }
+
+    /// Returns an iterator over the list's keys and mutable values, in slot order.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::Banklist;
+    /// 
+    /// let mut list = Banklist::<i32, 4>::new();
+    /// let a = list.insert(1);
+    /// 
+    /// for (_, value) in list.iter_mut() {
+    ///     *value *= 10;
+    /// }
+    /// assert_eq!(list[a], 10);
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut { len: self.len, inner: self.slots.iter_mut().enumerate() }
+    }
+
+    /// Returns an iterator over the list's keys and values, in slot order.  The
+    /// same as [`iter`](Banklist::iter), named to pair with [`keys`](Banklist::keys)
+    /// and [`values`](Banklist::values).
+    #[inline]
+    pub fn entries(&self) -> Iter<'_, T> { self.iter() }
+
+    /// Returns an iterator over the list's keys, in slot order.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::Banklist;
+    /// 
+    /// let mut list = Banklist::<i32, 4>::new();
+    /// let a = list.insert(1);
+    /// let b = list.insert(2);
+    /// 
+    /// assert_eq!(list.keys().collect::<Vec<_>>(), [a, b]);
+    /// ```
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, T> {
+        Keys { len: self.len, inner: self.slots.iter().enumerate() }
+    }
+
+    /// Returns an iterator over the list's values, in slot order.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::Banklist;
+    /// 
+    /// let mut list = Banklist::<i32, 4>::new();
+    /// list.insert(1);
+    /// list.insert(2);
+    /// 
+    /// assert_eq!(list.values().sum::<i32>(), 3);
+    /// ```
+    #[inline]
+    pub fn values(&self) -> Values<'_, T> {
+        Values { len: self.len, inner: self.slots.iter().enumerate() }
+    }
+
+    /// Returns an iterator over the list's mutable values, in slot order.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::Banklist;
+    /// 
+    /// let mut list = Banklist::<i32, 4>::new();
+    /// let a = list.insert(1);
+    /// 
+    /// list.values_mut().for_each(|value| *value += 1);
+    /// assert_eq!(list[a], 2);
+    /// ```
+    #[inline]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, T> {
+        ValuesMut { len: self.len, inner: self.slots.iter_mut().enumerate() }
+    }
+
+    /// Removes every value from the list, returning them with their keys as an
+    /// iterator.  Each vacated slot goes back onto the free-list, invalidating its
+    /// key as with [`remove`](Banklist::remove).
+    /// 
+    /// If the iterator is dropped before being fully consumed, it removes and
+    /// drops the remaining values.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::Banklist;
+    /// 
+    /// let mut list = Banklist::<i32, 4>::new();
+    /// let a = list.insert(1);
+    /// let b = list.insert(2);
+    /// list.remove(a);
+    /// 
+    /// let drained: Vec<_> = list.drain().collect();
+    /// assert_eq!(drained, [(b, 2)]);
+    /// assert!(list.is_empty());
+    /// assert_eq!(list.get(b), None);
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, T, C> {
+        let back = self.slots.len();
+        Drain { list: self, front: 0, back }
+    }
+
+    /// Retains only the values for which `keep` returns `true`, removing the rest
+    /// in a single pass and putting their slots back on the free-list.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::Banklist;
+    /// 
+    /// let mut list = Banklist::<i32, 4>::new();
+    /// let keys: Vec<_> = (0..6).map(|i| list.insert(i)).collect();
+    /// 
+    /// list.retain(|_, value| { *value *= 10; *value % 20 == 0 });
+    /// assert_eq!(list.len(), 3);
+    /// assert_eq!(list[keys[2]], 20);
+    /// assert_eq!(list.get(keys[1]), None);
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(`slots`) time.
+    pub fn retain<F: FnMut(Key, &mut T) -> bool>(&mut self, mut keep: F) {
+        for index in 0..self.slots.len() {
+            let Slot::Occupied { generation, value } = &mut self.slots[index] else { continue };
+            let key = Key { index: index as u32, generation: *generation };
+            if !keep(key, value) { self.vacate(index); }
+        }
+    }
+
+    /// Moves every value into the lowest slots, trimming the vacated ones off
+    /// the end.  Keys to moved values change, use
+    /// [`compact_with`](Banklist::compact_with) to learn their new keys.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::Banklist;
+    /// 
+    /// let mut list = Banklist::<i32, 4>::new();
+    /// let keys: Vec<_> = (0..4).map(|i| list.insert(i)).collect();
+    /// list.remove(keys[0]);
+    /// list.remove(keys[1]);
+    /// 
+    /// list.compact();
+    /// assert_eq!(list.iter().map(|(key, _)| key.index()).collect::<Vec<_>>(), [0, 1]);
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(`slots`) time.
+    #[inline]
+    pub fn compact(&mut self) { self.compact_with(|_, _| {}) }
+
+    /// Moves every value into the lowest slots, trimming the vacated ones off
+    /// the end, and calls `remap` with the old and new key of each moved value.
+    /// 
+    /// Keys which weren't remapped stay valid, and stale keys stay invalid.
+    /// 
+    /// # Examples
+    /// ```
+    /// use std::collections::HashMap;
+    /// use bankarr::Banklist;
+    /// 
+    /// let mut list = Banklist::<&str, 4>::new();
+    /// let a = list.insert("a");
+    /// let b = list.insert("b");
+    /// list.remove(a);
+    /// 
+    /// let mut remapped = HashMap::new();
+    /// list.compact_with(|old, new| { remapped.insert(old, new); });
+    /// 
+    /// assert_eq!(list.get(b), None);
+    /// assert_eq!(list[remapped[&b]], "b");
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(`slots`) time.
+    pub fn compact_with<F: FnMut(Key, Key)>(&mut self, mut remap: F) {
+        let (mut front, mut back) = (0, self.slots.len());
+
+        loop {
+            while front < back && matches!(self.slots[front], Slot::Occupied { .. }) { front += 1; }
+            while front < back && matches!(self.slots[back - 1], Slot::Vacant { .. }) { back -= 1; }
+            if front + 1 >= back { break }
+
+            back -= 1;
+            let Slot::Vacant { generation, .. } = self.slots[front] else { unreachable!() };
+            let (old, value) = self.vacate(back).unwrap();
+            let new = Key { index: front as u32, generation };
+            self.slots[front] = Slot::Occupied { generation, value };
+            self.len += 1;
+            remap(old, new);
+        }
+
+        self.trim_vacant();
+    }
+
+    /// Trims vacant slots off the end of the list and releases unused memory.
+    /// Unlike [`compact`](Banklist::compact), no values are moved so every key
+    /// stays valid.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::Banklist;
+    /// 
+    /// let mut list = Banklist::<i32, 2>::new();
+    /// let keys: Vec<_> = (0..8).map(|i| list.insert(i)).collect();
+    /// keys[1..].iter().for_each(|&key| { list.remove(key); });
+    /// 
+    /// list.shrink_to_fit();
+    /// assert_eq!(list.capacity(), 2);
+    /// assert_eq!(list[keys[0]], 0);
+    /// ```
+    #[track_caller]
+    pub fn shrink_to_fit(&mut self) {
+        self.trim_vacant();
+        self.slots.shrink_to_fit();
+    }
+
+    // Removes trailing vacant slots and rebuilds the free-list from what's left,
+    // lowest slot first.
+    fn trim_vacant(&mut self) {
+        while let Some(Slot::Vacant { generation, .. }) = self.slots.last() {
+            self.base_generation = self.base_generation.max(*generation);
+            self.slots.pop();
+        }
+
+        self.free = None;
+        for (index, slot) in self.slots.iter_mut().enumerate().rev() {
+            if let Slot::Vacant { next_free, .. } = slot {
+                *next_free = self.free;
+                self.free = Some(index as u32);
+            }
+        }
+    }
+
+    // Vacates an occupied slot, pushing it onto the free-list.
+    #[inline]
+    fn vacate(&mut self, index: usize) -> Option<(Key, T)> {
+        let slot = &mut self.slots[index];
+        let Slot::Occupied { generation, .. } = *slot else { return None };
+        let key = Key { index: index as u32, generation };
+        let vacant = Slot::Vacant { generation: generation.wrapping_add(1), next_free: self.free };
+        let Slot::Occupied { value, .. } = mem::replace(slot, vacant) else { unreachable!() };
+        self.free = Some(key.index);
+        self.len -= 1;
+        Some((key, value))
+    }
+}
+
+
+// Each iterator wraps an enumerated iterator over the slots, skipping vacant ones,
+// and tracks the number of values left for `ExactSizeIterator`.
+macro_rules! impl_iter {
+    ([$($gen:tt)*] $name:ty, $item:ty, $map:expr) => {
+        impl<$($gen)*> Iterator for $name {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                let entry = self.inner.find_map($map)?;
+                self.len -= 1;
+                Some(entry)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) { (self.len, Some(self.len)) }
+        }
+
+        impl<$($gen)*> DoubleEndedIterator for $name {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                let entry = self.inner.by_ref().rev().find_map($map)?;
+                self.len -= 1;
+                Some(entry)
+            }
+        }
+
+        impl<$($gen)*> ExactSizeIterator for $name {}
+
+        impl<$($gen)*> FusedIterator for $name {}
+    };
+}
+
+
+/// An iterator over the keys and values of a [`Banklist`].
+/// 
+/// Created by [`Banklist::iter`].
+#[derive(Debug, Clone)]
+pub struct Iter<'a, T> {
+    len: usize,
+    inner: Enumerate<slice::Iter<'a, Slot<T>>>,
+}
+
+impl_iter!(['a, T] Iter<'a, T>, (Key, &'a T), |(index, slot)| Slot::occupied(index, slot.as_ref()));
+
+/// A mutable iterator over the keys and values of a [`Banklist`].
+/// 
+/// Created by [`Banklist::iter_mut`].
+#[derive(Debug)]
+pub struct IterMut<'a, T> {
+    len: usize,
+    inner: Enumerate<slice::IterMut<'a, Slot<T>>>,
+}
+
+impl_iter!(['a, T] IterMut<'a, T>, (Key, &'a mut T), |(index, slot)| Slot::occupied(index, slot.as_mut()));
+
+/// An iterator over the keys of a [`Banklist`].
+/// 
+/// Created by [`Banklist::keys`].
+#[derive(Debug, Clone)]
+pub struct Keys<'a, T> {
+    len: usize,
+    inner: Enumerate<slice::Iter<'a, Slot<T>>>,
+}
+
+impl_iter!(['a, T] Keys<'a, T>, Key, |(index, slot)| Slot::occupied(index, slot.as_ref()).map(|(key, _)| key));
+
+/// An iterator over the values of a [`Banklist`].
+/// 
+/// Created by [`Banklist::values`].
+#[derive(Debug, Clone)]
+pub struct Values<'a, T> {
+    len: usize,
+    inner: Enumerate<slice::Iter<'a, Slot<T>>>,
+}
+
+impl_iter!(['a, T] Values<'a, T>, &'a T, |(index, slot)| Slot::occupied(index, slot.as_ref()).map(|(_, value)| value));
+
+/// A mutable iterator over the values of a [`Banklist`].
+/// 
+/// Created by [`Banklist::values_mut`].
+#[derive(Debug)]
+pub struct ValuesMut<'a, T> {
+    len: usize,
+    inner: Enumerate<slice::IterMut<'a, Slot<T>>>,
+}
+
+impl_iter!(['a, T] ValuesMut<'a, T>, &'a mut T, |(index, slot)| Slot::occupied(index, slot.as_mut()).map(|(_, value)| value));
+
+/// A consuming iterator over the keys and values of a [`Banklist`].
+/// 
+/// Created by the `into_iter` method on [`Banklist`], provided by the
+/// [`IntoIterator`] trait.
+#[derive(Debug)]
+pub struct IntoIter<T, const C: usize> {
+    len: usize,
+    inner: Enumerate<crate::IntoIter<Slot<T>, BankVec<Slot<T>, C>>>,
+}
+
+impl_iter!([T, const C: usize] IntoIter<T, C>, (Key, T), |(index, slot)| Slot::occupied(index, slot));
+
+/// A draining iterator over the keys and values of a [`Banklist`].
+/// 
+/// Created by [`Banklist::drain`].
+#[derive(Debug)]
+pub struct Drain<'a, T, const C: usize> {
+    list: &'a mut Banklist<T, C>,
+    front: usize,
+    back: usize,
+}
+
+impl<T, const C: usize> Iterator for Drain<'_, T, C> {
+    type Item = (Key, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.front < self.back {
+            self.front += 1;
+            if let Some(entry) = self.list.vacate(self.front - 1) { return Some(entry) }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.list.len, Some(self.list.len)) }
+}
+
+impl<T, const C: usize> DoubleEndedIterator for Drain<'_, T, C> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while self.front < self.back {
+            self.back -= 1;
+            if let Some(entry) = self.list.vacate(self.back) { return Some(entry) }
+        }
+        None
+    }
+}
+
+impl<T, const C: usize> ExactSizeIterator for Drain<'_, T, C> {}
+
+impl<T, const C: usize> FusedIterator for Drain<'_, T, C> {}
+
+impl<T, const C: usize> Drop for Drain<'_, T, C> {
+    fn drop(&mut self) { self.for_each(drop); }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    type L = Banklist<String, 2>;
+
+    #[test]
+    fn insert_get() {
+        let mut list = L::new();
+        let a = list.insert("a".to_string());
+        let b = list.insert("b".to_string());
+        let c = list.insert("c".to_string());
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.get(a).unwrap(), "a");
+        assert_eq!(list.get(b).unwrap(), "b");
+        assert_eq!(list[c], "c");
+
+        list[c].push('c');
+        assert_eq!(list[c], "cc");
+    }
+
+    #[test]
+    fn remove_reuses_slots() {
+        let mut list = L::new();
+        let keys: Vec<_> = (0..4).map(|i| list.insert(i.to_string())).collect();
+
+        assert_eq!(list.remove(keys[1]).unwrap(), "1");
+        assert_eq!(list.remove(keys[3]).unwrap(), "3");
+        assert_eq!(list.remove(keys[3]), None);
+        assert_eq!(list.len(), 2);
+
+        // Most recently freed slot first
+        let k3 = list.insert("x".to_string());
+        let k1 = list.insert("y".to_string());
+        let k4 = list.insert("z".to_string());
+
+        assert_eq!((k3.index(), k3.generation()), (3, 1));
+        assert_eq!((k1.index(), k1.generation()), (1, 1));
+        assert_eq!(k4.index(), 4);
+        assert_eq!(list.len(), 5);
+    }
+
+    #[test]
+    fn stale_keys() {
+        let mut list = L::new();
+        let key = list.insert("a".to_string());
+        list.remove(key);
+        let new = list.insert("b".to_string());
+
+        assert_eq!(key.index(), new.index());
+        assert_eq!(list.get(key), None);
+        assert_eq!(list.get_mut(key), None);
+        assert_eq!(list.remove(key), None);
+        assert_eq!(list[new], "b");
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid banklist key")]
+    fn index_invalid() {
+        let mut list = L::new();
+        let key = list.insert("a".to_string());
+        list.remove(key);
+        let _ = &list[key];
+    }
+
+    #[test]
+    fn iter() {
+        let mut list = L::new();
+        let keys: Vec<_> = ["a", "b", "c", "d"].map(|v| list.insert(v.to_string())).into();
+        list.remove(keys[1]);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some((keys[0], &"a".to_string())));
+        assert_eq!(iter.next_back(), Some((keys[3], &"d".to_string())));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.next(), Some((keys[2], &"c".to_string())));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        for (_, value) in &mut list { value.push('!'); }
+        let values: Vec<_> = (&list).into_iter().map(|(_, v)| v.as_str()).collect();
+        assert_eq!(values, ["a!", "c!", "d!"]);
+
+        let entries: Vec<_> = list.into_iter().rev().collect();
+        assert_eq!(entries[0], (keys[3], "d!".to_string()));
+        assert_eq!(entries.len(), 3);
+    }
+
+    #[test]
+    fn drain() {
+        let mut list = L::new();
+        let keys: Vec<_> = ["a", "b", "c"].map(|v| list.insert(v.to_string())).into();
+        list.remove(keys[0]);
+
+        let mut drain = list.drain();
+        assert_eq!(drain.len(), 2);
+        assert_eq!(drain.next(), Some((keys[1], "b".to_string())));
+        drop(drain);
+
+        assert!(list.is_empty());
+        assert_eq!(list.iter().count(), 0);
+        assert_eq!(list.get(keys[2]), None);
+
+        // Every slot is back on the free-list, under a new generation
+        let reused: Vec<_> = (0..4).map(|_| list.insert("x".to_string())).collect();
+        assert_eq!(reused.iter().map(Key::index).collect::<Vec<_>>(), [2, 1, 0, 3]);
+        assert!(reused[..3].iter().all(|key| key.generation() == 1));
+        assert!(keys.iter().all(|&key| list.get(key).is_none()));
+    }
+
+    #[test]
+    fn with_capacity() {
+        let list = Banklist::<i32, 4>::with_capacity(2);
+        assert_eq!(list.capacity(), 4);
+
+        let list = Banklist::<i32, 4>::with_capacity(10);
+        assert_eq!(list.capacity(), 10);
+    }
+
+    #[test]
+    fn compact() {
+        let mut list = L::new();
+        let keys: Vec<_> = (0..6).map(|i| list.insert(i.to_string())).collect();
+        for i in [0, 2, 3] { list.remove(keys[i]); }
+
+        let mut remapped = Vec::new();
+        list.compact_with(|old, new| remapped.push((old, new)));
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.slots.len(), 3);
+        assert_eq!(remapped.len(), 2);
+        for (old, new) in remapped {
+            assert!(new.index() < 3);
+            assert_eq!(list.get(old), None);
+            assert_eq!(list[new], old.index().to_string());
+        }
+        // `1` never moved
+        assert_eq!(list[keys[1]], "1");
+
+        // Removed keys don't resurrect, whether in moved-into or trimmed slots
+        let fresh: Vec<_> = (0..3).map(|i| list.insert(i.to_string())).collect();
+        assert!([0, 2, 3].iter().all(|&i| list.get(keys[i]).is_none()));
+        assert!(fresh.iter().all(|&key| list.get(key).is_some()));
+    }
+
+    #[test]
+    fn shrink_to_fit() {
+        let mut list = L::new();
+        let keys: Vec<_> = (0..6).map(|i| list.insert(i.to_string())).collect();
+        for i in [1, 4, 5] { list.remove(keys[i]); }
+
+        list.shrink_to_fit();
+        assert_eq!(list.slots.len(), 4);
+        assert_eq!(list.capacity(), 4);
+        assert!([0, 2, 3].iter().all(|&i| list[keys[i]] == i.to_string()));
+
+        // Remaining vacant slot reused first, then new slots
+        assert_eq!(list.insert("x".to_string()).index(), 1);
+        let key = list.insert("y".to_string());
+        assert_eq!(key.index(), 4);
+        assert_eq!(list.get(keys[4]), None);
+    }
+
+    #[test]
+    fn retain() {
+        let mut list = L::new();
+        let keys: Vec<_> = (0..5).map(|i| list.insert(i.to_string())).collect();
+        list.remove(keys[0]);
+
+        let mut seen = Vec::new();
+        list.retain(|key, value| { seen.push(key); value != "2" && value != "4" });
+
+        assert_eq!(seen, keys[1..]);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.get(keys[2]), None);
+        assert_eq!(list[keys[3]], "3");
+
+        // Retained slots are reused, most recently removed first
+        let reused: Vec<_> = (0..3).map(|_| list.insert(String::new()).index()).collect();
+        assert_eq!(reused, [4, 2, 0]);
+    }
+
+    #[test]
+    fn get_disjoint_mut() {
+        let mut list = L::new();
+        let keys: Vec<_> = (0..4).map(|i| list.insert(i.to_string())).collect();
+
+        let [a, b, c] = list.get_disjoint_mut([keys[3], keys[0], keys[2]]).unwrap();
+        a.push('a');
+        b.push('b');
+        c.push('c');
+        assert_eq!(list.iter().map(|(_, v)| v.as_str()).collect::<Vec<_>>(), ["0b", "1", "2c", "3a"]);
+
+        assert!(list.get_disjoint_mut([keys[1], keys[1]]).is_none());
+        assert!(list.get_disjoint_mut([keys[1], Key { index: 9, generation: 0 }]).is_none());
+
+        list.remove(keys[1]);
+        let stale = list.insert("x".to_string());
+        assert!(list.get_disjoint_mut([keys[0], keys[1]]).is_none());
+        assert!(list.get_disjoint_mut([keys[0], stale]).is_some());
+        assert_eq!(list.get_disjoint_mut::<0>([]), Some([]));
+    }
+
+    #[test]
+    fn keys_values() {
+        let mut list = L::new();
+        let keys: Vec<_> = (0..4).map(|i| list.insert(i.to_string())).collect();
+        list.remove(keys[1]);
+
+        assert_eq!(list.keys().collect::<Vec<_>>(), [keys[0], keys[2], keys[3]]);
+        assert_eq!(list.keys().next_back(), Some(keys[3]));
+        assert_eq!(list.values().len(), 3);
+        assert_eq!(list.values().collect::<Vec<_>>(), ["0", "2", "3"]);
+
+        list.values_mut().for_each(|value| value.push('!'));
+        assert_eq!(list.entries().collect::<Vec<_>>(), list.iter().collect::<Vec<_>>());
+        assert_eq!(list[keys[3]], "3!");
+
+        assert!(list.contains_key(keys[0]));
+        assert!(!list.contains_key(keys[1]));
+        assert!(!list.contains_key(Key { index: 7, generation: 0 }));
+    }
+}
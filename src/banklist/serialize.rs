@@ -0,0 +1,154 @@
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::{Banklist, Key, Slot};
+
+
+// Keys are packed into a single integer so they can serve as map keys in formats,
+// like JSON, which only allow strings and numbers there.
+impl Key {
+    #[inline]
+    const fn to_bits(self) -> u64 { (self.generation as u64) << 32 | self.index as u64 }
+
+    #[inline]
+    const fn from_bits(bits: u64) -> Self {
+        Self { index: bits as u32, generation: (bits >> 32) as u32 }
+    }
+}
+
+impl Serialize for Key {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.to_bits())
+    }
+}
+
+impl<'de> Deserialize<'de> for Key {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u64::deserialize(deserializer).map(Key::from_bits)
+    }
+}
+
+
+impl<T: Serialize, const C: usize> Serialize for Banklist<T, C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.len))?;
+        for (key, value) in self {
+            map.serialize_entry(&key, value)?;
+        }
+        map.end()
+    }
+}
+
+struct BanklistVisitor<T, const C: usize>(PhantomData<T>);
+
+impl<'de, T: Deserialize<'de>, const C: usize> Visitor<'de> for BanklistVisitor<T, C> {
+    type Value = Banklist<T, C>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a map of banklist keys to values")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+        let mut list = Banklist::<T, C>::new();
+        let mut max_generation = 0;
+
+        while let Some((key, value)) = access.next_entry::<Key, T>()? {
+            let index = key.index();
+            if index >= list.slots.len() {
+                // A far-off index in untrusted input must not abort the process
+                list.slots.try_reserve(index + 1 - list.slots.len()).map_err(de::Error::custom)?;
+                // Filled in with proper generations once every key is known
+                while list.slots.len() <= index {
+                    list.slots.push(Slot::Vacant { generation: 0, next_free: None });
+                }
+            }
+            if let Slot::Occupied { .. } = list.slots[index] {
+                return Err(de::Error::custom(format_args!("duplicate banklist slot {index}")));
+            }
+            list.slots[index] = Slot::Occupied { generation: key.generation, value };
+            list.len += 1;
+            max_generation = max_generation.max(key.generation);
+        }
+
+        // Generations of vacant slots aren't serialized, so give them one newer
+        // than any key seen.
+        let fresh = max_generation.wrapping_add(1);
+        for slot in list.slots.iter_mut() {
+            if let Slot::Vacant { generation, .. } = slot { *generation = fresh; }
+        }
+        list.base_generation = fresh;
+        list.trim_vacant();
+
+        Ok(list)
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const C: usize> Deserialize<'de> for Banklist<T, C> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(BanklistVisitor(PhantomData))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut list = Banklist::<String, 2>::new();
+        let keys: Vec<_> = (0..4).map(|i| list.insert(i.to_string())).collect();
+        list.remove(keys[1]);
+        let reinserted = list.insert("x".to_string());
+        list.remove(keys[2]);
+
+        let json = serde_json::to_string(&list).unwrap();
+        let mut de: Banklist<String, 2> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(de.len(), 3);
+        assert_eq!(de[keys[0]], "0");
+        assert_eq!(de[reinserted], "x");
+        assert_eq!(de[keys[3]], "3");
+        assert_eq!(de.get(keys[1]), None);
+        assert_eq!(de.get(keys[2]), None);
+
+        // The free-list is rebuilt, and vacated keys don't resurrect
+        let key = de.insert("y".to_string());
+        assert_eq!(key.index(), 2);
+        assert_ne!(key, keys[2]);
+    }
+
+    #[test]
+    fn duplicate_slot() {
+        let json = format!(
+            "{{\"{}\": 1, \"{}\": 2}}",
+            Key { index: 0, generation: 0 }.to_bits(),
+            Key { index: 0, generation: 1 }.to_bits(),
+        );
+        assert!(serde_json::from_str::<Banklist<i32, 2>>(&json).is_err());
+    }
+
+    #[test]
+    fn huge_index() {
+        // Wide enough that `u32::MAX` slots can never be allocated
+        struct Wide { _bytes: [u64; 8192] }
+
+        impl<'de> Deserialize<'de> for Wide {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                u8::deserialize(deserializer).map(|_| Wide { _bytes: [0; 8192] })
+            }
+        }
+
+        let json = format!("{{\"{}\": 0}}", Key { index: u32::MAX, generation: 0 }.to_bits());
+        let Err(err) = serde_json::from_str::<Banklist<Wide, 0>>(&json) else {
+            panic!("deserialized a list with a slot at `u32::MAX`")
+        };
+        assert!(err.to_string().contains("Allocation error"));
+    }
+}
@@ -0,0 +1,273 @@
+//! A fixed-capacity least-recently-used cache, see [`BankLru`].
+
+use std::{borrow::Borrow, fmt, iter::{FusedIterator, Rev}, slice};
+use crate::BankArr;
+
+
+/// A cache holding up to `C` key-value pairs inline, evicting the least
+/// recently used pair to make room for a new one.
+/// 
+/// Pairs are kept in order of use and found by linear scan, so keys need only
+/// be [`Eq`].  Like [`BankMap`](crate::BankMap), that's as fast as hashing for
+/// the small capacities caches like this are usually given.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::BankLru;
+/// 
+/// let mut cache = BankLru::<&str, u32, 2>::new();
+/// cache.put("a", 1);
+/// cache.put("b", 2);
+/// 
+/// // Reading "a" makes "b" the least recently used
+/// assert_eq!(cache.get("a"), Some(&1));
+/// assert_eq!(cache.put("c", 3), Some(("b", 2)));
+/// 
+/// assert!(!cache.contains_key("b"));
+/// assert_eq!(cache.iter().collect::<Vec<_>>(), [(&"c", &3), (&"a", &1)]);
+/// ```
+#[derive(Clone)]
+pub struct BankLru<K, V, const C: usize> {
+    // Ordered from least to most recently used
+    pairs: BankArr<(K, V), C>,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<K: fmt::Debug, V: fmt::Debug, const C: usize> fmt::Debug for BankLru<K, V, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, const C: usize> Default for BankLru<K, V, C> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+
+impl<'a, K, V, const C: usize> IntoIterator for &'a BankLru<K, V, C> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<K, V, const C: usize> BankLru<K, V, C> {
+
+    /// Constructs a new, empty `BankLru<K, V, C>`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankLru;
+    /// 
+    /// let cache = BankLru::<u64, String, 16>::new();
+    /// assert!(cache.is_empty());
+    /// ```
+    pub const fn new() -> Self { Self { pairs: BankArr::new() } }
+
+    /// Returns the number of pairs in the cache.
+    #[inline]
+    pub const fn len(&self) -> usize { self.pairs.len() }
+
+    /// Returns `true` if the cache contains no pairs.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.pairs.is_empty() }
+
+    /// Returns the number of pairs the cache can hold, `C`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { C }
+
+    /// Removes every pair from the cache.
+    #[inline]
+    pub fn clear(&mut self) { self.pairs.clear() }
+
+    /// Returns an iterator over the cache's pairs, from the most to the least
+    /// recently used.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> { Iter { inner: self.pairs.iter().rev() } }
+
+    /// Returns the least recently used pair, the next to be evicted, without
+    /// promoting it.
+    #[inline]
+    pub fn peek_lru(&self) -> Option<(&K, &V)> {
+        self.pairs.first().map(|(k, v)| (k, v))
+    }
+
+    /// Removes and returns the least recently used pair.
+    #[inline]
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        (!self.pairs.is_empty()).then(|| self.pairs.remove(0))
+    }
+
+    #[inline]
+    fn position<Q: Eq + ?Sized>(&self, key: &Q) -> Option<usize> where K: Borrow<Q> {
+        self.pairs.iter().position(|(k, _)| k.borrow() == key)
+    }
+
+    // Moves the pair at `index` to the most recently used end, returning its
+    // new index.
+    #[inline]
+    fn promote(&mut self, index: usize) -> usize {
+        self.pairs[index..].rotate_left(1);
+        self.pairs.len() - 1
+    }
+}
+
+impl<K: Eq, V, const C: usize> BankLru<K, V, C> {
+
+    /// Returns `true` if the cache contains `key`, without promoting it.
+    #[inline]
+    pub fn contains_key<Q: Eq + ?Sized>(&self, key: &Q) -> bool where K: Borrow<Q> {
+        self.position(key).is_some()
+    }
+
+    /// Returns a reference to the value for `key`, marking it the most recently
+    /// used, or `None` if the cache doesn't contain it.
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(`C`) time.
+    #[inline]
+    pub fn get<Q: Eq + ?Sized>(&mut self, key: &Q) -> Option<&V> where K: Borrow<Q> {
+        self.get_mut(key).map(|value| &*value)
+    }
+
+    /// Returns a mutable reference to the value for `key`, marking it the most
+    /// recently used, or `None` if the cache doesn't contain it.
+    #[inline]
+    pub fn get_mut<Q: Eq + ?Sized>(&mut self, key: &Q) -> Option<&mut V> where K: Borrow<Q> {
+        let index = self.position(key)?;
+        let index = self.promote(index);
+        Some(&mut self.pairs[index].1)
+    }
+
+    /// Returns a reference to the value for `key` without promoting it.
+    #[inline]
+    pub fn peek<Q: Eq + ?Sized>(&self, key: &Q) -> Option<&V> where K: Borrow<Q> {
+        self.position(key).map(|index| &self.pairs[index].1)
+    }
+
+    /// Inserts a pair as the most recently used, returning the pair it pushed
+    /// out of the cache: the previous pair for the same key, or else the least
+    /// recently used pair if the cache was full.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankLru;
+    /// 
+    /// let mut cache = BankLru::<u8, char, 2>::new();
+    /// assert_eq!(cache.put(1, 'a'), None);
+    /// assert_eq!(cache.put(1, 'b'), Some((1, 'a')));
+    /// assert_eq!(cache.put(2, 'c'), None);
+    /// assert_eq!(cache.put(3, 'd'), Some((1, 'b')));
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(`C`) time.
+    pub fn put(&mut self, key: K, value: V) -> Option<(K, V)> {
+        if let Some(index) = self.position(&key) {
+            let index = self.promote(index);
+            return Some(std::mem::replace(&mut self.pairs[index], (key, value)));
+        }
+        let evicted = match self.pairs.len() == C {
+            true if C == 0 => return Some((key, value)),
+            true => self.pop_lru(),
+            false => None,
+        };
+        self.pairs.push((key, value));
+        evicted
+    }
+
+    /// Removes `key` from the cache, returning its value if it was present.
+    #[inline]
+    pub fn remove<Q: Eq + ?Sized>(&mut self, key: &Q) -> Option<V> where K: Borrow<Q> {
+        self.position(key).map(|index| self.pairs.remove(index).1)
+    }
+}
+
+
+/// An iterator over the pairs of a [`BankLru`], from the most to the least
+/// recently used.
+/// 
+/// Created by [`BankLru::iter`].
+pub struct Iter<'a, K, V> {
+    inner: Rev<slice::Iter<'a, (K, V)>>,
+}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    fn clone(&self) -> Self { Self { inner: self.inner.clone() } }
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for Iter<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> { self.inner.next().map(|(k, v)| (k, v)) }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { self.inner.size_hint() }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> { self.inner.next_back().map(|(k, v)| (k, v)) }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn evicts_lru() {
+        let mut cache = BankLru::<String, i32, 3>::new();
+        for (i, key) in ["a", "b", "c"].into_iter().enumerate() {
+            assert_eq!(cache.put(key.to_string(), i as i32), None);
+        }
+        assert_eq!(cache.peek("a"), Some(&0));
+        assert_eq!(cache.peek_lru(), Some((&"a".to_string(), &0)));
+
+        *cache.get_mut("a").unwrap() += 10;
+        assert_eq!(cache.put("d".to_string(), 3), Some(("b".to_string(), 1)));
+        assert_eq!(cache.put("e".to_string(), 4), Some(("c".to_string(), 2)));
+        assert_eq!(cache.iter().map(|(_, v)| *v).collect::<Vec<_>>(), [4, 3, 10]);
+
+        assert_eq!(cache.remove("d"), Some(3));
+        assert_eq!(cache.remove("d"), None);
+        assert_eq!(cache.pop_lru(), Some(("a".to_string(), 10)));
+        assert_eq!(cache.len(), 1);
+
+        cache.clear();
+        assert_eq!(cache.pop_lru(), None);
+    }
+
+    #[test]
+    fn replaces() {
+        let mut cache = BankLru::<u8, u8, 2>::new();
+        cache.put(1, 1);
+        cache.put(2, 2);
+        assert_eq!(cache.put(1, 10), Some((1, 1)));
+        assert_eq!(cache.get(&2), Some(&2));
+        assert_eq!(cache.put(3, 3), Some((1, 10)));
+        assert!(cache.iter().rev().eq([(&2, &2), (&3, &3)]));
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut cache = BankLru::<u8, u8, 0>::new();
+        assert_eq!(cache.put(1, 1), Some((1, 1)));
+        assert!(cache.is_empty());
+    }
+}
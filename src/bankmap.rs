@@ -0,0 +1,788 @@
+//! Fixed-size maps and sets searched by linear scan or binary search, see
+//! [`BankMap`] and [`BankSet`].
+
+use std::{borrow::Borrow, fmt, iter::FusedIterator, marker::PhantomData, mem, ops::Index, slice};
+use crate::{BankArr, errors::BankFullError};
+
+
+/// How a [`BankMap`] arranges and searches its pairs, either [`Linear`] or
+/// [`Sorted`].
+pub trait Backend {
+    /// Whether pairs are kept ordered by key, so removals must shift rather
+    /// than swap.
+    const SORTED: bool;
+}
+
+/// Locates keys of type `Q` among a [`BankMap`]'s pairs with keys of type `K`.
+pub trait Lookup<K, Q: ?Sized>: Backend {
+    /// Returns `Ok` with the index of the pair matching `key`, or `Err` with the
+    /// index a pair with that key should be inserted at.
+    fn search<V>(pairs: &[(K, V)], key: &Q) -> Result<usize, usize>;
+}
+
+/// The default [`BankMap`] backend, keeping pairs in insertion order and
+/// scanning them linearly.  Keys need only be [`Eq`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Linear;
+
+impl Backend for Linear {
+    const SORTED: bool = false;
+}
+
+impl<K: Borrow<Q>, Q: Eq + ?Sized> Lookup<K, Q> for Linear {
+    #[inline]
+    fn search<V>(pairs: &[(K, V)], key: &Q) -> Result<usize, usize> {
+        pairs.iter().position(|(k, _)| k.borrow() == key).ok_or(pairs.len())
+    }
+}
+
+/// A [`BankMap`] backend keeping pairs ordered by key, found by binary search.
+/// Keys must be [`Ord`].
+/// 
+/// Lookups take *O*(log `n`) time, at the cost of shifting pairs on insertion
+/// and removal, which pays off once maps hold a few dozen pairs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Sorted;
+
+impl Backend for Sorted {
+    const SORTED: bool = true;
+}
+
+impl<K: Borrow<Q>, Q: Ord + ?Sized> Lookup<K, Q> for Sorted {
+    #[inline]
+    fn search<V>(pairs: &[(K, V)], key: &Q) -> Result<usize, usize> {
+        pairs.binary_search_by(|(k, _)| k.borrow().cmp(key))
+    }
+}
+
+/// A [`BankMap`] ordered by key, see [`Sorted`].
+pub type SortedBankMap<K, V, const C: usize> = BankMap<K, V, C, Sorted>;
+
+/// A [`BankSet`] ordered by value, see [`Sorted`].
+pub type SortedBankSet<T, const C: usize> = BankSet<T, C, Sorted>;
+
+
+/// A fixed-capacity map storing up to `C` key-value pairs inline.
+/// 
+/// By default lookups scan the pairs linearly, which for the handful of entries
+/// a bank typically holds beats hashing.  Pairs are kept in insertion order until
+/// one is removed, when the last pair takes its place.
+/// 
+/// For larger maps the [`Sorted`] backend, used by the [`SortedBankMap`] alias,
+/// keeps pairs ordered by key and finds them by binary search.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::BankMap;
+/// 
+/// let mut map = BankMap::<&str, i32, 4>::new();
+/// map.insert("a", 1);
+/// map.insert("b", 2);
+/// 
+/// assert_eq!(map.get("a"), Some(&1));
+/// assert_eq!(map.insert("a", 3), Some(1));
+/// assert_eq!(map.remove("b"), Some(2));
+/// assert_eq!(map.len(), 1);
+/// ```
+/// 
+/// # Capacity
+/// 
+/// Inserting a new key into a full map panics, see [`try_insert`](Self::try_insert)
+/// for an alternative returning a [`Result`].
+#[derive(Clone)]
+pub struct BankMap<K, V, const C: usize, S = Linear> {
+    pairs: BankArr<(K, V), C>,
+    backend: PhantomData<S>,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<K: fmt::Debug, V: fmt::Debug, const C: usize, S> fmt::Debug for BankMap<K, V, C, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, const C: usize, S> Default for BankMap<K, V, C, S> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+
+impl<K, V: PartialEq, const C: usize, S: Lookup<K, K>> PartialEq for BankMap<K, V, C, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().all(|(k, v)| other.get(k) == Some(v))
+    }
+}
+
+impl<K, V: Eq, const C: usize, S: Lookup<K, K>> Eq for BankMap<K, V, C, S> {}
+
+impl<K, Q: ?Sized, V, const C: usize, S: Lookup<K, Q>> Index<&Q> for BankMap<K, V, C, S> {
+    type Output = V;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, key: &Q) -> &Self::Output {
+        self.get(key).expect("key not found in map")
+    }
+}
+
+impl<K, V, const C: usize, S: Lookup<K, K>> Extend<(K, V)> for BankMap<K, V, C, S> {
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, pairs: I) {
+        pairs.into_iter().for_each(|(key, value)| { self.insert(key, value); });
+    }
+}
+
+impl<K, V, const C: usize, const N: usize, S: Lookup<K, K>> From<[(K, V); N]> for BankMap<K, V, C, S> {
+
+    /// Creates a map from an array of pairs, later pairs overwriting earlier
+    /// ones with the same key.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if there are more than `C` distinct keys.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankMap;
+    /// 
+    /// let map = BankMap::<_, _, 4>::from([("a", 1), ("b", 2), ("a", 3)]);
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map["a"], 3);
+    /// ```
+    #[track_caller]
+    fn from(pairs: [(K, V); N]) -> Self {
+        let mut map = Self::new();
+        map.extend(pairs);
+        map
+    }
+}
+
+impl<K, V, const C: usize, S: Lookup<K, K>> FromIterator<(K, V)> for BankMap<K, V, C, S> {
+    #[track_caller]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(pairs: I) -> Self {
+        let mut map = Self::new();
+        map.extend(pairs);
+        map
+    }
+}
+
+impl<K, V, const C: usize, S> IntoIterator for BankMap<K, V, C, S> {
+    type Item = (K, V);
+    type IntoIter = crate::IntoIter<(K, V), BankArr<(K, V), C>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.pairs.into_iter() }
+}
+
+impl<'a, K, V, const C: usize, S> IntoIterator for &'a BankMap<K, V, C, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<'a, K, V, const C: usize, S> IntoIterator for &'a mut BankMap<K, V, C, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter_mut() }
+}
+
+impl<K, V, const C: usize, S> BankMap<K, V, C, S> {
+
+    /// Constructs a new, empty `BankMap<K, V, C>`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankMap;
+    /// 
+    /// let map = BankMap::<u8, char, 8>::new();
+    /// assert!(map.is_empty());
+    /// ```
+    pub const fn new() -> Self { Self { pairs: BankArr::new(), backend: PhantomData } }
+
+    /// Returns the number of pairs in the map.
+    #[inline]
+    pub const fn len(&self) -> usize { self.pairs.len() }
+
+    /// Returns `true` if the map contains no pairs.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.pairs.is_empty() }
+
+    /// Returns the number of pairs the map can hold, `C`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { C }
+
+    /// Returns an iterator over the map's keys and values, in insertion order
+    /// or, for a [`SortedBankMap`], ordered by key.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> { Iter { inner: self.pairs.iter() } }
+
+    /// Returns an iterator over the map's keys and mutable values.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> { IterMut { inner: self.pairs.iter_mut() } }
+
+    /// Returns an iterator over the map's keys.
+    #[inline]
+    pub fn keys(&self) -> impl ExactSizeIterator<Item = &K> + DoubleEndedIterator + Clone {
+        self.pairs.iter().map(|(key, _)| key)
+    }
+
+    /// Returns an iterator over the map's values.
+    #[inline]
+    pub fn values(&self) -> impl ExactSizeIterator<Item = &V> + DoubleEndedIterator + Clone {
+        self.pairs.iter().map(|(_, value)| value)
+    }
+
+    /// Returns an iterator over the map's mutable values.
+    #[inline]
+    pub fn values_mut(&mut self) -> impl ExactSizeIterator<Item = &mut V> + DoubleEndedIterator {
+        self.pairs.iter_mut().map(|(_, value)| value)
+    }
+
+    /// Removes every pair from the map.
+    #[inline]
+    pub fn clear(&mut self) { self.pairs.truncate(0) }
+
+    /// Retains only the pairs for which `f` returns `true`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankMap;
+    /// 
+    /// let mut map = BankMap::<_, _, 4>::from([(1, 'a'), (2, 'b'), (3, 'c')]);
+    /// map.retain(|key, _| key % 2 == 1);
+    /// assert_eq!(map.len(), 2);
+    /// assert!(!map.contains_key(&2));
+    /// ```
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) where S: Backend {
+        let mut index = 0;
+        while index < self.len() {
+            let (key, value) = &mut self.pairs[index];
+            match f(key, value) {
+                true => index += 1,
+                false => drop(self.remove_index(index)),
+            }
+        }
+    }
+
+    #[inline]
+    fn remove_index(&mut self, index: usize) -> (K, V) where S: Backend {
+        match S::SORTED {
+            true => self.pairs.remove(index),
+            false => self.pairs.swap_remove(index),
+        }
+    }
+}
+
+impl<K, V, const C: usize, S: Backend> BankMap<K, V, C, S> {
+
+    #[inline]
+    fn position<Q: ?Sized>(&self, key: &Q) -> Option<usize> where S: Lookup<K, Q> {
+        S::search(&self.pairs, key).ok()
+    }
+
+    /// Returns `true` if the map contains `key`.
+    #[inline]
+    pub fn contains_key<Q: ?Sized>(&self, key: &Q) -> bool where S: Lookup<K, Q> {
+        self.position(key).is_some()
+    }
+
+    /// Returns a reference to the value for `key`, or `None` if the map doesn't
+    /// contain it.
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(`n`) time, or *O*(log `n`) for a [`SortedBankMap`].
+    #[inline]
+    pub fn get<Q: ?Sized>(&self, key: &Q) -> Option<&V> where S: Lookup<K, Q> {
+        self.position(key).map(|index| &self.pairs[index].1)
+    }
+
+    /// Returns a mutable reference to the value for `key`, or `None` if the map
+    /// doesn't contain it.
+    #[inline]
+    pub fn get_mut<Q: ?Sized>(&mut self, key: &Q) -> Option<&mut V> where S: Lookup<K, Q> {
+        self.position(key).map(|index| &mut self.pairs[index].1)
+    }
+
+    /// Returns the stored key and value for `key`, or `None` if the map doesn't
+    /// contain it.
+    #[inline]
+    pub fn get_key_value<Q: ?Sized>(&self, key: &Q) -> Option<(&K, &V)> where S: Lookup<K, Q> {
+        self.position(key).map(|index| { let (k, v) = &self.pairs[index]; (k, v) })
+    }
+
+    /// Inserts a key-value pair into the map, returning the previous value if the
+    /// key was already present.  The stored key isn't replaced.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `key` is new and the map is full.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankMap;
+    /// 
+    /// let mut map = BankMap::<_, _, 2>::new();
+    /// assert_eq!(map.insert(1, "a"), None);
+    /// assert_eq!(map.insert(1, "b"), Some("a"));
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(`n`) time.
+    #[inline]
+    #[track_caller]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> where S: Lookup<K, K> {
+        match self.try_insert(key, value) {
+            Ok(previous) => previous,
+            Err(_) => panic!("capacity exceeded during operation `insert`: capacity is {C}"),
+        }
+    }
+
+    /// Attempts to insert a key-value pair into the map, returning the previous
+    /// value if the key was already present.  If the key is new and the map is
+    /// full, the pair is handed back inside the error.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankMap;
+    /// 
+    /// let mut map = BankMap::<_, _, 1>::new();
+    /// assert_eq!(map.try_insert(1, "a"), Ok(None));
+    /// assert_eq!(map.try_insert(1, "b"), Ok(Some("a")));
+    /// assert_eq!(map.try_insert(2, "c").unwrap_err().element(), (2, "c"));
+    /// ```
+    pub fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, BankFullError<(K, V)>> where S: Lookup<K, K> {
+        match S::search(&self.pairs, &key) {
+            Ok(index) => Ok(Some(mem::replace(&mut self.pairs[index].1, value))),
+            Err(index) => match self.pairs.try_insert(index, (key, value)) {
+                Ok(()) => Ok(None),
+                Err(err) => Err(BankFullError::new(err.element())),
+            },
+        }
+    }
+
+    /// Removes `key` from the map, returning its value if it was present.
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(`n`) time.
+    #[inline]
+    pub fn remove<Q: ?Sized>(&mut self, key: &Q) -> Option<V> where S: Lookup<K, Q> {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Removes `key` from the map, returning the stored key and value if it was
+    /// present.
+    #[inline]
+    pub fn remove_entry<Q: ?Sized>(&mut self, key: &Q) -> Option<(K, V)> where S: Lookup<K, Q> {
+        self.position(key).map(|index| self.remove_index(index))
+    }
+
+    /// Returns a mutable reference to the value for `key`, first inserting the
+    /// result of `f` if the map doesn't contain it.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `key` is new and the map is full.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankMap;
+    /// 
+    /// let mut counts = BankMap::<char, u32, 8>::new();
+    /// for c in "hello".chars() {
+    ///     *counts.get_or_insert_with(c, || 0) += 1;
+    /// }
+    /// assert_eq!(counts[&'l'], 2);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn get_or_insert_with<F: FnOnce() -> V>(&mut self, key: K, f: F) -> &mut V where S: Lookup<K, K> {
+        match self.try_get_or_insert_with(key, f) {
+            Ok(value) => value,
+            Err(_) => panic!("capacity exceeded during operation `get_or_insert_with`: capacity is {C}"),
+        }
+    }
+
+    /// Returns a mutable reference to the value for `key`, first inserting the
+    /// result of `f` if the map doesn't contain it.  If the key is new and the
+    /// map is full, `f` isn't called and the key is handed back inside the error.
+    pub fn try_get_or_insert_with<F: FnOnce() -> V>(&mut self, key: K, f: F) -> Result<&mut V, BankFullError<K>>
+    where S: Lookup<K, K> {
+        let index = match S::search(&self.pairs, &key) {
+            Ok(index) => index,
+            Err(_) if self.pairs.remaining_capacity() == 0 => return Err(BankFullError::new(key)),
+            Err(index) => {
+                self.pairs.insert(index, (key, f()));
+                index
+            }
+        };
+        Ok(&mut self.pairs[index].1)
+    }
+}
+
+
+/// An iterator over the keys and values of a [`BankMap`].
+/// 
+/// Created by [`BankMap::iter`].
+#[derive(Debug, Clone)]
+pub struct Iter<'a, K, V> {
+    inner: slice::Iter<'a, (K, V)>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> { self.inner.next().map(|(k, v)| (k, v)) }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { self.inner.size_hint() }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> { self.inner.next_back().map(|(k, v)| (k, v)) }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+/// A mutable iterator over the keys and values of a [`BankMap`].
+/// 
+/// Created by [`BankMap::iter_mut`].
+#[derive(Debug)]
+pub struct IterMut<'a, K, V> {
+    inner: slice::IterMut<'a, (K, V)>,
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> { self.inner.next().map(|(k, v)| (&*k, v)) }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { self.inner.size_hint() }
+}
+
+impl<K, V> DoubleEndedIterator for IterMut<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> { self.inner.next_back().map(|(k, v)| (&*k, v)) }
+}
+
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {}
+
+impl<K, V> FusedIterator for IterMut<'_, K, V> {}
+
+
+/// A fixed-capacity set storing up to `C` values inline, a [`BankMap`] without
+/// values.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::BankSet;
+/// 
+/// let mut set = BankSet::<i32, 4>::new();
+/// assert!(set.insert(1));
+/// assert!(!set.insert(1));
+/// assert!(set.contains(&1));
+/// assert!(set.remove(&1));
+/// assert!(set.is_empty());
+/// ```
+#[derive(Clone, Default)]
+pub struct BankSet<T, const C: usize, S = Linear> {
+    map: BankMap<T, (), C, S>,
+}
+
+impl<T, const C: usize, S: Lookup<T, T>> PartialEq for BankSet<T, C, S> {
+    fn eq(&self, other: &Self) -> bool { self.map == other.map }
+}
+
+impl<T, const C: usize, S: Lookup<T, T>> Eq for BankSet<T, C, S> {}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: fmt::Debug, const C: usize, S> fmt::Debug for BankSet<T, C, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const C: usize, S: Lookup<T, T>> Extend<T> for BankSet<T, C, S> {
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, values: I) {
+        values.into_iter().for_each(|value| { self.insert(value); });
+    }
+}
+
+impl<T, const C: usize, const N: usize, S: Lookup<T, T>> From<[T; N]> for BankSet<T, C, S> {
+
+    /// Creates a set from an array, dropping duplicates.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if there are more than `C` distinct values.
+    #[track_caller]
+    fn from(values: [T; N]) -> Self {
+        let mut set = Self::new();
+        set.extend(values);
+        set
+    }
+}
+
+impl<T, const C: usize, S: Lookup<T, T>> FromIterator<T> for BankSet<T, C, S> {
+    #[track_caller]
+    fn from_iter<I: IntoIterator<Item = T>>(values: I) -> Self {
+        let mut set = Self::new();
+        set.extend(values);
+        set
+    }
+}
+
+impl<'a, T, const C: usize, S> IntoIterator for &'a BankSet<T, C, S> {
+    type Item = &'a T;
+    type IntoIter = SetIter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<T, const C: usize, S> BankSet<T, C, S> {
+
+    /// Constructs a new, empty `BankSet<T, C>`.
+    pub const fn new() -> Self { Self { map: BankMap::new() } }
+
+    /// Returns the number of values in the set.
+    #[inline]
+    pub const fn len(&self) -> usize { self.map.len() }
+
+    /// Returns `true` if the set contains no values.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.map.is_empty() }
+
+    /// Returns the number of values the set can hold, `C`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { C }
+
+    /// Returns an iterator over the set's values.
+    #[inline]
+    pub fn iter(&self) -> SetIter<'_, T> { SetIter { inner: self.map.iter() } }
+
+    /// Removes every value from the set.
+    #[inline]
+    pub fn clear(&mut self) { self.map.clear() }
+
+    /// Retains only the values for which `f` returns `true`.
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) where S: Backend {
+        self.map.retain(|value, _| f(value))
+    }
+}
+
+impl<T, const C: usize, S: Backend> BankSet<T, C, S> {
+
+    /// Returns `true` if the set contains `value`.
+    #[inline]
+    pub fn contains<Q: ?Sized>(&self, value: &Q) -> bool where S: Lookup<T, Q> {
+        self.map.contains_key(value)
+    }
+
+    /// Returns a reference to the stored value equal to `value`, if any.
+    #[inline]
+    pub fn get<Q: ?Sized>(&self, value: &Q) -> Option<&T> where S: Lookup<T, Q> {
+        self.map.get_key_value(value).map(|(value, _)| value)
+    }
+
+    /// Adds a value to the set, returning `true` if it wasn't already present.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `value` is new and the set is full.
+    #[inline]
+    #[track_caller]
+    pub fn insert(&mut self, value: T) -> bool where S: Lookup<T, T> {
+        match self.try_insert(value) {
+            Ok(inserted) => inserted,
+            Err(_) => panic!("capacity exceeded during operation `insert`: capacity is {C}"),
+        }
+    }
+
+    /// Attempts to add a value to the set, returning whether it wasn't already
+    /// present.  If the value is new and the set is full, it's handed back
+    /// inside the error.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankSet;
+    /// 
+    /// let mut set = BankSet::<i32, 1>::new();
+    /// assert_eq!(set.try_insert(1), Ok(true));
+    /// assert_eq!(set.try_insert(1), Ok(false));
+    /// assert_eq!(set.try_insert(2).unwrap_err().element(), 2);
+    /// ```
+    pub fn try_insert(&mut self, value: T) -> Result<bool, BankFullError<T>> where S: Lookup<T, T> {
+        match self.map.try_insert(value, ()) {
+            Ok(previous) => Ok(previous.is_none()),
+            Err(err) => Err(BankFullError::new(err.element().0)),
+        }
+    }
+
+    /// Removes `value` from the set, returning `true` if it was present.
+    #[inline]
+    pub fn remove<Q: ?Sized>(&mut self, value: &Q) -> bool where S: Lookup<T, Q> {
+        self.map.remove(value).is_some()
+    }
+
+    /// Removes and returns the stored value equal to `value`, if any.
+    #[inline]
+    pub fn take<Q: ?Sized>(&mut self, value: &Q) -> Option<T> where S: Lookup<T, Q> {
+        self.map.remove_entry(value).map(|(value, _)| value)
+    }
+}
+
+/// An iterator over the values of a [`BankSet`].
+/// 
+/// Created by [`BankSet::iter`].
+#[derive(Debug, Clone)]
+pub struct SetIter<'a, T> {
+    inner: Iter<'a, T, ()>,
+}
+
+impl<'a, T> Iterator for SetIter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> { self.inner.next().map(|(value, _)| value) }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { self.inner.size_hint() }
+}
+
+impl<T> DoubleEndedIterator for SetIter<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> { self.inner.next_back().map(|(value, _)| value) }
+}
+
+impl<T> ExactSizeIterator for SetIter<'_, T> {}
+
+impl<T> FusedIterator for SetIter<'_, T> {}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn insert_get_remove() {
+        let mut map = BankMap::<String, i32, 3>::new();
+        assert_eq!(map.insert("a".to_string(), 1), None);
+        assert_eq!(map.insert("b".to_string(), 2), None);
+        assert_eq!(map.insert("a".to_string(), 3), Some(1));
+        assert_eq!(map.len(), 2);
+
+        assert_eq!(map.get("a"), Some(&3));
+        assert_eq!(map["b"], 2);
+        assert_eq!(map.get("c"), None);
+        *map.get_mut("b").unwrap() += 1;
+        assert_eq!(map.get_key_value("b"), Some((&"b".to_string(), &3)));
+
+        assert_eq!(map.remove("a"), Some(3));
+        assert_eq!(map.remove("a"), None);
+        assert!(!map.contains_key("a"));
+        assert!(map.contains_key("b"));
+    }
+
+    #[test]
+    fn full() {
+        let mut map = BankMap::<i32, i32, 2>::from([(1, 1), (2, 2)]);
+        assert_eq!(map.try_insert(3, 3), Err(BankFullError::new((3, 3))));
+        assert_eq!(map.try_insert(2, 4), Ok(Some(2)));
+        assert_eq!(map.try_get_or_insert_with(3, || unreachable!()), Err(BankFullError::new(3)));
+        assert_eq!(map.try_get_or_insert_with(1, || unreachable!()), Ok(&mut 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity exceeded during operation `insert`: capacity is 1")]
+    fn insert_to_full() {
+        let mut map = BankMap::<i32, i32, 1>::from([(1, 1)]);
+        map.insert(2, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "key not found in map")]
+    fn index_missing() {
+        let map = BankMap::<i32, i32, 1>::new();
+        let _ = map[&1];
+    }
+
+    #[test]
+    fn iter() {
+        let mut map = BankMap::<i32, char, 4>::from([(1, 'a'), (2, 'b'), (3, 'c')]);
+        assert_eq!(map.iter().collect::<Vec<_>>(), [(&1, &'a'), (&2, &'b'), (&3, &'c')]);
+        assert_eq!(map.keys().rev().copied().collect::<Vec<_>>(), [3, 2, 1]);
+
+        map.values_mut().for_each(|value| *value = value.to_ascii_uppercase());
+        for (_, value) in &mut map {
+            *value = (*value as u8 + 1) as char;
+        }
+        assert_eq!(map.values().collect::<String>(), "BCD");
+
+        // The last pair fills the gap left by a removal
+        map.remove(&1);
+        assert_eq!(map.into_iter().collect::<Vec<_>>(), [(3, 'D'), (2, 'C')]);
+    }
+
+    #[test]
+    fn eq_unordered() {
+        let a = BankMap::<i32, i32, 3>::from([(1, 1), (2, 2)]);
+        let b = BankMap::<i32, i32, 3>::from([(2, 2), (1, 1)]);
+        assert_eq!(a, b);
+        assert_ne!(a, BankMap::from([(1, 1), (2, 3)]));
+        assert_ne!(a, BankMap::from([(1, 1)]));
+    }
+
+    #[test]
+    fn set() {
+        let mut set: BankSet<String, 3> = ["a", "b", "a"].into_iter().map(String::from).collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains("a"));
+        assert_eq!(set.get("b").map(String::as_str), Some("b"));
+
+        assert_eq!(set.try_insert("c".to_string()), Ok(true));
+        assert_eq!(set.try_insert("d".to_string()), Err(BankFullError::new("d".to_string())));
+
+        assert!(set.remove("a"));
+        assert_eq!(set.take("b").unwrap(), "b");
+        set.retain(|value| value != "c");
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn sorted() {
+        let mut map = SortedBankMap::<i32, char, 4>::from([(3, 'c'), (1, 'a'), (4, 'd')]);
+        assert_eq!(map.keys().copied().collect::<Vec<_>>(), [1, 3, 4]);
+        assert_eq!(map.insert(2, 'b'), None);
+        assert_eq!(map.insert(3, 'C'), Some('c'));
+        assert_eq!(map.try_insert(0, '0'), Err(BankFullError::new((0, '0'))));
+        assert_eq!(map.values().collect::<String>(), "abCd");
+
+        // Removals keep the remaining pairs ordered
+        assert_eq!(map.remove(&1), Some('a'));
+        map.retain(|&key, _| key != 3);
+        assert_eq!(map.iter().collect::<Vec<_>>(), [(&2, &'b'), (&4, &'d')]);
+        *map.get_or_insert_with(3, || 'x') = 'y';
+        assert_eq!(map.into_iter().collect::<Vec<_>>(), [(2, 'b'), (3, 'y'), (4, 'd')]);
+
+        let set: SortedBankSet<&str, 4> = ["pear", "apple", "fig", "apple"].into_iter().collect();
+        assert_eq!(set.iter().copied().collect::<Vec<_>>(), ["apple", "fig", "pear"]);
+        assert!(set.contains("fig"));
+        assert_eq!(set, SortedBankSet::from(["fig", "pear", "apple"]));
+    }
+}
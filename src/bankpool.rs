@@ -0,0 +1,445 @@
+//! A pool of spare heap buffers for spilled banks, see [`BankPool`].
+
+use std::{cell::RefCell, fmt, mem::{self, ManuallyDrop}, ops::{AddAssign, Deref}};
+
+use crate::BankVec;
+
+
+/// A pool of heap buffers which [`BankVec`]s spill into, and which they hand
+/// back when dropped.
+/// 
+/// Workloads that repeatedly fill a bank past `C` and then discard it would
+/// otherwise allocate and free a buffer every cycle.  Banks taken from the pool
+/// with [`get`](BankPool::get) draw a spare buffer when they first spill and
+/// return it once they're done, so after warming up the cycle allocates
+/// nothing.
+/// 
+/// A pool made with [`with_max_buffers`](BankPool::with_max_buffers) keeps at
+/// most that many spare buffers, freeing any returned beyond them.
+/// 
+/// The pool isn't `Sync`; give each thread its own, e.g. in a `thread_local!`.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::BankPool;
+/// 
+/// let pool = BankPool::<u32, 8>::new();
+/// 
+/// let mut bank = pool.get();
+/// bank.extend(0..100);
+/// let ptr = bank.as_ptr();
+/// drop(bank);
+/// assert_eq!(pool.len(), 1);
+/// 
+/// // The next spill reuses the returned buffer
+/// let mut bank = pool.get();
+/// bank.extend(0..100);
+/// assert_eq!(bank.as_ptr(), ptr);
+/// ```
+pub struct BankPool<T, const C: usize> {
+    // Empty vecs, each with a capacity greater than `C`
+    buffers: RefCell<Vec<Vec<T>>>,
+    max_buffers: usize,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, const C: usize> fmt::Debug for BankPool<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let capacities = self.buffers.borrow().iter().map(Vec::capacity).collect::<Vec<_>>();
+        f.debug_struct("BankPool")
+            .field("buffers", &capacities)
+            .field("max_buffers", &self.max_buffers)
+            .finish()
+    }
+}
+
+impl<T, const C: usize> Default for BankPool<T, C> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+
+impl<T, const C: usize> BankPool<T, C> {
+
+    /// Constructs a new, empty `BankPool<T, C>`, which keeps every buffer
+    /// returned to it.
+    #[inline]
+    pub const fn new() -> Self { Self::with_max_buffers(usize::MAX) }
+
+    /// Constructs a new, empty `BankPool<T, C>` which keeps at most
+    /// `max_buffers` spare buffers, freeing any returned beyond them.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::{BankPool, BankVec};
+    /// 
+    /// let pool = BankPool::<i32, 2>::with_max_buffers(1);
+    /// pool.recycle(BankVec::from([1, 2, 3]));
+    /// pool.recycle(BankVec::from([4, 5, 6]));
+    /// assert_eq!(pool.len(), 1);
+    /// ```
+    #[inline]
+    pub const fn with_max_buffers(max_buffers: usize) -> Self {
+        Self { buffers: RefCell::new(Vec::new()), max_buffers }
+    }
+
+    /// Returns the greatest number of spare buffers the pool keeps.
+    #[inline]
+    pub const fn max_buffers(&self) -> usize { self.max_buffers }
+
+    /// Returns the number of spare buffers in the pool.
+    #[inline]
+    pub fn len(&self) -> usize { self.buffers.borrow().len() }
+
+    /// Returns `true` if the pool holds no spare buffers.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Frees every spare buffer in the pool.
+    #[inline]
+    pub fn clear(&self) { self.buffers.borrow_mut().clear() }
+
+    /// Returns a new, empty bank which spills into the pool's buffers.
+    #[inline]
+    pub fn get(&self) -> Pooled<'_, T, C> {
+        Pooled { bank: ManuallyDrop::new(BankVec::new()), pool: self }
+    }
+
+    /// Keeps the heap buffer of a spilled bank for reuse, dropping its
+    /// elements.  Inline banks, and spilled banks once the pool holds
+    /// [`max_buffers`](BankPool::max_buffers), are simply dropped.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::{BankPool, BankVec};
+    /// 
+    /// let pool = BankPool::<i32, 2>::new();
+    /// pool.recycle(BankVec::from([1, 2]));
+    /// assert!(pool.is_empty());
+    /// 
+    /// pool.recycle(BankVec::from([1, 2, 3]));
+    /// assert_eq!(pool.len(), 1);
+    /// ```
+    pub fn recycle(&self, bank: BankVec<T, C>) {
+        if bank.on_heap() {
+            let mut vec = Vec::from(bank);
+            vec.clear();
+            self.keep(vec);
+        }
+    }
+
+    // Keeps an empty buffer for reuse, unless the pool is already full.
+    fn keep(&self, vec: Vec<T>) {
+        let mut buffers = self.buffers.borrow_mut();
+        if buffers.len() < self.max_buffers { buffers.push(vec) }
+    }
+
+    // Takes a spare buffer able to hold at least `capacity` elements.
+    fn take(&self, capacity: usize) -> Option<Vec<T>> {
+        let mut buffers = self.buffers.borrow_mut();
+        let index = buffers.iter().rposition(|vec| vec.capacity() >= capacity)?;
+        Some(buffers.swap_remove(index))
+    }
+}
+
+
+/// A [`BankVec`] drawn from a [`BankPool`], returning its heap buffer to the
+/// pool when dropped.
+/// 
+/// `Pooled` dereferences to its `BankVec` for reading only.  Its methods that
+/// grow the bank, along with [`Extend`] and `+=`, draw on the pool when
+/// spilling.  Mutating through a plain `BankVec` would allocate behind the
+/// pool's back, so for anything else take the bank out with
+/// [`into_inner`](Pooled::into_inner).
+/// 
+/// Created by [`BankPool::get`].
+pub struct Pooled<'a, T, const C: usize> {
+    bank: ManuallyDrop<BankVec<T, C>>,
+    pool: &'a BankPool<T, C>,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: fmt::Debug, const C: usize> fmt::Debug for Pooled<'_, T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Pooled").field(&self.bank.as_slice()).finish()
+    }
+}
+
+impl<T, const C: usize> Deref for Pooled<'_, T, C> {
+    type Target = BankVec<T, C>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target { &self.bank }
+}
+
+impl<T, const C: usize> Drop for Pooled<'_, T, C> {
+    fn drop(&mut self) {
+        // The bank is never touched again after being taken
+        self.pool.recycle(unsafe { ManuallyDrop::take(&mut self.bank) });
+    }
+}
+
+impl<T, const C: usize> Extend<T> for Pooled<'_, T, C> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        iter.for_each(|value| self.push(value));
+    }
+}
+
+impl<T: Clone, const C: usize> AddAssign<&[T]> for Pooled<'_, T, C> {
+
+    /// Appends clones of the elements of `rhs` onto the back of the bank,
+    /// drawing a buffer from the pool if it spills.
+    #[inline]
+    fn add_assign(&mut self, rhs: &[T]) { self.extend(rhs.iter().cloned()) }
+}
+
+impl<T, const C: usize> Pooled<'_, T, C> {
+
+    /// Appends an element to the back of the bank, drawing a buffer from the
+    /// pool if it spills.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    #[inline]
+    #[track_caller]
+    pub fn push(&mut self, value: T) {
+        self.reserve(1);
+        self.bank.push(value);
+    }
+
+    /// Inserts an element at position `index`, shifting all elements after it
+    /// to the right and drawing a buffer from the pool if the bank spills.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index > len` or if the new capacity exceeds `isize::MAX` bytes.
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, element: T) {
+        self.reserve(1);
+        self.bank.insert(index, element);
+    }
+
+    /// Removes the last element from the bank and returns it, or `None` if it
+    /// is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> { self.bank.pop() }
+
+    /// Removes and returns the element at position `index`, shifting all
+    /// elements after it to the left.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn remove(&mut self, index: usize) -> T { self.bank.remove(index) }
+
+    /// Removes and returns the element at position `index`, replacing it with
+    /// the last element.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn swap_remove(&mut self, index: usize) -> T { self.bank.swap_remove(index) }
+
+    /// Shortens the bank to `len` elements, dropping the rest.  The heap buffer
+    /// is kept, see [`shrink_to_fit`](Pooled::shrink_to_fit).
+    #[inline]
+    pub fn truncate(&mut self, len: usize) { self.bank.truncate(len) }
+
+    /// Removes all elements from the bank, dropping them.
+    #[inline]
+    pub fn clear(&mut self) { self.bank.clear() }
+
+    /// Extracts a mutable slice of the entire bank.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] { self.bank.as_mut_slice() }
+
+    /// Reserves capacity for at least `additional` more elements, drawing a
+    /// buffer from the pool if the bank spills.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    #[track_caller]
+    pub fn reserve(&mut self, additional: usize) {
+        let needed = self.bank.len().saturating_add(additional);
+        if !self.bank.on_heap() && needed > C
+            && let Some(vec) = self.pool.take(needed)
+        {
+            self.bank.adopt_heap(vec);
+        }
+        self.bank.reserve(additional);
+    }
+
+    /// Shrinks the bank's heap allocation to fit its length, as with
+    /// [`BankVec::shrink_to_fit`], except that a bank whose elements fit back
+    /// inline returns its buffer to the pool rather than freeing it.
+    /// 
+    /// A bank whose length swings back and forth across `C` thus reuses one
+    /// buffer rather than allocating and freeing one every cycle, even from a
+    /// pool of its own.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankPool;
+    /// 
+    /// let pool = BankPool::<u32, 4>::new();
+    /// let mut bank = pool.get();
+    /// bank.extend(0..10);
+    /// let ptr = bank.as_ptr();
+    /// 
+    /// bank.truncate(2);
+    /// bank.shrink_to_fit();
+    /// assert!(!bank.on_heap());
+    /// assert_eq!(pool.len(), 1);
+    /// 
+    /// bank.extend(2..10);
+    /// assert_eq!(bank.as_ptr(), ptr);
+    /// ```
+    #[track_caller]
+    pub fn shrink_to_fit(&mut self) {
+        match self.bank.on_heap() && self.bank.len() <= C {
+            true => {
+                let vec = self.bank.release_heap();
+                self.pool.keep(vec);
+            }
+            false => self.bank.shrink_to_fit(),
+        }
+    }
+
+    /// Detaches the bank from the pool, so its buffer is freed rather than
+    /// returned when dropped.
+    #[inline]
+    pub fn into_inner(mut self) -> BankVec<T, C> {
+        let bank = mem::take(&mut *self.bank);
+        mem::forget(self);
+        bank
+    }
+}
+
+impl<T: Copy, const C: usize> Pooled<'_, T, C> {
+
+    /// Copies the elements of `other` onto the back of the bank, drawing a
+    /// buffer from the pool if it spills.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    #[inline]
+    #[track_caller]
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        self.reserve(other.len());
+        self.bank.extend_from_slice(other);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn reuses_buffers() {
+        let pool = BankPool::<String, 2>::new();
+        let mut bank = pool.get();
+        bank.push("a".to_string());
+        assert!(!bank.on_heap());
+        bank.extend(["b", "c", "d"].map(String::from));
+        assert!(bank.on_heap());
+        let (ptr, capacity) = (bank.as_ptr(), bank.capacity());
+        drop(bank);
+
+        for _ in 0..3 {
+            let mut bank = pool.get();
+            bank.extend(["e", "f"].map(String::from));
+            assert!(pool.len() == 1 && !bank.on_heap());
+            bank.push("g".to_string());
+            assert!(pool.is_empty());
+            assert_eq!((bank.as_ptr(), bank.capacity()), (ptr, capacity));
+            assert_eq!(bank.as_slice(), ["e", "f", "g"]);
+        }
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[test]
+    fn shrink_to_fit() {
+        let pool = BankPool::<String, 2>::new();
+        let mut bank = pool.get();
+        bank.extend(["a", "b", "c", "d", "e"].map(String::from));
+
+        // Too long to move inline, so the buffer shrinks as usual
+        bank.truncate(3);
+        bank.shrink_to_fit();
+        assert_eq!((bank.capacity(), pool.len()), (3, 0));
+        let ptr = bank.as_ptr();
+
+        bank.pop();
+        bank.shrink_to_fit();
+        assert_eq!((bank.as_slice(), pool.len()), (&["a", "b"].map(String::from)[..], 1));
+
+        bank.push("f".to_string());
+        assert!(pool.is_empty());
+        assert_eq!((bank.as_ptr(), bank.capacity()), (ptr, 3));
+        drop(bank);
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[test]
+    fn too_small() {
+        let pool = BankPool::<i32, 2>::new();
+        pool.recycle(BankVec::from([1, 2, 3]));
+        let capacity = pool.buffers.borrow()[0].capacity();
+
+        let mut bank = pool.get();
+        bank.reserve(capacity + 1);
+        assert_eq!(pool.len(), 1);
+
+        let bank = bank.into_inner();
+        assert!(bank.on_heap());
+        assert_eq!(pool.len(), 1);
+
+        pool.clear();
+        assert!(pool.is_empty());
+    }
+
+    #[test]
+    fn growing_draws_on_pool() {
+        let pool = BankPool::<u8, 2>::new();
+        pool.recycle(BankVec::from([0; 8]));
+        let ptr = pool.buffers.borrow()[0].as_ptr();
+
+        let mut bank = pool.get();
+        bank.extend_from_slice(&[1, 2, 3]);
+        assert_eq!((bank.as_ptr(), pool.len()), (ptr, 0));
+        drop(bank);
+
+        let mut bank = pool.get();
+        bank += &[1, 2][..];
+        bank.insert(0, 0);
+        assert_eq!((bank.as_ptr(), pool.len()), (ptr, 0));
+        assert_eq!(bank.as_slice(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn max_buffers() {
+        let pool = BankPool::<i32, 2>::with_max_buffers(1);
+        let (mut a, mut b) = (pool.get(), pool.get());
+        a.extend(0..4);
+        b.extend(0..4);
+        drop((a, b));
+        assert_eq!((pool.len(), pool.max_buffers()), (1, 1));
+
+        let mut bank = pool.get();
+        bank.extend(0..4);
+        bank.truncate(1);
+        pool.recycle(BankVec::from([0; 3]));
+        bank.shrink_to_fit();
+        assert_eq!(pool.len(), 1);
+    }
+}
@@ -0,0 +1,361 @@
+
+use std::{cell::UnsafeCell, cmp, fmt, mem::MaybeUninit, sync::atomic::{self, AtomicUsize, Ordering}};
+use crate::{bankspsc::CachePadded, errors::BankFullError};
+
+
+// A slot in the queue, its stamp recording which position may use it next.  A
+// stamp equal to a position frees the slot for the push there, and one past it
+// fills the slot for the pop from there.
+struct Slot<T> {
+    stamp: AtomicUsize,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+/// A fixed-size queue shared between any number of producing and consuming
+/// threads, holding up to `C` elements inline without locks or allocation.
+/// 
+/// Each slot carries a sequence stamp, as in Dmitry Vyukov's bounded queue and
+/// crossbeam's `ArrayQueue`, so pushes and pops only contend on the queue's two
+/// indices.  Where there's just one thread on each side, [`BankSpsc`](crate::BankSpsc)
+/// is cheaper.
+/// 
+/// # Examples
+/// ```
+/// use std::thread;
+/// use bankarr::BankQueue;
+/// 
+/// let queue = BankQueue::<u32, 16>::new();
+/// 
+/// thread::scope(|s| {
+///     for worker in 0..4 {
+///         let queue = &queue;
+///         s.spawn(move || {
+///             for job in 0..4 {
+///                 while queue.try_push(worker * 4 + job).is_err() {
+///                     thread::yield_now();
+///                 }
+///             }
+///         });
+///     }
+/// });
+/// 
+/// let mut jobs = std::iter::from_fn(|| queue.pop()).collect::<Vec<_>>();
+/// jobs.sort();
+/// assert_eq!(jobs, (0..16).collect::<Vec<_>>());
+/// ```
+pub struct BankQueue<T, const C: usize> {
+    // Both indices pack a slot index into their low bits and a lap count above
+    // them, as crossbeam's `ArrayQueue` does.  A lap is `ONE_LAP` rather than
+    // `C`, so the slot index is a mask whatever `C` is and stays correct when
+    // the lap count wraps.
+    head: CachePadded<AtomicUsize>,
+    tail: CachePadded<AtomicUsize>,
+    slots: [Slot<T>; C],
+}
+
+unsafe impl<T: Send, const C: usize> Send for BankQueue<T, C> {}
+unsafe impl<T: Send, const C: usize> Sync for BankQueue<T, C> {}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, const C: usize> fmt::Debug for BankQueue<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BankQueue").field("len", &self.len()).field("capacity", &C).finish()
+    }
+}
+
+impl<T, const C: usize> Default for BankQueue<T, C> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+
+impl<T, const C: usize> Drop for BankQueue<T, C> {
+    fn drop(&mut self) {
+        while self.pop().is_some() {}
+    }
+}
+
+impl<T, const C: usize> BankQueue<T, C> {
+
+    // The smallest power of two greater than `C`, so a lap always leaves a
+    // spare bit between the index and the lap count
+    const ONE_LAP: usize = (C + 1).next_power_of_two();
+
+    // The position after `pos`, moving on to the next lap past the last slot
+    #[inline]
+    const fn advance(pos: usize) -> usize {
+        let index = pos & (Self::ONE_LAP - 1);
+        match index + 1 < C {
+            true => pos + 1,
+            false => (pos & !(Self::ONE_LAP - 1)).wrapping_add(Self::ONE_LAP),
+        }
+    }
+
+    /// Constructs a new, empty `BankQueue<T, C>`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankQueue;
+    /// 
+    /// static JOBS: BankQueue<u64, 64> = BankQueue::new();
+    /// assert!(JOBS.is_empty());
+    /// ```
+    pub const fn new() -> Self {
+        let mut slots = [const { Slot { stamp: AtomicUsize::new(0), value: UnsafeCell::new(MaybeUninit::uninit()) } }; C];
+        let mut index = 0;
+        while index < C {
+            slots[index].stamp = AtomicUsize::new(index);
+            index += 1;
+        }
+        Self {
+            head: CachePadded(AtomicUsize::new(0)),
+            tail: CachePadded(AtomicUsize::new(0)),
+            slots,
+        }
+    }
+
+    /// Returns the number of elements in the queue, which may be stale by the
+    /// time it's read.
+    pub fn len(&self) -> usize {
+        loop {
+            let tail = self.tail.load(Ordering::SeqCst);
+            let head = self.head.load(Ordering::SeqCst);
+            // Only trust the difference if no push raced between the loads
+            if self.tail.load(Ordering::SeqCst) == tail {
+                let head_index = head & (Self::ONE_LAP - 1);
+                let tail_index = tail & (Self::ONE_LAP - 1);
+                return match head_index.cmp(&tail_index) {
+                    cmp::Ordering::Less => tail_index - head_index,
+                    cmp::Ordering::Greater => C - head_index + tail_index,
+                    // The same slot is either empty or a lap behind, full
+                    cmp::Ordering::Equal if tail == head => 0,
+                    cmp::Ordering::Equal => C,
+                };
+            }
+        }
+    }
+
+    /// Returns `true` if the queue contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Returns `true` if the queue holds `C` elements.
+    #[inline]
+    pub fn is_full(&self) -> bool { self.len() == C }
+
+    /// Returns the number of elements the queue can hold, `C`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { C }
+
+    /// Attempts to push an element onto the back of the queue, handing it back
+    /// inside the error if the queue is full.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankQueue;
+    /// 
+    /// let queue = BankQueue::<i32, 1>::new();
+    /// assert!(queue.try_push(1).is_ok());
+    /// assert_eq!(queue.try_push(2).unwrap_err().element(), 2);
+    /// ```
+    pub fn try_push(&self, value: T) -> Result<(), BankFullError<T>> {
+        if C == 0 { return Err(BankFullError::new(value)) }
+
+        let mut tail = self.tail.load(Ordering::Relaxed);
+        loop {
+            let slot = &self.slots[tail & (Self::ONE_LAP - 1)];
+            let stamp = slot.stamp.load(Ordering::Acquire);
+
+            if stamp == tail {
+                // The slot is free for this position, try to claim it
+                match self.tail.compare_exchange_weak(tail, Self::advance(tail), Ordering::SeqCst, Ordering::Relaxed) {
+                    Ok(_) => {
+                        unsafe { (*slot.value.get()).write(value) };
+                        slot.stamp.store(tail + 1, Ordering::Release);
+                        return Ok(());
+                    }
+                    Err(current) => tail = current,
+                }
+            } else if stamp.wrapping_add(Self::ONE_LAP) == tail + 1 {
+                // The slot still holds the element from a lap ago, so the
+                // queue is full unless a pop is under way
+                atomic::fence(Ordering::SeqCst);
+                let head = self.head.load(Ordering::Relaxed);
+                if head.wrapping_add(Self::ONE_LAP) == tail { return Err(BankFullError::new(value)) }
+                tail = self.tail.load(Ordering::Relaxed);
+            } else {
+                // Another producer claimed the position first
+                tail = self.tail.load(Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Removes the element at the front of the queue and returns it, or `None`
+    /// if the queue is empty.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankQueue;
+    /// 
+    /// let queue = BankQueue::<i32, 4>::new();
+    /// queue.try_push(1).unwrap();
+    /// assert_eq!(queue.pop(), Some(1));
+    /// assert_eq!(queue.pop(), None);
+    /// ```
+    pub fn pop(&self) -> Option<T> {
+        if C == 0 { return None }
+
+        let mut head = self.head.load(Ordering::Relaxed);
+        loop {
+            let slot = &self.slots[head & (Self::ONE_LAP - 1)];
+            let stamp = slot.stamp.load(Ordering::Acquire);
+
+            if stamp == head + 1 {
+                // The slot holds this position's element, try to claim it
+                match self.head.compare_exchange_weak(head, Self::advance(head), Ordering::SeqCst, Ordering::Relaxed) {
+                    Ok(_) => {
+                        let value = unsafe { (*slot.value.get()).assume_init_read() };
+                        slot.stamp.store(head.wrapping_add(Self::ONE_LAP), Ordering::Release);
+                        return Some(value);
+                    }
+                    Err(current) => head = current,
+                }
+            } else if stamp == head {
+                // Nothing has been pushed to this position yet, so the queue
+                // is empty unless a push is under way
+                atomic::fence(Ordering::SeqCst);
+                let tail = self.tail.load(Ordering::Relaxed);
+                if tail == head { return None }
+                head = self.head.load(Ordering::Relaxed);
+            } else {
+                // Another consumer claimed the position first
+                head = self.head.load(Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use std::{rc::Rc, sync::atomic::AtomicBool, thread};
+    use super::*;
+
+    #[test]
+    fn wraps_laps() {
+        type Queue = BankQueue<usize, 3>;
+        let queue = Queue::new();
+
+        // Start on the last lap before the counters wrap
+        let lap = usize::MAX & !(Queue::ONE_LAP - 1);
+        queue.head.store(lap, Ordering::Relaxed);
+        queue.tail.store(lap, Ordering::Relaxed);
+        for (index, slot) in queue.slots.iter().enumerate() {
+            slot.stamp.store(lap + index, Ordering::Relaxed);
+        }
+
+        for round in 0..4 {
+            for i in 0..3 {
+                queue.try_push(round * 3 + i).unwrap();
+            }
+            assert!(queue.is_full());
+            assert!(queue.try_push(0).is_err());
+            for i in 0..3 {
+                assert_eq!(queue.pop(), Some(round * 3 + i));
+            }
+            assert_eq!(queue.pop(), None);
+        }
+    }
+
+    #[test]
+    fn push_pop() {
+        let queue = BankQueue::<String, 3>::new();
+        for round in 0..4 {
+            for i in 0..3 {
+                queue.try_push(format!("{round}{i}")).unwrap();
+            }
+            assert!(queue.is_full());
+            assert!(queue.try_push("x".to_string()).is_err());
+            for i in 0..3 {
+                assert_eq!(queue.pop().unwrap(), format!("{round}{i}"));
+            }
+            assert!(queue.is_empty());
+            assert_eq!(queue.pop(), None);
+        }
+    }
+
+    #[test]
+    fn single_slot() {
+        let queue = BankQueue::<i32, 1>::new();
+        for i in 0..3 {
+            queue.try_push(i).unwrap();
+            assert_eq!(queue.try_push(-1), Err(BankFullError::new(-1)));
+            assert_eq!(queue.pop(), Some(i));
+            assert_eq!(queue.pop(), None);
+        }
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let queue = BankQueue::<i32, 0>::new();
+        assert_eq!(queue.try_push(1), Err(BankFullError::new(1)));
+        assert_eq!(queue.pop(), None);
+    }
+
+    #[test]
+    fn drops_remaining() {
+        let rc = Rc::new(());
+        let queue = BankQueue::<Rc<()>, 4>::new();
+        for _ in 0..3 {
+            queue.try_push(rc.clone()).unwrap();
+        }
+        drop(queue.pop());
+        drop(queue);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn threaded() {
+        const PRODUCERS: usize = 4;
+        const PER_PRODUCER: usize = 2_000;
+
+        let queue = BankQueue::<usize, 8>::new();
+        let done = AtomicBool::new(false);
+        let mut seen = vec![false; PRODUCERS * PER_PRODUCER];
+
+        thread::scope(|s| {
+            let consumers = (0..3).map(|_| s.spawn(|| {
+                let mut received = vec![];
+                loop {
+                    match queue.pop() {
+                        Some(value) => received.push(value),
+                        None if done.load(Ordering::Acquire) && queue.is_empty() => break received,
+                        None => thread::yield_now(),
+                    }
+                }
+            })).collect::<Vec<_>>();
+
+            let producers = (0..PRODUCERS).map(|producer| {
+                let queue = &queue;
+                s.spawn(move || {
+                    for i in 0..PER_PRODUCER {
+                        let mut value = producer * PER_PRODUCER + i;
+                        while let Err(err) = queue.try_push(value) {
+                            value = err.element();
+                            thread::yield_now();
+                        }
+                    }
+                })
+            }).collect::<Vec<_>>();
+
+            producers.into_iter().for_each(|producer| producer.join().unwrap());
+            done.store(true, Ordering::Release);
+
+            for value in consumers.into_iter().flat_map(|consumer| consumer.join().unwrap()) {
+                assert!(!seen[value], "{value} popped twice");
+                seen[value] = true;
+            }
+        });
+        assert!(seen.into_iter().all(|seen| seen));
+    }
+}
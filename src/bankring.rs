@@ -0,0 +1,265 @@
+
+use std::{fmt, ops::Index};
+use crate::{BankDeque, bankdeque::{IntoIter, Iter, IterMut}};
+
+
+/// A fixed-size circular buffer which overwrites its oldest element once full.
+/// 
+/// Where a full [`BankDeque`] rejects further pushes, `BankRing` evicts its oldest
+/// element to make room, keeping the most recent `C` elements.  Useful for holding
+/// onto the last few samples of a stream.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::BankRing;
+/// 
+/// let mut ring = BankRing::<i32, 3>::new();
+/// for sample in 1..=4 {
+///     ring.push_overwrite(sample);
+/// }
+/// 
+/// assert_eq!(ring.iter().collect::<Vec<_>>(), [&2, &3, &4]);
+/// assert_eq!(ring.oldest(), Some(&2));
+/// assert_eq!(ring.newest(), Some(&4));
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct BankRing<T, const C: usize> {
+    deque: BankDeque<T, C>,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: fmt::Debug, const C: usize> fmt::Debug for BankRing<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, const C: usize, const N: usize> PartialEq<[T; N]> for BankRing<T, C> {
+    fn eq(&self, other: &[T; N]) -> bool { self.deque == *other }
+}
+
+impl<T, const C: usize> Default for BankRing<T, C> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+
+impl<T, const C: usize> Index<usize> for BankRing<T, C> {
+    type Output = T;
+
+    /// Indexes the ring from its oldest element.
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: usize) -> &Self::Output { &self.deque[index] }
+}
+
+impl<T, const C: usize> Extend<T> for BankRing<T, C> {
+
+    /// Pushes every item onto the ring, overwriting the oldest elements as needed.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        items.into_iter().for_each(|value| { self.push_overwrite(value); });
+    }
+}
+
+impl<T, const C: usize, const N: usize> From<[T; N]> for BankRing<T, C> {
+
+    /// Creates a ring from an array, oldest element first.  If `N` exceeds `C`
+    /// only the last `C` elements are kept.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankRing;
+    /// 
+    /// let ring = BankRing::<i32, 2>::from([1, 2, 3]);
+    /// assert_eq!(ring, [2, 3]);
+    /// ```
+    fn from(arr: [T; N]) -> Self {
+        let mut ring = Self::new();
+        ring.extend(arr);
+        ring
+    }
+}
+
+impl<T, const C: usize> IntoIterator for BankRing<T, C> {
+    type Item = T;
+    type IntoIter = IntoIter<T, C>;
+
+    /// Creates a consuming iterator, moving each element out of the ring from
+    /// oldest to newest.
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.deque.into_iter() }
+}
+
+impl<'a, T, const C: usize> IntoIterator for &'a BankRing<T, C> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<T, const C: usize> BankRing<T, C> {
+
+    /// Constructs a new, empty `BankRing<T, C>`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankRing;
+    /// 
+    /// let ring = BankRing::<f32, 16>::new();
+    /// assert!(ring.is_empty());
+    /// ```
+    pub const fn new() -> Self { Self { deque: BankDeque::new() } }
+
+    /// Returns the number of elements in the ring.
+    #[inline]
+    pub const fn len(&self) -> usize { self.deque.len() }
+
+    /// Returns `true` if the ring contains no elements.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.deque.is_empty() }
+
+    /// Returns `true` if the ring holds `C` elements, so the next push will
+    /// overwrite the oldest.
+    #[inline]
+    pub const fn is_full(&self) -> bool { self.deque.is_full() }
+
+    /// Returns the number of elements the ring can hold, `C`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { C }
+
+    /// Pushes an element onto the ring, returning the oldest element if it was
+    /// evicted to make room.
+    /// 
+    /// A ring with a capacity of zero hands the element straight back.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankRing;
+    /// 
+    /// let mut ring = BankRing::<i32, 2>::new();
+    /// assert_eq!(ring.push_overwrite(1), None);
+    /// assert_eq!(ring.push_overwrite(2), None);
+    /// assert_eq!(ring.push_overwrite(3), Some(1));
+    /// assert_eq!(ring, [2, 3]);
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(1) time.
+    #[inline]
+    pub fn push_overwrite(&mut self, value: T) -> Option<T> {
+        if C == 0 { return Some(value) }
+        let evicted = if self.deque.is_full() { self.deque.pop_front() } else { None };
+        unsafe { self.deque.push_back_unchecked(value) }
+        evicted
+    }
+
+    /// Removes the oldest element and returns it, or `None` if the ring is empty.
+    #[inline]
+    pub fn pop_oldest(&mut self) -> Option<T> { self.deque.pop_front() }
+
+    /// Removes the newest element and returns it, or `None` if the ring is empty.
+    #[inline]
+    pub fn pop_newest(&mut self) -> Option<T> { self.deque.pop_back() }
+
+    /// Returns a reference to the oldest element, or `None` if the ring is empty.
+    #[inline]
+    pub fn oldest(&self) -> Option<&T> { self.deque.front() }
+
+    /// Returns a reference to the newest element, or `None` if the ring is empty.
+    #[inline]
+    pub fn newest(&self) -> Option<&T> { self.deque.back() }
+
+    /// Returns a reference to the element at `index`, counting from the oldest,
+    /// or `None` if it's out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> { self.deque.get(index) }
+
+    /// Returns a mutable reference to the element at `index`, counting from the
+    /// oldest, or `None` if it's out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> { self.deque.get_mut(index) }
+
+    /// Returns an iterator over the ring from its oldest element to its newest.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankRing;
+    /// 
+    /// let ring = BankRing::<i32, 3>::from([1, 2, 3, 4, 5]);
+    /// assert_eq!(ring.iter().rev().collect::<Vec<_>>(), [&5, &4, &3]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> { self.deque.iter() }
+
+    /// Returns a mutable iterator over the ring from its oldest element to its newest.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> { self.deque.iter_mut() }
+
+    /// Returns the ring's contents as a pair of slices, which in order hold every
+    /// element from oldest to newest.
+    #[inline]
+    pub fn as_slices(&self) -> (&[T], &[T]) { self.deque.as_slices() }
+
+    /// Removes every element from the ring.
+    #[inline]
+    pub fn clear(&mut self) { self.deque.clear() }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn push_overwrite() {
+        let mut ring = BankRing::<String, 3>::new();
+        for i in 0..3 {
+            assert_eq!(ring.push_overwrite(i.to_string()), None);
+        }
+        assert!(ring.is_full());
+
+        assert_eq!(ring.push_overwrite("3".to_string()).unwrap(), "0");
+        assert_eq!(ring.push_overwrite("4".to_string()).unwrap(), "1");
+        assert_eq!(ring.iter().map(String::as_str).collect::<Vec<_>>(), ["2", "3", "4"]);
+        assert_eq!(ring[0], "2");
+        assert_eq!(ring.len(), 3);
+
+        assert_eq!(ring.pop_newest().unwrap(), "4");
+        assert_eq!(ring.pop_oldest().unwrap(), "2");
+        assert_eq!(ring.push_overwrite("5".to_string()), None);
+        assert_eq!(ring.into_iter().collect::<Vec<_>>(), ["3", "5"]);
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut ring = BankRing::<i32, 0>::new();
+        assert_eq!(ring.push_overwrite(1), Some(1));
+        assert!(ring.is_empty());
+        assert_eq!(ring.oldest(), None);
+    }
+
+    #[test]
+    fn extend() {
+        let mut ring = BankRing::<i32, 4>::from([1, 2]);
+        ring.extend(3..=9);
+        assert_eq!(ring, [6, 7, 8, 9]);
+        ring.iter_mut().for_each(|value| *value *= 2);
+        assert_eq!(ring.newest(), Some(&18));
+    }
+
+    #[test]
+    fn dropping_types() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        let mut ring = BankRing::<Rc<()>, 2>::new();
+        for _ in 0..5 {
+            drop(ring.push_overwrite(rc.clone()));
+        }
+        assert_eq!(Rc::strong_count(&rc), 3);
+        drop(ring);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
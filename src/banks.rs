@@ -0,0 +1,208 @@
+//! Adapters splitting a reader or an iterator into fixed-size banks, see
+//! [`Banks`] and [`IterBanks`].
+
+use std::{fmt, io::{self, Read}, iter::FusedIterator};
+use crate::BankArr;
+
+
+/// An iterator reading successive chunks of up to `C` bytes from a reader,
+/// each a [`BankArr<u8, C>`].
+/// 
+/// Every chunk but the last is full: short reads are retried until `C` bytes
+/// arrive or the reader reaches its end, and reads interrupted by
+/// [`io::ErrorKind::Interrupted`] are retried as well.  When a read fails part
+/// way through a chunk, the bytes read so far are yielded first and the error
+/// right after.  This lets a file or socket be processed in fixed batches
+/// without managing a buffer by hand.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::Banks;
+/// 
+/// let data = b"the quick brown fox".as_slice();
+/// let chunks: Vec<_> = Banks::<_, 8>::new(data).collect::<Result<_, _>>().unwrap();
+/// 
+/// assert_eq!(chunks.len(), 3);
+/// assert_eq!(chunks[0], *b"the quic");
+/// assert_eq!(chunks[2], *b"fox");
+/// ```
+pub struct Banks<R, const C: usize> {
+    reader: R,
+    error: Option<io::Error>,
+    done: bool,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<R: fmt::Debug, const C: usize> fmt::Debug for Banks<R, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Banks")
+            .field("reader", &self.reader)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<R: Read, const C: usize> Banks<R, C> {
+
+    /// Constructs an iterator over chunks of `reader`.
+    #[inline]
+    pub const fn new(reader: R) -> Self { Self { reader, error: None, done: false } }
+}
+
+impl<R, const C: usize> Banks<R, C> {
+
+    /// Returns a reference to the underlying reader.
+    #[inline]
+    pub const fn get_ref(&self) -> &R { &self.reader }
+
+    /// Returns a mutable reference to the underlying reader.
+    #[inline]
+    pub const fn get_mut(&mut self) -> &mut R { &mut self.reader }
+
+    /// Consumes the iterator, returning the underlying reader.
+    #[inline]
+    pub fn into_inner(self) -> R { self.reader }
+}
+
+impl<R: Read, const C: usize> Iterator for Banks<R, C> {
+    type Item = io::Result<BankArr<u8, C>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(error) = self.error.take() { return Some(Err(error)) }
+        if self.done || C == 0 { return None }
+
+        let mut chunk = BankArr::from([0; C]);
+        let mut filled = 0;
+        while filled < C {
+            match self.reader.read(&mut chunk.as_mut_slice()[filled..]) {
+                Ok(0) => {
+                    self.done = true;
+                    break
+                }
+                Ok(n) => filled += n,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) if filled == 0 => return Some(Err(error)),
+                Err(error) => {
+                    self.error = Some(error);
+                    break
+                }
+            }
+        }
+        chunk.truncate(filled);
+        (filled > 0).then_some(Ok(chunk))
+    }
+}
+
+impl<R: Read, const C: usize> FusedIterator for Banks<R, C> {}
+
+
+/// An iterator gathering the items of another iterator into
+/// [`BankArr<T, C>`]s of up to `C` items, every one but the last full.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::IterBanks;
+/// 
+/// let batches: Vec<_> = IterBanks::<_, 4>::new(1..=10).collect();
+/// assert_eq!(batches.len(), 3);
+/// assert_eq!(batches[1], [5, 6, 7, 8]);
+/// assert_eq!(batches[2], [9, 10]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IterBanks<I, const C: usize> {
+    iter: I,
+}
+
+impl<I: Iterator, const C: usize> IterBanks<I, C> {
+
+    /// Constructs an iterator over chunks of `iter`'s items.
+    #[inline]
+    pub fn new<T: IntoIterator<IntoIter = I>>(iter: T) -> Self { Self { iter: iter.into_iter() } }
+
+    /// Consumes the adapter, returning the underlying iterator.
+    #[inline]
+    pub fn into_inner(self) -> I { self.iter }
+}
+
+impl<I: Iterator, const C: usize> Iterator for IterBanks<I, C> {
+    type Item = BankArr<I::Item, C>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = BankArr::new();
+        chunk.extend(self.iter.by_ref().take(C));
+        (!chunk.is_empty()).then_some(chunk)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match C {
+            0 => (0, Some(0)),
+            _ => {
+                let (lower, upper) = self.iter.size_hint();
+                (lower.div_ceil(C), upper.map(|upper| upper.div_ceil(C)))
+            }
+        }
+    }
+}
+
+impl<I: FusedIterator, const C: usize> FusedIterator for IterBanks<I, C> {}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    // Hands out at most 3 bytes per read, failing once with the given kind
+    struct Trickle<'a> {
+        data: &'a [u8],
+        fail_at: usize,
+        kind: io::ErrorKind,
+    }
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.fail_at == 0 {
+                self.fail_at = usize::MAX;
+                return Err(self.kind.into());
+            }
+            self.fail_at -= 1;
+            let n = buf.len().min(self.data.len()).min(3);
+            buf[..n].copy_from_slice(&self.data[..n]);
+            self.data = &self.data[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn short_reads() {
+        let reader = Trickle { data: b"abcdefghij", fail_at: 2, kind: io::ErrorKind::Interrupted };
+        let chunks: Vec<_> = Banks::<_, 4>::new(reader).map(Result::unwrap).collect();
+        assert_eq!(chunks.iter().map(BankArr::as_slice).collect::<Vec<_>>(), [&b"abcd"[..], b"efgh", b"ij"]);
+    }
+
+    #[test]
+    fn errors() {
+        let reader = Trickle { data: b"abcdefghij", fail_at: 2, kind: io::ErrorKind::BrokenPipe };
+        let mut banks = Banks::<_, 8>::new(reader);
+        assert_eq!(banks.next().unwrap().unwrap(), *b"abcdef");
+        assert_eq!(banks.next().unwrap().unwrap_err().kind(), io::ErrorKind::BrokenPipe);
+        assert_eq!(banks.next().unwrap().unwrap(), *b"ghij");
+        assert!(banks.next().is_none());
+        assert!(banks.next().is_none());
+        assert!(banks.into_inner().data.is_empty());
+
+        assert!(Banks::<_, 0>::new(&b"abc"[..]).next().is_none());
+    }
+
+    #[test]
+    fn iter_banks() {
+        let mut banks = IterBanks::<_, 3>::new(vec!["a", "b", "c", "d"]);
+        assert_eq!(banks.size_hint(), (2, Some(2)));
+        assert_eq!(banks.next().unwrap(), ["a", "b", "c"]);
+        assert_eq!(banks.next().unwrap(), ["d"]);
+        assert!(banks.next().is_none());
+
+        assert_eq!(IterBanks::<_, 0>::new(0..5).next(), None);
+    }
+}
@@ -0,0 +1,379 @@
+//! A bounded single-producer, single-consumer queue, see [`BankSpsc`].
+
+use std::{cell::UnsafeCell, fmt, mem::MaybeUninit, ops::Deref, sync::atomic::{AtomicUsize, Ordering}};
+use crate::errors::BankFullError;
+
+
+// Aligns a value to its own cache line, so the producer and consumer don't
+// invalidate each other's line on every update.
+#[derive(Debug, Default)]
+#[repr(align(128))]
+pub(crate) struct CachePadded<T>(pub(crate) T);
+
+impl<T> Deref for CachePadded<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T { &self.0 }
+}
+
+
+/// A fixed-size queue passing up to `C` elements from one thread to another,
+/// without locks or allocation.
+/// 
+/// The queue is [`split`](BankSpsc::split) into a [`Producer`] and a
+/// [`Consumer`], which can be sent to different threads.  Each side only
+/// touches its own index, and the other's with a single atomic load, so both
+/// are wait-free and suitable for realtime threads like audio callbacks.
+/// 
+/// # Examples
+/// ```
+/// use std::thread;
+/// use bankarr::BankSpsc;
+/// 
+/// let mut queue = BankSpsc::<u32, 8>::new();
+/// let (mut producer, mut consumer) = queue.split();
+/// 
+/// thread::scope(|s| {
+///     s.spawn(move || {
+///         for sample in 0..100 {
+///             while producer.try_push(sample).is_err() {
+///                 thread::yield_now();
+///             }
+///         }
+///     });
+/// 
+///     let mut received = 0;
+///     while received < 100 {
+///         if let Some(sample) = consumer.pop() {
+///             assert_eq!(sample, received);
+///             received += 1;
+///         }
+///     }
+/// });
+/// ```
+pub struct BankSpsc<T, const C: usize> {
+    // Both indices count through `0..2 * C`, wrapping explicitly so any `C`
+    // works, with index `i` addressing slot `i % C`.  Counting two laps rather
+    // than one tells a full queue (`C` apart) from an empty one (equal).
+    head: CachePadded<AtomicUsize>,
+    tail: CachePadded<AtomicUsize>,
+    slots: [UnsafeCell<MaybeUninit<T>>; C],
+}
+
+unsafe impl<T: Send, const C: usize> Send for BankSpsc<T, C> {}
+unsafe impl<T: Send, const C: usize> Sync for BankSpsc<T, C> {}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, const C: usize> fmt::Debug for BankSpsc<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BankSpsc").field("len", &self.len()).field("capacity", &C).finish()
+    }
+}
+
+impl<T, const C: usize> Default for BankSpsc<T, C> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+
+impl<T, const C: usize> Drop for BankSpsc<T, C> {
+    fn drop(&mut self) {
+        let (_, mut consumer) = self.split();
+        while consumer.pop().is_some() {}
+    }
+}
+
+impl<T, const C: usize> BankSpsc<T, C> {
+
+    /// Constructs a new, empty `BankSpsc<T, C>`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankSpsc;
+    /// 
+    /// let queue = BankSpsc::<f32, 256>::new();
+    /// assert!(queue.is_empty());
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            head: CachePadded(AtomicUsize::new(0)),
+            tail: CachePadded(AtomicUsize::new(0)),
+            slots: [const { UnsafeCell::new(MaybeUninit::uninit()) }; C],
+        }
+    }
+
+    /// Returns the number of elements in the queue.  While the queue is split
+    /// the count may be stale by the time it's read.
+    #[inline]
+    pub fn len(&self) -> usize {
+        let head = self.head.load(Ordering::Acquire);
+        Self::distance(head, self.tail.load(Ordering::Acquire))
+    }
+
+    /// Returns `true` if the queue contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Returns the number of elements the queue can hold, `C`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { C }
+
+    /// Splits the queue into its producing and consuming halves.  The borrow
+    /// ensures there's only ever one of each.
+    #[inline]
+    pub fn split(&mut self) -> (Producer<'_, T, C>, Consumer<'_, T, C>) {
+        (Producer { queue: self }, Consumer { queue: self })
+    }
+
+    #[inline]
+    fn slot(&self, index: usize) -> *mut MaybeUninit<T> {
+        let index = if index < C { index } else { index - C };
+        self.slots[index].get()
+    }
+
+    // The index after `index`, wrapping at `2 * C`
+    #[inline]
+    const fn next(index: usize) -> usize {
+        if index + 1 == 2 * C { 0 } else { index + 1 }
+    }
+
+    // The number of elements from `head` up to `tail`
+    #[inline]
+    const fn distance(head: usize, tail: usize) -> usize {
+        if tail >= head { tail - head } else { tail + 2 * C - head }
+    }
+}
+
+
+/// The producing half of a [`BankSpsc`].
+/// 
+/// Created by [`BankSpsc::split`].
+pub struct Producer<'a, T, const C: usize> {
+    queue: &'a BankSpsc<T, C>,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, const C: usize> fmt::Debug for Producer<'_, T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Producer").field("queue", self.queue).finish()
+    }
+}
+
+impl<T, const C: usize> Producer<'_, T, C> {
+
+    /// Attempts to push an element onto the back of the queue, handing it back
+    /// inside the error if the queue is full.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankSpsc;
+    /// 
+    /// let mut queue = BankSpsc::<i32, 1>::new();
+    /// let (mut producer, _) = queue.split();
+    /// 
+    /// assert!(producer.try_push(1).is_ok());
+    /// assert_eq!(producer.try_push(2).unwrap_err().element(), 2);
+    /// ```
+    pub fn try_push(&mut self, value: T) -> Result<(), BankFullError<T>> {
+        let tail = self.queue.tail.load(Ordering::Relaxed);
+        let head = self.queue.head.load(Ordering::Acquire);
+        if BankSpsc::<T, C>::distance(head, tail) == C { return Err(BankFullError::new(value)) }
+
+        // The consumer won't read the slot until the new tail is published
+        unsafe { (*self.queue.slot(tail)).write(value) };
+        self.queue.tail.store(BankSpsc::<T, C>::next(tail), Ordering::Release);
+        Ok(())
+    }
+
+    /// Returns the number of elements in the queue, which may shrink at any
+    /// time as the consumer pops.
+    #[inline]
+    pub fn len(&self) -> usize { self.queue.len() }
+
+    /// Returns `true` if the queue is empty, which can change at any time as
+    /// the consumer pops.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.queue.is_empty() }
+
+    /// Returns `true` if the queue is full, which can change at any time as the
+    /// consumer pops.
+    #[inline]
+    pub fn is_full(&self) -> bool { self.queue.len() == C }
+
+    /// Returns the number of elements the queue can hold, `C`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { C }
+}
+
+
+/// The consuming half of a [`BankSpsc`].
+/// 
+/// Created by [`BankSpsc::split`].
+pub struct Consumer<'a, T, const C: usize> {
+    queue: &'a BankSpsc<T, C>,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, const C: usize> fmt::Debug for Consumer<'_, T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Consumer").field("queue", self.queue).finish()
+    }
+}
+
+impl<T, const C: usize> Consumer<'_, T, C> {
+
+    /// Removes the element at the front of the queue and returns it, or `None`
+    /// if the queue is empty.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankSpsc;
+    /// 
+    /// let mut queue = BankSpsc::<i32, 4>::new();
+    /// let (mut producer, mut consumer) = queue.split();
+    /// 
+    /// producer.try_push(1).unwrap();
+    /// assert_eq!(consumer.pop(), Some(1));
+    /// assert_eq!(consumer.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        let head = self.queue.head.load(Ordering::Relaxed);
+        let tail = self.queue.tail.load(Ordering::Acquire);
+        if head == tail { return None }
+
+        // The producer won't reuse the slot until the new head is published
+        let value = unsafe { (*self.queue.slot(head)).assume_init_read() };
+        self.queue.head.store(BankSpsc::<T, C>::next(head), Ordering::Release);
+        Some(value)
+    }
+
+    /// Returns a reference to the element at the front of the queue, or `None`
+    /// if the queue is empty.
+    pub fn peek(&mut self) -> Option<&T> {
+        let head = self.queue.head.load(Ordering::Relaxed);
+        let tail = self.queue.tail.load(Ordering::Acquire);
+        if head == tail { return None }
+
+        // Only this consumer can pop the element, and it's borrowed until the
+        // reference is dropped
+        Some(unsafe { (*self.queue.slot(head)).assume_init_ref() })
+    }
+
+    /// Returns the number of elements in the queue, which may grow at any time
+    /// as the producer pushes.
+    #[inline]
+    pub fn len(&self) -> usize { self.queue.len() }
+
+    /// Returns `true` if the queue is empty, which can change at any time as
+    /// the producer pushes.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.queue.is_empty() }
+
+    /// Returns the number of elements the queue can hold, `C`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { C }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use std::{rc::Rc, sync::Arc, thread};
+    use super::*;
+
+    #[test]
+    fn push_pop() {
+        let mut queue = BankSpsc::<String, 2>::new();
+        let (mut producer, mut consumer) = queue.split();
+
+        // Wrap around the slots a few times
+        for round in 0..5 {
+            producer.try_push(format!("{round}a")).unwrap();
+            producer.try_push(format!("{round}b")).unwrap();
+            assert!(producer.is_full());
+            assert!(producer.try_push("c".to_string()).is_err());
+
+            assert_eq!(consumer.peek().unwrap(), &format!("{round}a"));
+            assert_eq!(consumer.pop().unwrap(), format!("{round}a"));
+            assert_eq!(consumer.pop().unwrap(), format!("{round}b"));
+            assert!(consumer.is_empty());
+        }
+        assert_eq!(consumer.pop(), None);
+    }
+
+    #[test]
+    fn wraps_odd_capacity() {
+        let mut queue = BankSpsc::<usize, 3>::new();
+        let (mut producer, mut consumer) = queue.split();
+
+        // Many laps of the indices, at every fill level
+        let (mut pushed, mut popped) = (0, 0);
+        for round in 0..50 {
+            for _ in 0..round % 4 {
+                if producer.try_push(pushed).is_ok() { pushed += 1 }
+            }
+            assert_eq!(consumer.len(), pushed - popped);
+            for _ in 0..round % 3 {
+                if let Some(value) = consumer.pop() {
+                    assert_eq!(value, popped);
+                    popped += 1;
+                }
+            }
+        }
+        assert!(pushed > 20);
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut queue = BankSpsc::<i32, 0>::new();
+        let (mut producer, mut consumer) = queue.split();
+        assert_eq!(producer.try_push(1), Err(BankFullError::new(1)));
+        assert_eq!(consumer.pop(), None);
+    }
+
+    #[test]
+    fn drops_remaining() {
+        let rc = Rc::new(());
+        let mut queue = BankSpsc::<Rc<()>, 4>::new();
+        let (mut producer, mut consumer) = queue.split();
+        for _ in 0..3 {
+            producer.try_push(rc.clone()).unwrap();
+        }
+        drop(consumer.pop());
+        assert_eq!(Rc::strong_count(&rc), 3);
+        drop(queue);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+
+    #[test]
+    fn threaded() {
+        const COUNT: usize = 10_000;
+
+        let mut queue = BankSpsc::<Arc<usize>, 16>::new();
+        let (mut producer, mut consumer) = queue.split();
+
+        thread::scope(|s| {
+            s.spawn(move || {
+                for i in 0..COUNT {
+                    let mut value = Arc::new(i);
+                    while let Err(err) = producer.try_push(value) {
+                        value = err.element();
+                        thread::yield_now();
+                    }
+                }
+            });
+
+            let mut expected = 0;
+            while expected < COUNT {
+                match consumer.pop() {
+                    Some(value) => {
+                        assert_eq!(*value, expected);
+                        expected += 1;
+                    }
+                    None => thread::yield_now(),
+                }
+            }
+        });
+        assert!(queue.is_empty());
+    }
+}
@@ -0,0 +1,296 @@
+
+use std::{fmt, str};
+use crate::{BankVec, bankstring::impl_str_traits};
+
+
+/// A UTF-8 string which keeps up to `C` bytes inline, moving onto the heap once
+/// it grows past `C`.
+/// 
+/// `BankStr` is to [`String`] what [`BankVec`] is to [`Vec`], and to
+/// [`BankString`](crate::BankString) what `BankVec` is to
+/// [`BankArr`](crate::BankArr): short strings never allocate, and longer ones
+/// behave just as a `String` would.
+/// 
+/// # Examples
+/// ```
+/// use std::fmt::Write;
+/// use bankarr::BankStr;
+/// 
+/// let mut s = BankStr::<8>::from("bank");
+/// s.push_str("arr");
+/// assert!(!s.on_heap());
+/// 
+/// write!(s, " {}", 123).unwrap();
+/// assert!(s.on_heap());
+/// assert_eq!(s, "bankarr 123");
+/// ```
+#[derive(Clone, Default)]
+pub struct BankStr<const C: usize> {
+    bytes: BankVec<u8, C>,
+}
+
+impl_str_traits!(BankStr);
+
+impl<const C: usize> From<&str> for BankStr<C> {
+
+    /// Copies a string slice into a new `BankStr`, which is on the heap only if
+    /// the slice is longer than `C` bytes.
+    #[inline]
+    fn from(s: &str) -> Self {
+        let mut string = Self::new();
+        string.push_str(s);
+        string
+    }
+}
+
+impl<const C: usize> From<String> for BankStr<C> {
+
+    /// Converts a `String` into a `BankStr`, moving it inline if it fits within
+    /// `C` bytes and otherwise keeping its allocation.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankStr;
+    /// 
+    /// assert!(!BankStr::<4>::from("abc".to_string()).on_heap());
+    /// assert!(BankStr::<2>::from("abc".to_string()).on_heap());
+    /// ```
+    #[inline]
+    fn from(s: String) -> Self { Self { bytes: BankVec::from(s.into_bytes()) } }
+}
+
+impl<const C: usize> From<BankStr<C>> for String {
+
+    /// Converts a `BankStr` into a `String`, reusing its heap allocation if it
+    /// has one.
+    #[inline]
+    fn from(s: BankStr<C>) -> Self {
+        unsafe { String::from_utf8_unchecked(Vec::from(s.bytes)) }
+    }
+}
+
+impl<const C: usize> fmt::Write for BankStr<C> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+}
+
+impl<const C: usize> Extend<char> for BankStr<C> {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, chars: I) {
+        let chars = chars.into_iter();
+        self.reserve(chars.size_hint().0);
+        chars.for_each(|c| self.push(c));
+    }
+}
+
+impl<'a, const C: usize> Extend<&'a str> for BankStr<C> {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, strs: I) {
+        strs.into_iter().for_each(|s| self.push_str(s));
+    }
+}
+
+impl<const C: usize> FromIterator<char> for BankStr<C> {
+    fn from_iter<I: IntoIterator<Item = char>>(chars: I) -> Self {
+        let mut string = Self::new();
+        string.extend(chars);
+        string
+    }
+}
+
+impl<const C: usize> BankStr<C> {
+
+    /// Constructs a new, empty `BankStr<C>`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankStr;
+    /// 
+    /// let s = BankStr::<16>::new();
+    /// assert!(s.is_empty());
+    /// ```
+    pub const fn new() -> Self { Self { bytes: BankVec::new() } }
+
+    /// Constructs a new, empty `BankStr<C>` with room for at least `capacity`
+    /// bytes.  Allocates on the heap only if `capacity` exceeds `C`.
+    #[track_caller]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut string = Self::new();
+        string.reserve(capacity);
+        string
+    }
+
+    /// Returns the length of the string in bytes.
+    #[inline]
+    pub const fn len(&self) -> usize { self.bytes.len() }
+
+    /// Returns `true` if the string is empty.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.bytes.is_empty() }
+
+    /// Returns the number of bytes the string can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize { self.bytes.capacity() }
+
+    /// Returns `true` if the string has moved onto the heap.
+    #[inline]
+    pub const fn on_heap(&self) -> bool { self.bytes.on_heap() }
+
+    /// Reserves capacity for at least `additional` more bytes, moving the string
+    /// onto the heap if they won't fit within `C`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    #[inline]
+    #[track_caller]
+    pub fn reserve(&mut self, additional: usize) { self.bytes.reserve(additional) }
+
+    /// Shrinks the string's heap allocation to fit its length, moving it back
+    /// inline if it fits within `C` bytes.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankStr;
+    /// 
+    /// let mut s = BankStr::<4>::from("bankarr");
+    /// s.truncate(4);
+    /// s.shrink_to_fit();
+    /// assert!(!s.on_heap());
+    /// assert_eq!(s, "bank");
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn shrink_to_fit(&mut self) { self.bytes.shrink_to_fit() }
+
+    /// Extracts a string slice containing the entire string.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(self.bytes.as_slice()) }
+    }
+
+    /// Extracts a mutable string slice containing the entire string.
+    #[inline]
+    pub fn as_mut_str(&mut self) -> &mut str {
+        unsafe { str::from_utf8_unchecked_mut(self.bytes.as_mut_slice()) }
+    }
+
+    /// Returns the string's UTF-8 bytes.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] { self.bytes.as_slice() }
+
+    /// Appends a string slice onto the end of the string, moving onto the heap
+    /// if it no longer fits inline.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankStr;
+    /// 
+    /// let mut s = BankStr::<4>::new();
+    /// s.push_str("bank");
+    /// assert!(!s.on_heap());
+    /// s.push_str("arr");
+    /// assert_eq!(s, "bankarr");
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn push_str(&mut self, s: &str) { self.bytes.extend_from_slice(s.as_bytes()) }
+
+    /// Appends a character onto the end of the string.
+    #[inline]
+    #[track_caller]
+    pub fn push(&mut self, c: char) { self.push_str(c.encode_utf8(&mut [0; 4])) }
+
+    /// Removes the last character from the string and returns it, or `None` if
+    /// the string is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next_back()?;
+        self.bytes.truncate(self.len() - c.len_utf8());
+        Some(c)
+    }
+
+    /// Shortens the string to `len` bytes, leaving its capacity unchanged.  Has
+    /// no effect if `len` is greater than the string's current length.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `len` doesn't lie on a [`char`] boundary.
+    #[inline]
+    #[track_caller]
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            assert!(self.is_char_boundary(len), "new length does not lie on a char boundary");
+            self.bytes.truncate(len);
+        }
+    }
+
+    /// Empties the string, leaving its capacity unchanged.
+    #[inline]
+    pub fn clear(&mut self) { self.bytes.clear() }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use std::fmt::Write;
+    use super::*;
+
+    #[test]
+    fn spill() {
+        let mut s = BankStr::<4>::new();
+        s.push_str("ab");
+        s.push('é');
+        assert!(!s.on_heap());
+        assert_eq!(s, "abé");
+
+        s.push('!');
+        assert!(s.on_heap());
+        assert_eq!(s.len(), 5);
+        assert_eq!(s.pop(), Some('!'));
+        assert_eq!(s.pop(), Some('é'));
+
+        s.shrink_to_fit();
+        assert!(!s.on_heap());
+        assert_eq!(s, "ab");
+    }
+
+    #[test]
+    fn conversions() {
+        let s = BankStr::<4>::from("long string".to_string());
+        assert!(s.on_heap());
+        assert_eq!(String::from(s.clone()), "long string");
+        assert_eq!(String::from(BankStr::<4>::from("ab")), "ab");
+
+        let s: BankStr<8> = "héllo".chars().rev().collect();
+        assert_eq!(s, "olléh");
+
+        let mut s = BankStr::<2>::new();
+        s.extend(["a", "b", "c"]);
+        write!(s, "{}", 1).unwrap();
+        assert_eq!(s.as_str(), "abc1");
+        s.make_ascii_uppercase();
+        assert_eq!("ABC1", s);
+    }
+
+    #[test]
+    #[should_panic(expected = "new length does not lie on a char boundary")]
+    fn truncate_char_boundary() {
+        let mut s = BankStr::<8>::from("é");
+        s.truncate(1);
+    }
+
+    #[test]
+    fn ord_hash() {
+        use std::collections::HashSet;
+
+        let a = BankStr::<2>::from("abc");
+        let b = BankStr::<2>::from("b");
+        assert!(a < b);
+
+        let set: HashSet<_> = [a, b].into_iter().collect();
+        assert!(set.contains("abc"));
+    }
+}
@@ -0,0 +1,494 @@
+
+use std::{fmt, str};
+use crate::{BankArr, errors::BankFullError};
+
+#[cfg(feature = "serde")]
+mod serialize;
+
+
+/// A fixed-capacity UTF-8 string stored inline.
+/// 
+/// `BankString` is to [`String`] what [`BankArr`] is to [`Vec`]: it holds up to `C`
+/// bytes of UTF-8 without ever allocating.  It implements [`fmt::Write`], so
+/// values can be formatted straight into it with [`write!`].
+/// 
+/// # Examples
+/// ```
+/// use std::fmt::Write;
+/// use bankarr::BankString;
+/// 
+/// let (host, port) = ("localhost", 8080);
+/// let mut addr = BankString::<16>::new();
+/// write!(addr, "{host}:{port}").unwrap();
+/// 
+/// assert_eq!(addr.as_str(), "localhost:8080");
+/// assert_eq!(addr.remaining_capacity(), 2);
+/// ```
+#[derive(Clone, Default)]
+pub struct BankString<const C: usize> {
+    bytes: BankArr<u8, C>,
+}
+
+// The string traits shared with `BankStr`, implemented in terms of `as_str` and
+// `as_mut_str`.
+macro_rules! impl_str_traits {
+    ($name:ident) => {
+        #[cfg(not(tarpaulin_include))]
+        impl<const C: usize> std::fmt::Debug for $name<C> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::fmt::Debug::fmt(self.as_str(), f)
+            }
+        }
+
+        #[cfg(not(tarpaulin_include))]
+        impl<const C: usize> std::fmt::Display for $name<C> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::fmt::Display::fmt(self.as_str(), f)
+            }
+        }
+
+        impl<const C: usize> std::ops::Deref for $name<C> {
+            type Target = str;
+            #[inline]
+            fn deref(&self) -> &Self::Target { self.as_str() }
+        }
+
+        impl<const C: usize> std::ops::DerefMut for $name<C> {
+            #[inline]
+            fn deref_mut(&mut self) -> &mut Self::Target { self.as_mut_str() }
+        }
+
+        impl<const C: usize> AsRef<str> for $name<C> {
+            #[inline]
+            fn as_ref(&self) -> &str { self.as_str() }
+        }
+
+        impl<const C: usize> AsRef<[u8]> for $name<C> {
+            #[inline]
+            fn as_ref(&self) -> &[u8] { self.as_bytes() }
+        }
+
+        impl<const C: usize> std::borrow::Borrow<str> for $name<C> {
+            #[inline]
+            fn borrow(&self) -> &str { self.as_str() }
+        }
+
+        impl<const C: usize> PartialEq for $name<C> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool { self.as_str() == other.as_str() }
+        }
+
+        impl<const C: usize> Eq for $name<C> {}
+
+        $crate::bankstring::impl_str_traits!(@eq $name, str, &str, String);
+
+        impl<const C: usize> PartialOrd for $name<C> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> { Some(self.cmp(other)) }
+        }
+
+        impl<const C: usize> Ord for $name<C> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering { self.as_str().cmp(other.as_str()) }
+        }
+
+        // Hashes as a `str` would, which `Borrow<str>` relies on for map lookups.
+        impl<const C: usize> std::hash::Hash for $name<C> {
+            #[inline]
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) { std::hash::Hash::hash(self.as_str(), state) }
+        }
+    };
+    (@eq $name:ident, $($rhs:ty),*) => {$(
+        impl<const C: usize> PartialEq<$rhs> for $name<C> {
+            #[inline]
+            fn eq(&self, other: &$rhs) -> bool { self.as_str() == &other[..] }
+        }
+
+        impl<const C: usize> PartialEq<$name<C>> for $rhs {
+            #[inline]
+            fn eq(&self, other: &$name<C>) -> bool { &self[..] == other.as_str() }
+        }
+    )*};
+}
+
+pub(crate) use impl_str_traits;
+
+impl_str_traits!(BankString);
+
+/// Formats its arguments into a [`BankString`] of capacity `C`, as [`format!`]
+/// would into a `String`, without allocating.
+/// 
+/// # Panics
+/// 
+/// Panics if the output doesn't fit in `C` bytes.  Use
+/// [`BankString::try_write_fmt`] to handle that instead.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::format_bank;
+/// 
+/// let (level, id) = ("warn", 42);
+/// let prefix = format_bank!(16, "[{level}] #{id:04}");
+/// assert_eq!(prefix.as_str(), "[warn] #0042");
+/// assert_eq!(prefix.capacity(), 16);
+/// ```
+#[macro_export]
+macro_rules! format_bank {
+    ($cap:expr, $($arg:tt)*) => {
+        $crate::BankString::<{ $cap }>::from_fmt(::core::format_args!($($arg)*))
+    };
+}
+
+impl<'s, const C: usize> TryFrom<&'s str> for BankString<C> {
+    type Error = BankFullError<&'s str>;
+
+    /// Copies a string slice into a new `BankString`, failing if it's longer
+    /// than `C` bytes.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankString;
+    /// 
+    /// let s = BankString::<8>::try_from("bankarr").unwrap();
+    /// assert_eq!(s, "bankarr");
+    /// 
+    /// assert!(BankString::<4>::try_from("bankarr").is_err());
+    /// ```
+    #[inline]
+    fn try_from(s: &'s str) -> Result<Self, Self::Error> {
+        let mut string = Self::new();
+        string.try_push_str(s)?;
+        Ok(string)
+    }
+}
+
+impl<const C: usize> From<BankString<C>> for String {
+    #[inline]
+    fn from(s: BankString<C>) -> Self { s.as_str().to_owned() }
+}
+
+impl<const C: usize> fmt::Write for BankString<C> {
+
+    /// Appends `s` if it fits, otherwise leaves the string untouched and returns
+    /// [`fmt::Error`].
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.try_push_str(s).map_err(|_| fmt::Error)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.try_push(c).map_err(|_| fmt::Error)
+    }
+}
+
+impl<const C: usize> BankString<C> {
+
+    /// Constructs a new, empty `BankString<C>`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankString;
+    /// 
+    /// let s = BankString::<8>::new();
+    /// assert!(s.is_empty());
+    /// ```
+    pub const fn new() -> Self { Self { bytes: BankArr::new() } }
+
+    /// Constructs a string from formatted `args`, as the [`format_bank!`]
+    /// macro does.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the output doesn't fit in `C` bytes.
+    #[inline]
+    #[track_caller]
+    pub fn from_fmt(args: fmt::Arguments<'_>) -> Self {
+        let mut s = Self::new();
+        if s.try_write_fmt(args).is_err() {
+            panic!("capacity exceeded during operation `from_fmt`: capacity is {C}");
+        }
+        s
+    }
+
+    /// Returns the length of the string in bytes.
+    #[inline]
+    pub const fn len(&self) -> usize { self.bytes.len() }
+
+    /// Returns `true` if the string is empty.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.bytes.is_empty() }
+
+    /// Returns the string's capacity in bytes, `C`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { C }
+
+    /// Returns the number of bytes which can still be appended.
+    #[inline]
+    pub const fn remaining_capacity(&self) -> usize { self.bytes.remaining_capacity() }
+
+    /// Extracts a string slice containing the entire string.
+    #[inline]
+    pub const fn as_str(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(self.bytes.as_slice()) }
+    }
+
+    /// Extracts a mutable string slice containing the entire string.
+    #[inline]
+    pub const fn as_mut_str(&mut self) -> &mut str {
+        unsafe { str::from_utf8_unchecked_mut(self.bytes.as_mut_slice()) }
+    }
+
+    /// Returns the string's UTF-8 bytes.
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8] { self.bytes.as_slice() }
+
+    /// Appends a string slice onto the end of the string.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `s` doesn't fit in the remaining capacity.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankString;
+    /// 
+    /// let mut s = BankString::<8>::new();
+    /// s.push_str("bank");
+    /// s.push_str("arr");
+    /// assert_eq!(s.as_str(), "bankarr");
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn push_str(&mut self, s: &str) {
+        assert!(
+            s.len() <= self.remaining_capacity(),
+            "capacity exceeded during operation `push_str`: capacity is {C}"
+        );
+        self.bytes.extend_from_slice(s.as_bytes());
+    }
+
+    /// Attempts to append a string slice, handing it back inside the error if it
+    /// doesn't fit.  Nothing is appended on failure.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankString;
+    /// 
+    /// let mut s = BankString::<4>::new();
+    /// assert!(s.try_push_str("abc").is_ok());
+    /// assert_eq!(s.try_push_str("de").unwrap_err().element(), "de");
+    /// assert_eq!(s.as_str(), "abc");
+    /// ```
+    #[inline]
+    pub fn try_push_str<'s>(&mut self, s: &'s str) -> Result<(), BankFullError<&'s str>> {
+        if s.len() > self.remaining_capacity() { return Err(BankFullError::new(s)) }
+        self.bytes.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+
+    /// Appends a character onto the end of the string.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the character's UTF-8 encoding doesn't fit in the remaining capacity.
+    #[inline]
+    #[track_caller]
+    pub fn push(&mut self, c: char) {
+        self.push_str(c.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Attempts to append a character, handing it back inside the error if it
+    /// doesn't fit.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankString;
+    /// 
+    /// let mut s = BankString::<2>::new();
+    /// assert!(s.try_push('a').is_ok());
+    /// assert_eq!(s.try_push('é').unwrap_err().element(), 'é');
+    /// ```
+    #[inline]
+    pub fn try_push(&mut self, c: char) -> Result<(), BankFullError<char>> {
+        self.try_push_str(c.encode_utf8(&mut [0; 4]))
+            .map_err(|_| BankFullError::new(c))
+    }
+
+    /// Removes the last character from the string and returns it, or `None` if
+    /// the string is empty.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankString;
+    /// 
+    /// let mut s = BankString::<8>::new();
+    /// s.push_str("hé");
+    /// assert_eq!(s.pop(), Some('é'));
+    /// assert_eq!(s.as_str(), "h");
+    /// ```
+    #[inline]
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next_back()?;
+        self.bytes.truncate(self.len() - c.len_utf8());
+        Some(c)
+    }
+
+    /// Shortens the string to `len` bytes.  Has no effect if `len` is greater
+    /// than the string's current length.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `len` doesn't lie on a [`char`] boundary.
+    #[inline]
+    #[track_caller]
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            assert!(self.is_char_boundary(len), "new length does not lie on a char boundary");
+            self.bytes.truncate(len);
+        }
+    }
+
+    /// Empties the string.
+    #[inline]
+    pub fn clear(&mut self) { self.bytes.truncate(0) }
+
+    /// Formats `args` onto the end of the string, as [`write!`] would, failing
+    /// if the output doesn't fit.
+    /// 
+    /// Unlike writing through [`fmt::Write`], a failed write appends nothing at
+    /// all rather than keeping the pieces which fit.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankString;
+    /// 
+    /// let mut s = BankString::<8>::new();
+    /// s.try_write_fmt(format_args!("{}-{}", 1, 2)).unwrap();
+    /// assert_eq!(s.as_str(), "1-2");
+    /// 
+    /// assert!(s.try_write_fmt(format_args!("{:>8}", 3)).is_err());
+    /// assert_eq!(s.as_str(), "1-2");
+    /// ```
+    pub fn try_write_fmt(&mut self, args: fmt::Arguments<'_>) -> Result<(), BankFullError> {
+        let len = self.len();
+        fmt::Write::write_fmt(self, args).map_err(|_| {
+            self.bytes.truncate(len);
+            BankFullError::new(())
+        })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use std::fmt::Write;
+    use super::*;
+
+    #[test]
+    fn push() {
+        let mut s = BankString::<6>::new();
+        s.push_str("ab");
+        s.push('ü');
+        assert_eq!(s.as_str(), "abü");
+        assert_eq!(s.len(), 4);
+        assert_eq!(&s[1..], "bü");
+
+        assert_eq!(s.try_push_str("cde"), Err(BankFullError::new("cde")));
+        assert_eq!(s.try_push('€'), Err(BankFullError::new('€')));
+        assert!(s.try_push('c').is_ok());
+
+        s.make_ascii_uppercase();
+        assert_eq!(s.as_str(), "ABüC");
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity exceeded during operation `push_str`: capacity is 2")]
+    fn push_to_full() {
+        let mut s = BankString::<2>::new();
+        s.push_str("abc");
+    }
+
+    #[test]
+    fn pop_truncate() {
+        let mut s = BankString::<8>::new();
+        s.push_str("aé€");
+        assert_eq!(s.pop(), Some('€'));
+        s.truncate(1);
+        assert_eq!(s.as_str(), "a");
+        s.truncate(4);
+        assert_eq!(s.as_str(), "a");
+        s.clear();
+        assert_eq!(s.pop(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "new length does not lie on a char boundary")]
+    fn truncate_char_boundary() {
+        let mut s = BankString::<8>::new();
+        s.push('é');
+        s.truncate(1);
+    }
+
+    #[test]
+    fn write() {
+        let mut s = BankString::<6>::new();
+        let (a, b, c) = (1, 'é', "34");
+        write!(s, "{a}:{b}").unwrap();
+        assert_eq!(s.as_str(), "1:é");
+
+        // `fmt::Write` keeps whatever fit before failing
+        assert!(write!(s, "{}{c}", a + 1).is_err());
+        assert_eq!(s.as_str(), "1:é2");
+
+        assert!(s.try_write_fmt(format_args!("{}{c}", a + 2)).is_err());
+        assert_eq!(s.as_str(), "1:é2");
+        s.try_write_fmt(format_args!("{}", a + 2)).unwrap();
+        assert_eq!(s.as_str(), "1:é23");
+        assert_eq!(s.to_string(), "1:é23");
+    }
+
+    #[test]
+    fn format_bank() {
+        const CAP: usize = 4;
+        let s = format_bank!(CAP, "{}{}", 'é', 10);
+        assert_eq!(s.as_str(), "é10");
+        assert_eq!(format_bank!(2, "").as_str(), "");
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity exceeded during operation `from_fmt`: capacity is 3")]
+    fn format_bank_overflow() {
+        format_bank!(3, "{:>4}", 1);
+    }
+
+    #[test]
+    fn conversions() {
+        let s = BankString::<4>::try_from("abc").unwrap();
+        assert_eq!(String::from(s.clone()), "abc");
+        assert_eq!(BankString::<2>::try_from("abc"), Err(BankFullError::new("abc")));
+
+        assert_eq!(s, "abc");
+        assert_eq!(s, *"abc");
+        assert_eq!(s, "abc".to_string());
+        assert_eq!("abc", s);
+        assert_eq!("abc".to_string(), s);
+        assert_ne!(s, "ab");
+    }
+
+    #[test]
+    fn ord_hash() {
+        use std::collections::{BTreeSet, HashMap};
+
+        let mut set = BTreeSet::new();
+        for s in ["b", "ab", "a"] {
+            set.insert(BankString::<2>::try_from(s).unwrap());
+        }
+        assert_eq!(set.iter().map(|s| s.as_str()).collect::<Vec<_>>(), ["a", "ab", "b"]);
+
+        let mut map = HashMap::new();
+        map.insert(BankString::<4>::try_from("key").unwrap(), 1);
+        assert_eq!(map.get("key"), Some(&1));
+    }
+}
@@ -0,0 +1,50 @@
+use std::fmt;
+
+use serde::{de::{self, Visitor}, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::BankString;
+
+
+impl<const C: usize> Serialize for BankString<C> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+struct BankStringVisitor<const C: usize>;
+
+impl<const C: usize> Visitor<'_> for BankStringVisitor<C> {
+    type Value = BankString<C>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a string of at most {C} bytes")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Self::Value, E> {
+        BankString::try_from(s).map_err(|_| E::invalid_length(s.len(), &self))
+    }
+}
+
+impl<'de, const C: usize> Deserialize<'de> for BankString<C> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(BankStringVisitor)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let s = BankString::<8>::try_from("héllo").unwrap();
+        let json = serde_json::to_string(&s).unwrap();
+        assert_eq!(json, "\"héllo\"");
+        assert_eq!(serde_json::from_str::<BankString<8>>(&json).unwrap(), s);
+
+        let err = serde_json::from_str::<BankString<4>>(&json).unwrap_err();
+        assert!(err.to_string().contains("a string of at most 4 bytes"));
+    }
+}
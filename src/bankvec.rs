@@ -1,11 +1,16 @@
 
 use core::slice;
-use std::{mem::{self, ManuallyDrop}, ops::{self, Deref, DerefMut, Index, IndexMut}, ptr::{self, NonNull}, slice::SliceIndex};
+use std::{borrow::{Borrow, BorrowMut, Cow}, hint, marker::PhantomData, mem::{self, ManuallyDrop, MaybeUninit}, ops::{self, Add, AddAssign, Deref, DerefMut, Index, IndexMut}, panic::{RefUnwindSafe, UnwindSafe}, ptr::{self, NonNull}, slice::SliceIndex};
 
 mod allocation;
 mod buffer_union;
+#[cfg(feature = "instrument")]
+mod stats;
 
-use crate::{drain, errors::AllocErr};
+#[cfg(feature = "instrument")]
+pub use stats::SpillStats;
+
+use crate::{chunks, drain::{self, DrainTarget}, errors::{AllocErr, BankFullError, InsertError}, into_iter::IntoIter, raw::{self, RawBank}, sort, spec};
 use buffer_union::*;
 use allocation::*;
 
@@ -84,6 +89,16 @@ use allocation::*;
 /// 
 /// ```
 /// 
+/// Because a bank borrows as a slice, a collection of banks can be concatenated
+/// or joined into a [`Vec`].
+/// ```
+/// use bankarr::BankVec;
+/// 
+/// let banks = [BankVec::<i32, 3>::from([1, 2]), BankVec::from([3])];
+/// assert_eq!(banks.concat(), [1, 2, 3]);
+/// assert_eq!(banks.join(&0), [1, 2, 0, 3]);
+/// ```
+/// 
 /// # Capacity
 /// 
 /// As with a [`BankArr`], the underlying capacity is specified by its generic, `C`.
@@ -102,35 +117,60 @@ use allocation::*;
 /// [`BankArr`]: crate::BankArr
 pub struct BankVec<T, const C: usize> {
     buf: BufferUnion<T, C>,
+    pub(crate) len: usize,
+    // `C` while inline, otherwise the size of the heap buffer, which is always
+    // greater than `C`
     capacity: usize,
+    #[cfg(feature = "instrument")]
+    pub(crate) stats: SpillStats,
+    // The union never drops what it holds, so ownership of `T` is marked here
+    marker: PhantomData<T>,
 }
 
+// As with `BankArr`, these match `Vec` rather than following the fields.  The
+// heap pointer alone would demand `T: RefUnwindSafe` to be `UnwindSafe`, and
+// the inline array `T: Unpin`, though the bank never pins its elements.
+impl<T: UnwindSafe, const C: usize> UnwindSafe for BankVec<T, C> {}
+impl<T: RefUnwindSafe, const C: usize> RefUnwindSafe for BankVec<T, C> {}
+impl<T, const C: usize> Unpin for BankVec<T, C> {}
+
 #[cfg(not(tarpaulin_include))]
 impl<T: std::fmt::Debug, const C: usize> std::fmt::Debug for BankVec<T, C> 
 {
+    /// Formats the bank's elements and capacity.  The alternate form (`{:#?}`)
+    /// also shows its length, whether it has spilled onto the heap, and how much
+    /// room remains before it would.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         
-        const VEC_FIELD: &'static str = "buf (Vec)";
-        const ARR_FIELD: &'static str = "buf (Array)";
+        const VEC_FIELD: &str = "buf (Vec)";
+        const ARR_FIELD: &str = "buf (Array)";
 
-        let (field, capacity) = match self.on_heap() {
-            true => (VEC_FIELD, self.capacity),
-            false => (ARR_FIELD, C)
+        let field = match self.on_heap() {
+            true => VEC_FIELD,
+            false => ARR_FIELD,
         };
         
+        let alternate = f.alternate();
         let name = std::fmt::format(format_args!("BankVec<T, {}>", C));
-        f.debug_struct(&name)
+        let mut debug = f.debug_struct(&name);
+        debug
             .field(field, &self.as_slice())
-            .field("capacity", &capacity)
-            .finish()
-    
+            .field("capacity", &self.capacity);
+
+        if alternate {
+            debug
+                .field("len", &self.len())
+                .field("on_heap", &self.on_heap())
+                .field("inline_headroom", &C.saturating_sub(self.len()));
+        }
+        debug.finish()
     }
 }
 
 impl <T, const C: usize> Deref for BankVec<T, C> {
     type Target = [T];
     #[inline]
-    fn deref(&self) -> &Self::Target { &self.as_slice() }
+    fn deref(&self) -> &Self::Target { self.as_slice() }
 }
 
 impl <T, const C: usize> DerefMut for BankVec<T, C> {
@@ -138,19 +178,61 @@ impl <T, const C: usize> DerefMut for BankVec<T, C> {
     fn deref_mut(&mut self) -> &mut Self::Target { self.as_mut_slice() }
 }
 
+impl<T, const C: usize> AsRef<[T]> for BankVec<T, C> {
+    #[inline]
+    fn as_ref(&self) -> &[T] { self.as_slice() }
+}
+
+impl<T, const C: usize> AsMut<[T]> for BankVec<T, C> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] { self.as_mut_slice() }
+}
+
+// Borrowing as a slice is what lets `[bank1, bank2].concat()` and
+// `banks.join(&sep)` work through std's blanket `Concat`/`Join` impls.
+impl<T, const C: usize> Borrow<[T]> for BankVec<T, C> {
+    #[inline]
+    fn borrow(&self) -> &[T] { self.as_slice() }
+}
+
+impl<T, const C: usize> BorrowMut<[T]> for BankVec<T, C> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut [T] { self.as_mut_slice() }
+}
+
 impl<T, const C: usize, I: SliceIndex<[T]>> Index<I> for BankVec<T, C> {
     type Output = I::Output;
 
     #[inline]
+    #[track_caller]
     fn index(&self, index: I) -> &Self::Output { 
         Index::index(&**self, index) }
 }
 
 impl<T, const C: usize, I: SliceIndex<[T]>> IndexMut<I> for BankVec<T, C> {
     #[inline]
+    #[track_caller]
     fn index_mut(&mut self, index: I) -> &mut Self::Output { IndexMut::index_mut(&mut **self, index) }
 }
 
+impl<T, const C: usize> IntoIterator for BankVec<T, C> {
+    type Item = T;
+    type IntoIter = IntoIter<T, Self>;
+
+    /// Creates a consuming iterator, moving each element out of the bank.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let bank = BankVec::<String, 1>::from(["a".to_string(), "b".to_string()]);
+    /// let joined: String = bank.into_iter().rev().collect();
+    /// assert_eq!(joined, "ba");
+    /// ```
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { IntoIter::new(self) }
+}
+
 impl<'a, T, const C: usize> IntoIterator for &'a BankVec<T, C> {
     type Item = &'a T;
     type IntoIter = slice::Iter<'a, T>;
@@ -204,26 +286,38 @@ impl<T: PartialEq, const C: usize> PartialEq<&[T]> for BankVec<T, C> {
 }
 
 impl<T: Clone, const C: usize> Clone for BankVec<T, C> {
-    fn clone(&self) -> Self {
-        use ptr::copy_nonoverlapping as cp;
+    /// Clones the bank, allocating exactly its length if it doesn't fit
+    /// inline.  A spilled bank short enough to fit in `C` is cloned inline.
+    fn clone(&self) -> Self { Self::from(self.as_slice()) }
+}
 
-        if self.on_heap() {
-            let (ptr, len, _) = unsafe { self.heap() };
-            let mut cloned = Self {
-                buf: BufferUnion::heap_from(NonNull::dangling(), 0),
-                capacity: 0
-            };
-            cloned.reserve(len);
-            unsafe { cp(ptr, cloned.buf.heap.0.as_ptr(), len) }
-            cloned.buf.heap.1 = len;
-
-            cloned
-        } else {
-            let (ptr, len, _) = unsafe { self.stack() };
-            let mut buf = BufferUnion::new_stack();
-            unsafe { cp(ptr, buf.stack_ptr_nn().as_ptr(), len) }
-            Self { buf, capacity: len }
+impl<T, const C: usize> FromIterator<T> for BankVec<T, C> {
+
+    /// Collects an iterator into a bank, choosing between inline and heap
+    /// storage from its size hint before any element is written.
+    /// 
+    /// An iterator reporting its exact length longer than `C` is collected
+    /// straight into a heap buffer of that length, rather than filling the
+    /// inline array only to move it.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let bank: BankVec<i32, 2> = (1..=5).collect();
+    /// assert!(bank.on_heap());
+    /// assert_eq!(bank.capacity(), 5);
+    /// assert_eq!(bank, [1, 2, 3, 4, 5]);
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(items: I) -> Self {
+        let items = items.into_iter();
+        let mut bank = Self::new();
+        match items.size_hint() {
+            (lower, Some(upper)) if lower == upper => bank.reserve_exact(lower),
+            (lower, _) => bank.reserve(lower),
         }
+        bank.extend(items);
+        bank
     }
 }
 
@@ -231,9 +325,20 @@ impl<T, const C: usize> Extend<T> for BankVec<T, C> {
 
     /// Extends a collection with the contents of an iterator.  
     /// Will reallocate onto the heap if necessary.
+    /// 
+    /// Room for the iterator's lower size bound is reserved up front, so an
+    /// exact-size iterator grows the bank at most once.
     fn extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
 
         let mut iter = items.into_iter();
+        if let Some(slice) = spec::copy_slice(&iter) {
+            self.reserve(slice.len());
+            unsafe { self.raw_extend_from_slice(slice) }
+            self.record_len();
+            return
+        }
+
+        self.reserve(iter.size_hint().0);
         let (ptr, len, cap) = self.data_buf_mut();
 
         let ptr = ptr.as_ptr();
@@ -246,28 +351,95 @@ impl<T, const C: usize> Extend<T> for BankVec<T, C> {
             } else { break }
         }
         *len = cp_len;
+        self.record_len();
 
-        // This produces identical results to the while loop above
-            //for idx in cp_len..cap {
-            //    if let Some(value) = iter.next() {
-            //        unsafe { ptr.add(idx).write(value) }
-            //    } else {
-            //        *len = idx;
-            //        break;
-            //    }
-            //}
-        //
+        self.extend_spilling(iter)
+    }
+}
 
-        iter.for_each(|value| self.push(value))
+impl<'a, T: Copy + 'a, const C: usize> Extend<&'a T> for BankVec<T, C> {
+
+    /// Extends the bank by copying elements out of an iterator of references.  
+    /// Will reallocate onto the heap if necessary.
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, items: I) {
+        let items = items.into_iter();
+        match spec::ref_slice(&items) {
+            Some(slice) => self.extend_from_slice(slice),
+            None => self.extend(items.copied()),
+        }
     }
 }
 
+impl<T: Clone, const C: usize> AddAssign<&[T]> for BankVec<T, C> {
 
-#[cfg(not(tarpaulin_include))] // Drain's drop implicitly tests this
-impl<'a, T, const C: usize> drain::Drainable<'a, T> for BankVec<T, C> {
-    fn drain_parts(&'a mut self) -> (NonNull<T>, &'a mut usize) {
-        let (ptr, len, _) = self.data_buf_mut();
-        (ptr, len)
+    /// Appends clones of the elements of `rhs` onto the back of the bank.
+    #[inline]
+    fn add_assign(&mut self, rhs: &[T]) {
+        self.reserve(rhs.len());
+        self.extend(rhs.iter().cloned());
+    }
+}
+
+impl<T, const C: usize, const D: usize> AddAssign<BankVec<T, D>> for BankVec<T, C> {
+
+    /// Moves the elements of `rhs` onto the back of the bank.
+    #[inline]
+    fn add_assign(&mut self, mut rhs: BankVec<T, D>) {
+        self.reserve(rhs.len());
+        self.extend(rhs.drain(..));
+    }
+}
+
+impl<T: Clone, const C: usize> Add<&[T]> for BankVec<T, C> {
+    type Output = Self;
+
+    /// Concatenates clones of the elements of `rhs` onto the bank.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let bank = BankVec::<i32, 4>::from([1, 2]) + &[3, 4][..];
+    /// assert_eq!(bank, [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    fn add(mut self, rhs: &[T]) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<T, const C: usize, const D: usize> Add<BankVec<T, D>> for BankVec<T, C> {
+    type Output = Self;
+
+    /// Concatenates the elements of `rhs` onto the bank.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let bank = BankVec::<i32, 4>::from([1, 2]) + BankVec::<i32, 2>::from([3, 4]);
+    /// assert_eq!(bank, [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    fn add(mut self, rhs: BankVec<T, D>) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+unsafe impl<T, const C: usize> RawBank<T> for BankVec<T, C> {
+    #[inline(always)]
+    fn raw_ptr(&self) -> *const T { self.data_buf().0 }
+
+    #[inline(always)]
+    fn raw_parts(&mut self) -> (NonNull<T>, &mut usize, usize) { self.data_buf_mut() }
+
+    #[inline]
+    #[track_caller]
+    fn raw_reserve(&mut self, additional: usize) -> usize {
+        self.reserve(additional);
+        additional
     }
 }
 
@@ -300,20 +472,48 @@ impl<T, const C: usize> From<Vec<T>> for BankVec<T, C> {
             unsafe { vec.set_len(0); }
             unsafe { cp(vec.as_ptr(), buf.stack_ptr_nn().as_ptr(), len); }
 
-            Self { buf, capacity: len }
-        } else {
-            let (ptr, cap, len) = (vec.as_mut_ptr(), vec.capacity(), vec.len());
-            mem::forget(vec);
-            let ptr = NonNull::new(ptr).expect("Uh oh");
-
             Self {
-                buf: BufferUnion::heap_from(ptr, len),
-                capacity: cap,
+                buf, len, capacity: C,
+                #[cfg(feature = "instrument")]
+                stats: SpillStats::new(len),
+                marker: PhantomData,
             }
+        } else {
+            Self::from_vec_keep_alloc(vec)
         }
     }
 }
 
+impl<T: Clone, const C: usize> From<&[T]> for BankVec<T, C> {
+
+    /// Creates a bank holding clones of the elements of a slice, inline if they
+    /// fit in `C`, otherwise in a heap buffer of exactly their length.
+    /// 
+    /// For `Copy` elements, [`from_slice`](BankVec::from_slice) copies them in
+    /// bulk.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let words = ["a", "b", "c"].map(String::from);
+    /// let bank = BankVec::<String, 2>::from(&words[..]);
+    /// assert_eq!(bank.capacity(), 3);
+    /// assert_eq!(bank, words);
+    /// ```
+    fn from(slice: &[T]) -> Self {
+        let mut bank = Self::new();
+        // `reserve` would round a spilled length up to a power of two
+        bank.reserve_exact(slice.len());
+
+        let (ptr, len, _) = bank.data_buf_mut();
+        unsafe { spec::clone_to_uninit(slice, ptr.as_ptr()) }
+        *len = slice.len();
+        bank.record_len();
+        bank
+    }
+}
+
 impl<T, const C: usize, const N: usize> From<[T; N]> for BankVec<T, C> {
 
     /// Create a new instance from an array.
@@ -340,13 +540,20 @@ impl<T, const C: usize, const N: usize> From<[T; N]> for BankVec<T, C> {
         if N <= C {
             let mut buf = BufferUnion::new_stack();
             unsafe { ptr.copy_to_nonoverlapping(buf.stack_ptr_nn(), N);}
-            Self { buf, capacity: N }
+            Self {
+                buf, len: N, capacity: C,
+                #[cfg(feature = "instrument")]
+                stats: SpillStats::new(N),
+                marker: PhantomData,
+            }
         } else {
-            let mut bank = Self { buf: BufferUnion::new_heap(), capacity: 0, };
-            bank.reserve(N);
-            unsafe { ptr.copy_to_nonoverlapping(bank.buf.heap.0, N);}
-            bank.buf.heap.1 = N;
-            
+            // The length is known, so there's no growth to leave room for
+            let mut bank = Self::new();
+            bank.reserve_exact(N);
+            unsafe { ptr.copy_to_nonoverlapping(bank.buf.heap, N);}
+            bank.len = N;
+            bank.record_len();
+
             bank
         }
 
@@ -355,21 +562,143 @@ impl<T, const C: usize, const N: usize> From<[T; N]> for BankVec<T, C> {
 
 
 
-impl<T, const C: usize> Drop for BankVec<T, C> {
-    fn drop(&mut self) {
-        match self.on_heap() {
+impl<T, const C: usize> From<BankVec<T, C>> for Vec<T> {
+
+    /// Converts the bank into a vec.
+    /// 
+    /// A bank on the heap hands its allocation over to the vec without copying or 
+    /// reallocating, otherwise the inline elements are moved into a new vec.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let bank = BankVec::<i32, 2>::from([1, 2, 3]);
+    /// let ptr = bank.as_ptr();
+    /// 
+    /// let vec = Vec::from(bank);
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// assert_eq!(vec.as_ptr(), ptr);
+    /// ```
+    fn from(bank: BankVec<T, C>) -> Self {
+        let mut bank = ManuallyDrop::new(bank);
+        match bank.on_heap() {
             true => unsafe {
-                let (ptr, &mut len, _) = self.heap_mut();
-                drop(Vec::from_raw_parts(ptr.as_ptr(), len, self.capacity))
+                let (ptr, &mut len, cap) = bank.heap_mut();
+                Vec::from_raw_parts(ptr.as_ptr(), len, cap)
             },
-            false => unsafe { ptr::drop_in_place(&mut self[..]); }
+            false => {
+                let (ptr, len, _) = bank.data_buf();
+                let mut vec = Vec::with_capacity(len);
+                unsafe {
+                    ptr::copy_nonoverlapping(ptr, vec.as_mut_ptr(), len);
+                    vec.set_len(len);
+                }
+                vec
+            }
+        }
+    }
+}
+
+impl<T, const C: usize, const N: usize> TryFrom<BankVec<T, C>> for [T; N] {
+    type Error = BankVec<T, C>;
+
+    /// Moves the elements of a bank holding exactly `N` of them into an array,
+    /// or hands the bank back if its length differs.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let bank = BankVec::<i32, 2>::from([1, 2, 3]);
+    /// let bank = <[i32; 2]>::try_from(bank).unwrap_err();
+    /// assert_eq!(<[i32; 3]>::try_from(bank), Ok([1, 2, 3]));
+    /// ```
+    fn try_from(mut bank: BankVec<T, C>) -> Result<Self, Self::Error> {
+        if bank.len() != N { return Err(bank) }
+
+        let (ptr, len, _) = bank.data_buf_mut();
+        // The elements now belong to the array, the bank frees only its buffer
+        *len = 0;
+        Ok(unsafe { ptr.cast::<[T; N]>().read() })
+    }
+}
+
+impl<T: Clone, const C: usize> From<Cow<'_, [T]>> for BankVec<T, C> {
+
+    /// Create a new instance from a clone-on-write slice.
+    /// 
+    /// Owned data is converted as with [`From<Vec<T>>`], borrowed data is cloned.
+    /// Either may exceed `C`, in which case the bank is stored on the heap.
+    /// 
+    /// # Examples
+    /// ```
+    /// use std::borrow::Cow;
+    /// use bankarr::BankVec;
+    /// 
+    /// let bank = BankVec::<i32, 3>::from(Cow::Borrowed(&[1, 2][..]));
+    /// assert!(!bank.on_heap());
+    /// 
+    /// let bank = BankVec::<i32, 3>::from(Cow::Borrowed(&[1, 2, 3, 4][..]));
+    /// assert!(bank.on_heap());
+    /// ```
+    fn from(cow: Cow<'_, [T]>) -> Self {
+        match cow {
+            Cow::Owned(vec) => Self::from(vec),
+            Cow::Borrowed(slice) => {
+                let mut bank = Self::new();
+                bank.reserve_exact(slice.len());
+                bank.extend(slice.iter().cloned());
+                bank
+            }
         }
     }
 }
 
+impl<'a, T: Clone, const C: usize> From<&'a BankVec<T, C>> for Cow<'a, [T]> {
+    #[inline]
+    fn from(bank: &'a BankVec<T, C>) -> Self { Cow::Borrowed(bank.as_slice()) }
+}
+
+impl<T, const C: usize> Default for BankVec<T, C> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+
+#[cfg(not(feature = "may-dangle"))]
+impl<T, const C: usize> Drop for BankVec<T, C> {
+    fn drop(&mut self) { self.drop_buffer() }
+}
+
+// The elements are only dropped, never read, so any borrows they hold may
+// already have ended
+#[cfg(feature = "may-dangle")]
+unsafe impl<#[may_dangle] T, const C: usize> Drop for BankVec<T, C> {
+    fn drop(&mut self) { self.drop_buffer() }
+}
+
 impl<T, const C: usize> BankVec<T, C> {
 
+    // Drops the elements, then frees the heap buffer if there is one
+    fn drop_buffer(&mut self) {
+        // Frees the heap buffer even if an element's destructor panics
+        struct Dealloc<T>(NonNull<T>, usize);
+
+        impl<T> Drop for Dealloc<T> {
+            fn drop(&mut self) { unsafe { deallocate(self.0, self.1) } }
+        }
+
+        let on_heap = self.on_heap();
+        let (ptr, &mut len, cap) = self.data_buf_mut();
+        let _dealloc = on_heap.then(|| Dealloc(ptr, cap));
+        if mem::needs_drop::<T>() {
+            unsafe { ptr::slice_from_raw_parts_mut(ptr.as_ptr(), len).drop_in_place() }
+        }
+    }
+
     #[cold]
+    #[inline(never)]
+    #[track_caller]
     fn reserve_one_unchecked(&mut self) {
         debug_assert_eq!(self.len(), self.capacity());
         let new_cap = self.len()
@@ -379,6 +708,47 @@ impl<T, const C: usize> BankVec<T, C> {
         infallible(try_grow(self, new_cap));
     }
 
+    // Pushes whatever `extend` couldn't fit in the current capacity, growing
+    // as needed.  Kept out of line so `extend` stays small for the common case
+    // of items which fit.
+    #[inline(never)]
+    fn extend_spilling<I: Iterator<Item = T>>(&mut self, mut iter: I) {
+        while let Some(value) = iter.next() {
+            // Like `Vec`, grow by the remaining lower bound rather than one
+            // element at a time
+            if self.len() == self.capacity() {
+                self.reserve(iter.size_hint().0.saturating_add(1));
+            }
+            self.push(value);
+        }
+    }
+
+    // Moves an inline bank's elements into the spare allocation of `vec`, whose
+    // capacity must exceed `C`, and keeps it as the bank's heap buffer.
+    pub(crate) fn adopt_heap(&mut self, mut vec: Vec<T>) {
+        debug_assert!(!self.on_heap() && vec.is_empty() && vec.capacity() > C);
+        vec.extend(self.drain(..));
+
+        let mut vec = ManuallyDrop::new(vec);
+        let (ptr, len, cap) = (vec.as_mut_ptr(), vec.len(), vec.capacity());
+        let ptr = NonNull::new(ptr).expect("vec pointer should never be null");
+        self.buf = BufferUnion::heap_from(ptr);
+        (self.len, self.capacity) = (len, cap);
+        self.record_spill();
+    }
+
+    // Moves a spilled bank's elements back inline, handing back its heap buffer
+    // as an empty vec.  The bank's length must not exceed `C`.
+    pub(crate) fn release_heap(&mut self) -> Vec<T> {
+        debug_assert!(self.on_heap() && self.len() <= C);
+        let (ptr, len, cap) = (unsafe { self.buf.heap }, self.len, self.capacity);
+
+        self.buf = BufferUnion::new_stack();
+        unsafe { ptr.copy_to_nonoverlapping(self.buf.stack_ptr_nn(), len) }
+        self.capacity = C;
+        unsafe { Vec::from_raw_parts(ptr.as_ptr(), 0, cap) }
+    }
+
 
     /// Reserves the minimum capacity for at least `additional` more elements to be 
     /// inserted in the given BankVec. After calling reserve, capacity will be greater 
@@ -401,6 +771,7 @@ impl<T, const C: usize> BankVec<T, C> {
     /// ```
     ///     
     #[inline]
+    #[track_caller]
     pub fn reserve(&mut self, additional: usize) {
         infallible(self.try_reserve(additional));
     }
@@ -427,12 +798,27 @@ impl<T, const C: usize> BankVec<T, C> {
     /// ```
     ///     
     #[inline]
+    #[track_caller]
     pub fn reserve_exact(&mut self, additional: usize) {
         infallible(self.try_reserve_exact(additional))
     }
 
+    /// Tries to reserve capacity for at least `additional` more elements, as
+    /// with [`reserve`](BankVec::reserve), returning an error instead of panicking
+    /// or aborting if the allocation fails.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::{AllocErr, BankVec};
+    /// 
+    /// let mut bank = BankVec::<i32, 3>::from([1, 2, 3]);
+    /// assert!(bank.try_reserve(10).is_ok());
+    /// assert!(bank.capacity() >= 13);
+    /// 
+    /// assert_eq!(bank.try_reserve(usize::MAX), Err(AllocErr::Overflow));
+    /// ```
     #[inline]
-    fn try_reserve(&mut self, additional: usize) -> Result<(), AllocErr> {
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), AllocErr> {
         let (_, &mut len, cap) = self.data_buf_mut();
         match cap - len >= additional {
             true => Ok(()),
@@ -443,8 +829,22 @@ impl<T, const C: usize> BankVec<T, C> {
         }
     }
 
+    /// Tries to reserve capacity for exactly `additional` more elements, as
+    /// with [`reserve_exact`](BankVec::reserve_exact), returning an error instead
+    /// of panicking or aborting if the allocation fails.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::{AllocErr, BankVec};
+    /// 
+    /// let mut bank = BankVec::<i32, 3>::from([1, 2, 3]);
+    /// assert!(bank.try_reserve_exact(10).is_ok());
+    /// assert_eq!(bank.capacity(), 13);
+    /// 
+    /// assert_eq!(bank.try_reserve_exact(usize::MAX), Err(AllocErr::Overflow));
+    /// ```
     #[inline]
-    fn try_reserve_exact(&mut self, additional: usize) -> Result<(), AllocErr> {
+    pub fn try_reserve_exact(&mut self, additional: usize) -> Result<(), AllocErr> {
         let (_, &mut len, cap) = self.data_buf_mut();
         match cap - len >= additional {
             true => Ok(()),
@@ -467,19 +867,35 @@ impl<T, const C: usize> BankVec<T, C> {
     /// assert_eq!(bank.len(), 1);
     /// ```
     #[inline]
-    pub const fn len(&self) -> usize {
-        match self.on_heap() {
-            true => unsafe { self.buf.heap.1 },
-            false => self.capacity
-        }
-    }
+    pub const fn len(&self) -> usize { self.len }
+
+    /// Returns `true` if the bank contains no elements.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut bank = BankVec::<i32, 3>::new();
+    /// assert!(bank.is_empty());
+    /// 
+    /// bank.push(5);
+    /// assert!(!bank.is_empty());
+    /// ```
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.len() == 0 }
 
+    /// Forces the length of the bank to `length`.
+    /// 
+    /// # Safety
+    /// 
+    /// `length` must be less than or equal to [`capacity`](BankVec::capacity)
+    /// and the elements at `old_len..length` must be initialized.  This is the
+    /// same whether the bank is inline or on the heap.
     #[inline]
     pub const unsafe fn set_len(&mut self, length: usize) {
-        match self.on_heap() {
-            true => self.buf.heap.1 = length,
-            false => self.capacity = length
-        }
+        strict_assert!(length <= self.capacity, "set_len beyond the bank's capacity");
+        self.len = length;
+        self.record_len();
     }
 
     /// Returns true if the bank has exceeded its capacity and moved to the heap,
@@ -501,38 +917,59 @@ impl<T, const C: usize> BankVec<T, C> {
 
     #[inline(always)]
     unsafe fn heap(&self) -> DataBuf<T> {
-        unsafe { (self.buf.heap.0.as_ptr().cast_const(), self.buf.heap.1, self.capacity) }
+        unsafe { (self.buf.heap.as_ptr().cast_const(), self.len, self.capacity) }
     }
 
     #[inline(always)]
     const unsafe fn heap_mut<'a>(&'a mut self) -> DataBufMut<'a,T> {
-        unsafe { (self.buf.heap.0, &mut self.buf.heap.1, self.capacity) }
+        unsafe { (self.buf.heap, &mut self.len, self.capacity) }
     }
 
     #[inline(always)]
     unsafe fn stack(&self) -> DataBuf<T> {
-        unsafe { (self.buf.stack.as_ptr().cast(), self.capacity, C) }
+        unsafe { (self.buf.stack.as_ptr().cast(), self.len, C) }
     }
 
     #[inline(always)]
     unsafe fn stack_mut<'a>(&'a mut self) -> DataBufMut<'a,T> {
-        unsafe { (self.buf.stack_ptr_nn(), &mut self.capacity, C) }
+        unsafe { (self.buf.stack_ptr_nn(), &mut self.len, C) }
     }
 
     #[inline]
     fn data_buf(&self) -> DataBuf<T> {
-        match self.on_heap() {
+        let buf = match self.on_heap() {
             true => unsafe { self.heap() },
             false => unsafe { self.stack() }
-        }
+        };
+        // Lets the optimizer drop bounds checks against the length
+        unsafe { hint::assert_unchecked(buf.1 <= buf.2) }
+        buf
     }
 
     #[inline]
     pub(super) fn data_buf_mut<'a>(&'a mut self) -> DataBufMut<'a,T> {
-        match self.on_heap() {
+        let buf = match self.on_heap() {
             true => unsafe { self.heap_mut() },
             false => unsafe { self.stack_mut() }
-        }
+        };
+        unsafe { hint::assert_unchecked(*buf.1 <= buf.2) }
+        buf
+    }
+
+    // Counts a move of the elements from inline storage onto the heap, with
+    // the `instrument` feature
+    #[inline(always)]
+    const fn record_spill(&mut self) {
+        #[cfg(feature = "instrument")]
+        { self.stats.spills += 1 }
+    }
+
+    // Keeps the greatest length reached up to date after the bank grows, with
+    // the `instrument` feature
+    #[inline(always)]
+    const fn record_len(&mut self) {
+        #[cfg(feature = "instrument")]
+        if self.len() > self.stats.max_len { self.stats.max_len = self.len() }
     }
 
 
@@ -556,59 +993,199 @@ impl<T, const C: usize> BankVec<T, C> {
 
         Self {
             buf: BufferUnion::new_stack(),
-            capacity: 0
+            len: 0,
+            capacity: C,
+            #[cfg(feature = "instrument")]
+            stats: SpillStats::new(0),
+            marker: PhantomData,
         }
     }
 
-
-    /// Returns the number of elements the bank can hold without reallocating.
+    /// Converts a vec into a bank, adopting its allocation even when its
+    /// elements would fit inline.
     /// 
-    #[inline]
-    pub fn capacity(&self) -> usize {
-        if self.on_heap() { self.capacity } else { C }
-        //self.data_buf().2 
+    /// Unlike the `From<Vec<T>>` conversion, which moves short vecs inline and
+    /// frees their buffer, this keeps a buffer that's about to be needed
+    /// again.  Only a vec whose capacity
+    /// doesn't exceed `C` has its elements moved inline, as its buffer is no
+    /// larger than the bank's own.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut vec = Vec::with_capacity(64);
+    /// vec.extend([1, 2]);
+    /// 
+    /// let bank = BankVec::<i32, 4>::from_vec_keep_alloc(vec);
+    /// assert!(bank.on_heap());
+    /// assert_eq!(bank.capacity(), 64);
+    /// assert_eq!(bank, [1, 2]);
+    /// ```
+    pub fn from_vec_keep_alloc(vec: Vec<T>) -> Self {
+        if vec.capacity() <= C { return Self::from(vec) }
+
+        let mut vec = ManuallyDrop::new(vec);
+        let (ptr, len, cap) = (vec.as_mut_ptr(), vec.len(), vec.capacity());
+        let ptr = NonNull::new(ptr).expect("vec pointer should never be null");
+        Self {
+            buf: BufferUnion::heap_from(ptr),
+            len,
+            capacity: cap,
+            #[cfg(feature = "instrument")]
+            stats: SpillStats::new(len),
+            marker: PhantomData,
+        }
     }
 
 
-    /// Appends an element to the back of the collection.
-    /// 
-    /// If the resulting length would exceed `C`, the bank is moved to the heap.
+    /// Shrinks the bank's heap allocation to fit its length, moving the elements 
+    /// back inline if they fit within `C`.  Does nothing for an inline bank.
     /// 
     /// # Examples
-    /// 
     /// ```
     /// use bankarr::BankVec;
     /// 
-    /// let mut bank = BankVec::<i32, 3>::from([1, 2]);
-    /// bank.push(3);
-    /// assert!(!bank.on_heap()); // Still a fixed size data structure
-    /// bank.push(4); /// Length exceeds `C`
-    /// assert!(bank.on_heap()); // Now a vec-like heap
-    /// assert_eq!(bank, [1, 2, 3, 4]);
-    /// ```
-    /// 
-    /// # Time Complexity
+    /// let mut bank = BankVec::<i32, 3>::from([1, 2, 3, 4, 5]);
+    /// bank.truncate(4);
+    /// bank.shrink_to_fit();
+    /// assert_eq!(bank.capacity(), 4);
     /// 
-    /// Takes *O*(1) time if the new bank length does not exceed, or has already 
-    /// exceeded, `C`, otherwise *O*(`C` + 1) time is needed to move the data 
-    /// into a heap.
-    ///     
-    #[inline]
-    pub fn push(&mut self, value: T) {
-        let (mut ptr, mut len, cap) = self.data_buf_mut();
-        if *len == cap {
-            self.reserve_one_unchecked();
-            ptr = unsafe { self.buf.heap.0 };
-            len = unsafe { &mut self.buf.heap.1 };
+    /// bank.truncate(2);
+    /// bank.shrink_to_fit();
+    /// assert!(!bank.on_heap());
+    /// assert_eq!(bank, [1, 2]);
+    /// ```
+    #[track_caller]
+    pub fn shrink_to_fit(&mut self) {
+        if self.on_heap() {
+            infallible(try_grow(self, self.len()));
         }
-        unsafe { ptr.add(*len).write(value) };
-        *len += 1;
     }
 
-    /// Inserts an element at position `index` within the bank, shifting all elements
-    /// after it to the right.
+    /// Shortens the bank, keeping the first `len` elements and dropping the rest.
+    /// Does nothing if `len` is greater than the bank's current length.
     /// 
-    /// # Examples
+    /// The bank's capacity is left unchanged.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut bank = BankVec::<i32, 3>::from([1, 2, 3, 4]);
+    /// bank.truncate(2);
+    /// assert_eq!(bank, [1, 2]);
+    /// assert!(bank.on_heap());
+    /// ```
+    pub fn truncate(&mut self, len: usize) { self.raw_truncate(len) }
+
+    /// Removes all elements from the bank, leaving its capacity unchanged.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut bank = BankVec::<i32, 3>::from([1, 2, 3, 4]);
+    /// bank.clear();
+    /// assert!(bank.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) { self.truncate(0) }
+
+    /// Returns the number of elements the bank can hold without reallocating.
+    /// 
+    #[inline]
+    pub const fn capacity(&self) -> usize { self.capacity }
+
+    /// Returns the bank's spare capacity as a slice of uninitialized elements,
+    /// inline or on the heap.
+    /// 
+    /// Elements written here can be added to the bank with
+    /// [`set_len`](BankVec::set_len).
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut bank = BankVec::<i32, 4>::from([1]);
+    /// let spare = bank.spare_capacity_mut();
+    /// assert_eq!(spare.len(), 3);
+    /// spare[0].write(2);
+    /// spare[1].write(3);
+    /// 
+    /// unsafe { bank.set_len(3) };
+    /// assert_eq!(bank, [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        let (ptr, &mut len, cap) = self.data_buf_mut();
+        unsafe { slice::from_raw_parts_mut(ptr.as_ptr().add(len).cast(), cap - len) }
+    }
+
+
+    /// Appends an element to the back of the collection.
+    /// 
+    /// If the resulting length would exceed `C`, the bank is moved to the heap.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut bank = BankVec::<i32, 3>::from([1, 2]);
+    /// bank.push(3);
+    /// assert!(!bank.on_heap()); // Still a fixed size data structure
+    /// bank.push(4); /// Length exceeds `C`
+    /// assert!(bank.on_heap()); // Now a vec-like heap
+    /// assert_eq!(bank, [1, 2, 3, 4]);
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(1) time if the new bank length does not exceed, or has already 
+    /// exceeded, `C`, otherwise *O*(`C` + 1) time is needed to move the data 
+    /// into a heap.
+    ///     
+    #[inline]
+    #[track_caller]
+    pub fn push(&mut self, value: T) {
+        // Length and capacity mean the same thing inline or on the heap, so a
+        // bank with room to spare takes a single check either way
+        if self.len == self.capacity { self.reserve_one_unchecked() }
+
+        let (ptr, len, _) = self.data_buf_mut();
+        unsafe { ptr.add(*len).write(value) }
+        *len += 1;
+        self.record_len();
+    }
+
+    /// Attempts to append an element to the back of the collection.  If growing
+    /// the bank fails, the element is handed back inside the error instead of
+    /// panicking or aborting.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut bank = BankVec::<i32, 2>::from([1, 2]);
+    /// assert!(bank.try_push(3).is_ok());
+    /// assert_eq!(bank, [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn try_push(&mut self, value: T) -> Result<(), BankFullError<T>> {
+        if self.try_reserve(1).is_err() { return Err(BankFullError::new(value)) }
+        let (ptr, len, _) = self.data_buf_mut();
+        unsafe { ptr.add(*len).write(value) };
+        *len += 1;
+        self.record_len();
+        Ok(())
+    }
+
+    /// Inserts an element at position `index` within the bank, shifting all elements
+    /// after it to the right.
+    /// 
+    /// # Examples
     /// 
     /// ```
     /// use bankarr::BankVec;
@@ -627,28 +1204,47 @@ impl<T, const C: usize> BankVec<T, C> {
     /// shifted when insertion index is 0.  Should the new `len` exceed `C`, the
     /// data is moved to the heap.
     /// 
+    #[track_caller]
     pub fn insert(&mut self, index: usize, element: T) {
-        // Most of this procedure for insert was copied from the SmallVec crate.
-        // I really don't understand why but, it compiles down to slightly faster
-        // machine code.
-        let (mut ptr, mut len, cap) = self.data_buf_mut();
-        if *len == cap {
-            self.reserve_one_unchecked();
-            ptr = unsafe { self.buf.heap.0 };
-            len = unsafe { &mut self.buf.heap.1 };
+        let (_, &mut len, cap) = self.data_buf_mut();
+        if index > len { raw::insert_out_of_bounds(index, len) }
+        if len == cap {
+            // Spilling opens the gap as it moves the elements
+            if !self.on_heap() { return spill_insert(self, index, element) }
+            self.reserve_one_unchecked()
         }
-        let mut ptr = ptr.as_ptr();
-        let cp_len = *len;
 
-        if index > cp_len { panic!("index out of bounds"); }
+        unsafe { self.raw_insert(index, element) }
+        self.record_len();
+    }
 
-        ptr = unsafe { ptr.add(index) };
-        if index < cp_len {
-            unsafe { ptr.copy_to(ptr.add(1), cp_len - index) }
-        }
-        *len = cp_len + 1;
-        unsafe { ptr.write(element) };
+    /// Attempts to insert an element at position `index` within the bank, 
+    /// shifting all elements after it to the right.
+    /// 
+    /// Never panics, on failure the element is handed back inside the error.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::{BankVec, InsertError};
+    /// 
+    /// let mut bank = BankVec::<i32, 2>::from([1, 3]);
+    /// 
+    /// assert_eq!(bank.try_insert(5, 2), Err(InsertError::OutOfBounds(2)));
+    /// assert_eq!(bank.try_insert(1, 2), Ok(()));
+    /// assert_eq!(bank, [1, 2, 3]);
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(`BankVec::len - index`) time.
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), InsertError<T>> {
+        if index > self.len() { return Err(InsertError::OutOfBounds(element)) }
+        if self.try_reserve(1).is_err() { return Err(InsertError::Full(element)) }
 
+        unsafe { self.raw_insert(index, element) }
+        self.record_len();
+        Ok(())
     }
 
     /// Removes the last element of the bank and returns it, or None if it is empty.
@@ -665,15 +1261,9 @@ impl<T, const C: usize> BankVec<T, C> {
     /// 
     /// # Time Complexity
     /// 
-    /// Takes *O*(1) time but this time will be faster or slower depending on 
-    /// whether the capacity has exceeded `C`.
+    /// Takes *O*(1) time.
     #[inline]
-    pub fn pop(&mut self) -> Option<T> {
-        let (ptr, len, _) = self.data_buf_mut();
-        if *len == 0 { return None }
-        *len -= 1;
-        Some(unsafe { ptr.add(*len).read() })
-    }
+    pub fn pop(&mut self) -> Option<T> { self.raw_pop() }
 
     /// Removes and returns the element at position `index` within the bank, 
     /// shifting all elements after it to the left.
@@ -685,6 +1275,7 @@ impl<T, const C: usize> BankVec<T, C> {
     /// # Panics
     /// 
     /// Panics if the `index` is out of bounds.
+    /// For a panic-free `remove`, see [`try_remove`](BankVec::try_remove).
     /// 
     /// # Examples
     /// 
@@ -696,14 +1287,11 @@ impl<T, const C: usize> BankVec<T, C> {
     /// assert_eq!(bank, [1, 3]);
     /// ```    
     /// 
+    #[track_caller]
     pub fn remove(&mut self, index: usize) -> T {
-        let (ptr, len, _) = self.data_buf_mut();
-        assert!(index < *len, "index out of bounds");
-        *len -= 1;
-        let ptr = unsafe { ptr.as_ptr().add(index) };
-        let removed = unsafe { ptr.read() };
-        unsafe { ptr.copy_from(ptr.add(1), *len - index) }
-        removed
+        let len = self.len();
+        assert!(index < len, "removal index (is {index}) should be < len (is {len})");
+        unsafe { self.raw_remove(index) }
     }
 
     /// Removes an element from the bank and returns it.
@@ -714,7 +1302,8 @@ impl<T, const C: usize> BankVec<T, C> {
     /// 
     /// # Panics
     /// 
-    /// Panics if the `index` is out of bounds
+    /// Panics if the `index` is out of bounds.
+    /// For a panic-free `swap_remove`, see [`try_swap_remove`](BankVec::try_swap_remove).
     /// 
     /// # Examples
     /// 
@@ -727,12 +1316,92 @@ impl<T, const C: usize> BankVec<T, C> {
     /// ```
     ///     
     #[inline]
+    #[track_caller]
     pub fn swap_remove(&mut self, index: usize) -> T {
-        let (ptr, len, _) = self.data_buf_mut();
-        assert!(index < *len, "index out of bounds");
-        *len -= 1;
+        let len = self.len();
+        assert!(index < len, "swap_remove index (is {index}) should be < len (is {len})");
+        unsafe { self.raw_swap_remove(index) }
+    }
 
-        unsafe { ptr.add(index).replace(ptr.add(*len).read()) }
+    /// Removes and returns the element at position `index`, shifting all elements
+    /// after it to the left, or returns `None` if `index` is out of bounds.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut bank = BankVec::<i32, 3>::from([1, 2, 3]);
+    /// assert_eq!(bank.try_remove(1), Some(2));
+    /// assert_eq!(bank.try_remove(2), None);
+    /// assert_eq!(bank, [1, 3]);
+    /// ```
+    #[inline]
+    pub fn try_remove(&mut self, index: usize) -> Option<T> {
+        (index < self.len()).then(|| unsafe { self.raw_remove(index) })
+    }
+
+    /// Removes an element from the bank and returns it, replacing it with the 
+    /// last element, or returns `None` if `index` is out of bounds.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut bank = BankVec::<i32, 4>::from([1, 2, 3, 4]);
+    /// assert_eq!(bank.try_swap_remove(0), Some(1));
+    /// assert_eq!(bank.try_swap_remove(3), None);
+    /// assert_eq!(bank, [4, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn try_swap_remove(&mut self, index: usize) -> Option<T> {
+        (index < self.len()).then(|| unsafe { self.raw_swap_remove(index) })
+    }
+
+    /// Replaces the element at position `index` with `value`, returning the
+    /// element it replaced.  No other element moves.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index` is out of bounds.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut bank = BankVec::<&str, 2>::from(["a", "b", "c"]);
+    /// assert_eq!(bank.replace(1, "x"), "b");
+    /// assert_eq!(bank, ["a", "x", "c"]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn replace(&mut self, index: usize, value: T) -> T {
+        let len = self.len();
+        assert!(index < len, "replace index (is {index}) should be < len (is {len})");
+        unsafe { mem::replace(self.get_unchecked_mut(index), value) }
+    }
+
+    /// Replaces the element at position `index` with `value`, returning the
+    /// element it replaced, or handing `value` back if `index` is out of bounds.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut bank = BankVec::<&str, 2>::from(["a", "b", "c"]);
+    /// assert_eq!(bank.try_replace(1, "x"), Ok("b"));
+    /// assert_eq!(bank.try_replace(3, "y"), Err("y"));
+    /// assert_eq!(bank, ["a", "x", "c"]);
+    /// ```
+    #[inline]
+    pub fn try_replace(&mut self, index: usize, value: T) -> Result<T, T> {
+        match self.get_mut(index) {
+            Some(slot) => Ok(mem::replace(slot, value)),
+            None => Err(value),
+        }
     }
 
 
@@ -775,33 +1444,367 @@ impl<T, const C: usize> BankVec<T, C> {
         unsafe { slice::from_raw_parts_mut(ptr.as_ptr(), len)}
     }
 
+    /// Splits the bank's elements into arrays of `K`, starting at the front,
+    /// and the remainder of fewer than `K`.
+    /// 
+    /// The same as the slice method of the same name, which needs Rust 1.88.
+    /// `K` must be non-zero, which is checked at compile time.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let bank = BankVec::<u8, 8>::from([1, 2, 3, 4, 5]);
+    /// let (chunks, rest) = bank.as_chunks::<2>();
+    /// assert_eq!(chunks, [[1, 2], [3, 4]]);
+    /// assert_eq!(rest, [5]);
+    /// ```
+    #[inline]
+    pub fn as_chunks<const K: usize>(&self) -> (&[[T; K]], &[T]) {
+        chunks::as_chunks(self.as_slice())
+    }
+
+    /// Splits the bank's elements into mutable arrays of `K`, starting at the
+    /// front, and the remainder of fewer than `K`.
+    /// 
+    /// `K` must be non-zero, which is checked at compile time.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut bank = BankVec::<u8, 8>::from([1, 2, 3, 4, 5]);
+    /// let (chunks, _) = bank.as_chunks_mut::<2>();
+    /// chunks.iter_mut().for_each(|chunk| chunk.reverse());
+    /// assert_eq!(bank, [2, 1, 4, 3, 5]);
+    /// ```
+    #[inline]
+    pub fn as_chunks_mut<const K: usize>(&mut self) -> (&mut [[T; K]], &mut [T]) {
+        chunks::as_chunks_mut(self.as_mut_slice())
+    }
+
+    /// Removes the elements in `range` from the bank and returns a double-ended
+    /// iterator over them.
+    /// 
+    /// If the iterator is dropped before being fully consumed, it drops the
+    /// remaining elements.  If it's leaked instead, the bank is left holding only
+    /// the elements before the range.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the starting point is greater than the end point or if the end
+    /// point is greater than the length of the bank.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut bank = BankVec::<i32, 2>::from([1, 2, 3, 4]);
+    /// let drained: Vec<_> = bank.drain(1..3).collect();
+    /// assert_eq!(drained, [2, 3]);
+    /// assert_eq!(bank, [1, 4]);
+    /// ```
+    #[track_caller]
     pub fn drain<R>(&mut self, range: R) -> drain::Drain<'_, T, Self> 
     where 
         R: ops::RangeBounds<usize>,
     {
-        // This implementation was pulled from `Vec::drain`
+        let range = drain::slice_range(range, ..self.len());
+        self.raw_drain(range)
+    }
 
-        let (ptr, len, _) = self.data_buf_mut();
+    /// Removes the elements in `range` from the bank and returns a double-ended
+    /// iterator over them, or returns `None` if the range is out of bounds.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut bank = BankVec::<i32, 2>::from([1, 2, 3, 4]);
+    /// assert!(bank.try_drain(2..5).is_none());
+    /// 
+    /// let drained: Vec<_> = bank.try_drain(1..3).unwrap().collect();
+    /// assert_eq!(drained, [2, 3]);
+    /// assert_eq!(bank, [1, 4]);
+    /// ```
+    pub fn try_drain<R>(&mut self, range: R) -> Option<drain::Drain<'_, T, Self>>
+    where 
+        R: ops::RangeBounds<usize>,
+    {
+        let range = drain::try_slice_range(range, ..self.len())?;
+        Some(self.raw_drain(range))
+    }
 
-        let ptr = ptr.as_ptr();
-        let cp_len = *len;
-        let ops::Range { start, end } = drain::slice_range(range, ..cp_len);
-
-        unsafe {
-            *len = start;
-            drain::Drain {
-                tail_start: end,
-                tail_len: cp_len - end,
-                iter: slice::from_raw_parts(ptr.add(start), end - start).iter(),
-                bank: NonNull::new_unchecked(self)
-            }
-        }
+    /// Removes all elements from the bank, yielding them in order as fixed-size
+    /// [`BankArr<T, K>`](crate::BankArr) chunks.  The last chunk holds the remainder and
+    /// may be partial.
+    /// 
+    /// If the iterator is dropped before being fully consumed, it drops the
+    /// remaining elements.  `K` must be non-zero, which is checked at compile time.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::{BankVec};
+    /// 
+    /// let mut bank = BankVec::<i32, 2>::from([1, 2, 3, 4, 5]);
+    /// let mut chunks = bank.drain_chunks::<2>();
+    /// 
+    /// assert_eq!(chunks.next().unwrap(), [1, 2]);
+    /// assert_eq!(chunks.next().unwrap(), [3, 4]);
+    /// assert_eq!(chunks.next().unwrap(), [5]);
+    /// assert!(chunks.next().is_none());
+    /// # drop(chunks);
+    /// assert!(bank.is_empty());
+    /// ```
+    #[inline]
+    pub fn drain_chunks<const K: usize>(&mut self) -> drain::DrainChunks<'_, T, Self, K> {
+        drain::DrainChunks::new(self.drain(..))
+    }
+
+    /// Moves the elements in `range` onto the back of `dst` in one pass,
+    /// returning how many were moved.
+    /// 
+    /// A [`BankVec`](crate::BankVec) destination grows to take every element.
+    /// A fixed-size destination such as a [`BankArr`](crate::BankArr) takes as
+    /// many as it has room for, from the front of the range, and the rest are
+    /// left in this bank.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the starting point is greater than the end point or if the end
+    /// point is greater than the length of the bank.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::{BankArr, BankVec};
+    /// 
+    /// let mut bank = BankVec::<i32, 2>::from([1, 2, 3, 4]);
+    /// let mut full = BankArr::<i32, 3>::from([0]);
+    /// assert_eq!(bank.drain_into(1.., &mut full), 2);
+    /// assert_eq!((bank.as_slice(), full.as_slice()), (&[1, 4][..], &[0, 2, 3][..]));
+    /// 
+    /// let mut spilling = BankVec::<i32, 1>::new();
+    /// assert_eq!(bank.drain_into(.., &mut spilling), 2);
+    /// assert_eq!((bank.as_slice(), spilling.as_slice()), (&[][..], &[1, 4][..]));
+    /// ```
+    #[track_caller]
+    pub fn drain_into<R, D>(&mut self, range: R, dst: &mut D) -> usize
+    where
+        R: ops::RangeBounds<usize>,
+        D: DrainTarget<T>,
+    {
+        let range = drain::slice_range(range, ..self.len());
+        self.raw_drain_into(range, dst)
+    }
+
+    /// Moves the elements in `range` onto the back of `dst` as
+    /// [`drain_into`](Self::drain_into) does, or returns `None` if the range is
+    /// out of bounds.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut bank = BankVec::<i32, 2>::from([1, 2, 3, 4]);
+    /// let mut dst = BankVec::<i32, 4>::new();
+    /// assert_eq!(bank.try_drain_into(2..5, &mut dst), None);
+    /// assert_eq!(bank.try_drain_into(1..3, &mut dst), Some(2));
+    /// assert_eq!((bank.as_slice(), dst.as_slice()), (&[1, 4][..], &[2, 3][..]));
+    /// ```
+    pub fn try_drain_into<R, D>(&mut self, range: R, dst: &mut D) -> Option<usize>
+    where
+        R: ops::RangeBounds<usize>,
+        D: DrainTarget<T>,
+    {
+        let range = drain::try_slice_range(range, ..self.len())?;
+        Some(self.raw_drain_into(range, dst))
     }
 }
 
 
+impl<T: Copy, const C: usize> BankVec<T, C> {
+
+    /// Creates a bank from a slice of `Copy` elements, choosing inline or heap
+    /// storage once and copying them over with a single `memcpy`.
+    /// 
+    /// A slice longer than `C` is copied into a heap buffer of exactly its
+    /// length.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let bank = BankVec::<u8, 4>::from_slice(b"bank");
+    /// assert!(!bank.on_heap());
+    /// 
+    /// let bank = BankVec::<u8, 4>::from_slice(b"bankarr");
+    /// assert_eq!(bank.capacity(), 7);
+    /// assert_eq!(bank, *b"bankarr");
+    /// ```
+    pub fn from_slice(slice: &[T]) -> Self {
+        let mut bank = Self::new();
+        bank.reserve_exact(slice.len());
+        unsafe { bank.raw_extend_from_slice(slice) }
+        bank.record_len();
+        bank
+    }
+
+    /// Copies all elements of `other` onto the back of the bank.
+    /// 
+    /// Reserves space once and then performs a single `memcpy` since `T` is
+    /// `Copy`.  Will reallocate onto the heap if necessary.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut bank = BankVec::<u8, 2>::from([1]);
+    /// bank.extend_from_slice(&[2, 3, 4]);
+    /// assert!(bank.on_heap());
+    /// assert_eq!(bank, [1, 2, 3, 4]);
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(`other.len()`) time, plus *O*(`len`) if the bank must
+    /// reallocate.
+    #[inline]
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        let count = other.len();
+        self.reserve(count);
+        unsafe { self.raw_extend_from_slice(other) }
+        self.record_len();
+    }
+
+    /// Attempts to copy all elements of `other` onto the back of the bank, 
+    /// returning an error instead of panicking or aborting if growing the bank
+    /// fails.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut bank = BankVec::<u8, 2>::from([1]);
+    /// assert!(bank.try_extend_from_slice(&[2, 3, 4]).is_ok());
+    /// assert_eq!(bank, [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), AllocErr> {
+        self.try_reserve(other.len())?;
+        unsafe { self.raw_extend_from_slice(other) }
+        self.record_len();
+        Ok(())
+    }
+}
+
+impl<T: Default, const C: usize> BankVec<T, C> {
+
+    /// Takes the element at position `index`, leaving `T::default()` in its
+    /// place.  No other element moves.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index` is out of bounds.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut bank = BankVec::<String, 2>::from(["a", "b", "c"].map(String::from));
+    /// assert_eq!(bank.take(0), "a");
+    /// assert_eq!(bank.as_slice(), ["", "b", "c"]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn take(&mut self, index: usize) -> T { self.replace(index, T::default()) }
+
+    /// Takes the element at position `index`, leaving `T::default()` in its
+    /// place, or returns `None` if `index` is out of bounds.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut bank = BankVec::<String, 2>::from(["a", "b", "c"].map(String::from));
+    /// assert_eq!(bank.try_take(0).as_deref(), Some("a"));
+    /// assert_eq!(bank.try_take(3), None);
+    /// assert_eq!(bank.as_slice(), ["", "b", "c"]);
+    /// ```
+    #[inline]
+    pub fn try_take(&mut self, index: usize) -> Option<T> {
+        self.get_mut(index).map(mem::take)
+    }
+}
+
+impl<T: Ord, const C: usize> BankVec<T, C> {
+
+    /// Sorts the bank without preserving the order of equal elements, using a
+    /// fixed sorting network when it holds at most 16 elements.
+    /// 
+    /// For the handful of elements banks are designed around, a network beats
+    /// the setup cost of [`slice::sort_unstable`], which longer banks fall
+    /// back to.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut bank = BankVec::<i32, 4>::from([5, -1, 3, 0, 3]);
+    /// bank.sort_small();
+    /// assert_eq!(bank, [-1, 0, 3, 3, 5]);
+    /// ```
+    #[inline]
+    pub fn sort_small(&mut self) {
+        sort::sort_small(self.as_mut_slice())
+    }
+}
+
 impl<T: PartialEq, const C: usize> BankVec<T, C> {
 
+    /// Returns the index of the first element equal to `value`, or `None` if
+    /// there is none.
+    /// 
+    /// With the `simd` feature enabled, banks of primitive integers are
+    /// searched a vector at a time.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let bank = BankVec::<i32, 4>::from([1, 2, 3, 2]);
+    /// assert_eq!(bank.position(&2), Some(1));
+    /// assert_eq!(bank.position(&5), None);
+    /// ```
+    #[inline]
+    pub fn position(&self, value: &T) -> Option<usize> {
+        spec::find(self.as_slice(), value)
+    }
+
+    /// Returns `true` if the bank contains an element equal to `value`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let bank = BankVec::<i32, 4>::from([1, 2, 3]);
+    /// assert!(bank.contains(&2));
+    /// assert!(!bank.contains(&5));
+    /// ```
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool { self.position(value).is_some() }
+
     /// Removes the item from the bank and returns true if the item existed,
     /// otherwise returns false.
     /// 
@@ -820,28 +1823,20 @@ impl<T: PartialEq, const C: usize> BankVec<T, C> {
     /// 
     /// assert_eq!(bank, [1, 4, 3]);
     /// ```
-    ///
+    /// 
     #[inline]
     pub fn remove_item(&mut self, value: &T) -> bool {
-        let (ptr, len, _) = self.data_buf_mut();
-        unsafe {
-            for index in 0usize..*len {
-                let cp_ptr = ptr.add(index);
-                if cp_ptr.as_ref() == value {
-                    *len -= 1;
-                    cp_ptr.replace(ptr.add(*len).read());
-                    return true
-                }                
-            }
+        match self.position(value) {
+            Some(index) => { self.swap_remove(index); true }
+            None => false
         }
-
-        false
     }
 }
 
 #[cfg(test)]
 mod tests {
     use std::array;
+    use crate::BankArr;
     use super::*;
 
     type B = BankVec<u32, 3>;
@@ -856,6 +1851,228 @@ mod tests {
 
     }
 
+    #[test]
+    fn drop_frees_after_panic() {
+        struct Bomb(bool);
+        impl Drop for Bomb {
+            fn drop(&mut self) { if self.0 { panic!("boom") } }
+        }
+
+        let bank = BankVec::<Bomb, 1>::from([Bomb(false), Bomb(true), Bomb(false)]);
+        assert!(std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| drop(bank))).is_err());
+
+        // A spilled bank of zero-sized elements never allocated its buffer
+        drop(BankVec::<(), 2>::from_vec_keep_alloc(vec![(); 5]));
+    }
+
+    #[test]
+    fn auto_traits() {
+        use std::{cell::Cell, marker::PhantomPinned, panic::{self, RefUnwindSafe, UnwindSafe}};
+
+        fn unwind_safe<T: UnwindSafe>() {}
+        fn ref_unwind_safe<T: RefUnwindSafe>() {}
+        fn unpin<T: Unpin>() {}
+
+        unwind_safe::<BankVec<Cell<i32>, 2>>();
+        ref_unwind_safe::<BankVec<String, 2>>();
+        unpin::<BankVec<PhantomPinned, 2>>();
+
+        // Inline and spilled banks alike cross `catch_unwind` without a wrapper
+        for bank in [BankVec::<Cell<i32>, 2>::from([Cell::new(1)]), BankVec::from([0, 1, 2].map(Cell::new))] {
+            let len = bank.len();
+            assert_eq!(panic::catch_unwind(move || bank.len()).ok(), Some(len));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "may-dangle")]
+    fn may_dangle() {
+        // Declared first, so it's dropped after the strings it borrows
+        let mut bank = BankVec::<&mut String, 1>::new();
+        let (mut a, mut b) = ("a".to_string(), "b".to_string());
+        bank.push(&mut a);
+        bank.push(&mut b);
+        bank.iter_mut().for_each(|s| s.push('!'));
+        assert_eq!(bank.len(), 2);
+    }
+
+    #[test]
+    fn zst_spill() {
+        let mut bank = BankVec::<(), 4>::new();
+        bank.extend(std::iter::repeat_n((), 1 << 20));
+        assert!(bank.on_heap());
+        bank.insert(2, ());
+        assert_eq!(bank.len(), (1 << 20) + 1);
+
+        assert_eq!(bank.drain(1..).rev().count(), 1 << 20);
+        bank.push(());
+        bank.shrink_to_fit();
+        assert!(!bank.on_heap());
+        assert_eq!(bank.into_iter().count(), 2);
+    }
+
+    #[test]
+    fn clone_panic() {
+        // A panicking clone drops the clones made before it, and frees the
+        // heap buffer they were written to
+        #[derive(Debug)]
+        struct Fussy(String);
+        impl Clone for Fussy {
+            fn clone(&self) -> Self {
+                assert!(self.0 != "c", "won't clone");
+                Fussy(self.0.clone())
+            }
+        }
+
+        let bank = BankVec::<Fussy, 2>::from(["a", "b", "c"].map(|s| Fussy(s.to_string())));
+        assert!(std::panic::catch_unwind(|| bank.clone()).is_err());
+    }
+
+    #[test]
+    fn from_slice() {
+        let bank = BankVec::<u32, 4>::from_slice(&[1, 2, 3]);
+        assert_eq!((bank.on_heap(), bank.as_slice()), (false, &[1, 2, 3][..]));
+
+        let bank = BankVec::<u32, 2>::from_slice(&[1, 2, 3, 4, 5]);
+        assert_eq!((bank.capacity(), bank.as_slice()), (5, &[1, 2, 3, 4, 5][..]));
+
+        let words = ["a", "b", "c"].map(String::from);
+        let bank = BankVec::<String, 4>::from(&words[1..]);
+        assert!(!bank.on_heap());
+        assert_eq!(bank, words[1..]);
+    }
+
+    #[test]
+    fn from_array_exact() {
+        let bank = BankVec::<u8, 4>::from([7; 5]);
+        assert_eq!(bank.capacity(), 5);
+        assert_eq!(bank, [7; 5]);
+
+        let bank = BankVec::<u8, 4>::from(vec![7; 5]);
+        assert_eq!(bank.capacity(), 5);
+    }
+
+    #[test]
+    fn from_vec_keep_alloc() {
+        let mut vec = Vec::with_capacity(16);
+        vec.push("kept".to_string());
+        let ptr = vec.as_ptr();
+
+        let mut bank = BankVec::<String, 2>::from_vec_keep_alloc(vec);
+        assert_eq!((bank.as_ptr(), bank.capacity()), (ptr, 16));
+        bank.extend(["a", "b", "c"].map(String::from));
+        assert_eq!(bank.as_ptr(), ptr);
+
+        let bank = BankVec::<u8, 4>::from_vec_keep_alloc(Vec::with_capacity(3));
+        assert!(!bank.on_heap());
+    }
+
+    #[test]
+    fn into_vec() {
+        let bank = BankVec::<String, 2>::from(["aa".to_string()]);
+        let vec = Vec::from(bank);
+        assert_eq!(vec, ["aa".to_string()]);
+
+        let mut bank = BankVec::<String, 2>::from(["aa".to_string(), "bb".to_string()]);
+        bank.reserve_exact(8);
+        let ptr = bank.as_ptr();
+        let vec = Vec::from(bank);
+        assert_eq!(vec, ["aa".to_string(), "bb".to_string()]);
+        assert_eq!(vec.as_ptr(), ptr);
+        assert_eq!(vec.capacity(), 10);
+    }
+
+    #[test]
+    fn replace_take() {
+        let mut bank = BankVec::<String, 2>::from(["a", "b", "c"].map(String::from));
+        assert_eq!(bank.replace(2, "d".to_string()), "c");
+        assert_eq!(bank.take(1), "b");
+        assert_eq!(bank.as_slice(), ["a", "", "d"]);
+
+        let result = std::panic::catch_unwind(move || bank.replace(3, String::new()));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn try_replace_take() {
+        let mut bank = BankVec::<String, 2>::from(["a", "b", "c"].map(String::from));
+        assert_eq!(bank.try_replace(2, "d".to_string()), Ok("c".to_string()));
+        assert_eq!(bank.try_replace(3, "e".to_string()), Err("e".to_string()));
+        assert_eq!(bank.try_take(1).as_deref(), Some("b"));
+        assert_eq!(bank.try_take(3), None);
+        assert_eq!(bank.as_slice(), ["a", "", "d"]);
+    }
+
+    // Compiles only while the bank and its iterator are covariant in `T`
+    #[test]
+    fn covariant() {
+        let local = String::from("d");
+        let mut short = BankVec::<&'static str, 2>::from(["a", "b", "c"]);
+        short.push(&local);
+        assert_eq!(short, ["a", "b", "c", "d"]);
+
+        let mut iter = BankVec::<&'static str, 2>::from(["e"]).into_iter();
+        assert_eq!(iter.next(), Some("e"));
+        iter = short.into_iter();
+        assert_eq!(iter.next_back(), Some("d"));
+    }
+
+    #[test]
+    fn drain_into() {
+        let mut bank = BankVec::<String, 2>::from(["a", "b", "c", "d"].map(String::from));
+
+        let mut dst = BankVec::<String, 2>::from(["x".to_string()]);
+        assert_eq!(bank.drain_into(1..3, &mut dst), 2);
+        assert_eq!(bank.as_slice(), ["a", "d"]);
+        assert_eq!(dst.as_slice(), ["x", "b", "c"]);
+
+        let mut full = BankArr::<String, 1>::from(["y".to_string()]);
+        assert_eq!(dst.drain_into(.., &mut full), 0);
+        assert_eq!(dst.len(), 3);
+    }
+
+    #[test]
+    fn try_drain_into() {
+        let mut bank = BankVec::<String, 2>::from(["a", "b", "c"].map(String::from));
+        let mut dst = BankVec::<String, 1>::new();
+
+        assert_eq!(bank.try_drain_into(..=3, &mut dst), None);
+        assert_eq!(bank.len(), 3);
+
+        assert_eq!(bank.try_drain_into(1.., &mut dst), Some(2));
+        assert_eq!(bank.as_slice(), ["a"]);
+        assert_eq!(dst.as_slice(), ["b", "c"]);
+    }
+
+    #[test]
+    fn try_into_array() {
+        let bank = BankVec::<String, 2>::from(["a", "b"].map(String::from));
+        let bank = <[String; 3]>::try_from(bank).unwrap_err();
+        assert_eq!(<[String; 2]>::try_from(bank).unwrap(), ["a", "b"]);
+
+        let bank = BankVec::<String, 2>::from(["a", "b", "c"].map(String::from));
+        assert_eq!(<[String; 3]>::try_from(bank).unwrap(), ["a", "b", "c"]);
+        assert_eq!(<[String; 0]>::try_from(BankVec::<String, 2>::new()), Ok([]));
+    }
+
+    #[test]
+    fn from_cow() {
+        let bank = BankVec::<String, 2>::from(Cow::Borrowed(&["aa".to_string()][..]));
+        assert!(!bank.on_heap());
+        assert_eq!(bank, ["aa".to_string()]);
+
+        let bank = BankVec::<i32, 2>::from(Cow::Borrowed(&[1, 2, 3][..]));
+        assert!(bank.on_heap());
+        assert_eq!(bank.capacity(), 3);
+        assert_eq!(bank, [1, 2, 3]);
+
+        let bank = BankVec::<i32, 2>::from(Cow::Owned(vec![1, 2, 3]));
+        assert_eq!(bank, [1, 2, 3]);
+
+        let cow: Cow<'_, [i32]> = (&bank).into();
+        assert!(matches!(cow, Cow::Borrowed([1, 2, 3])));
+    }
+
     #[test]
     fn from_arr() {
         let bank = BankVec::<i32, 4>::from([1, 2, 3, 4]);
@@ -905,7 +2122,7 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
+    #[should_panic(expected = "insertion index (is 4) should be <= len (is 3)")]
     fn insert_out_of_bounds() {
         let mut bank = B::from([3, 4, 5]);
 
@@ -924,6 +2141,91 @@ mod tests {
 
     }
 
+    #[test]
+    fn insert_spilling() {
+        for index in 0..=3 {
+            let mut bank = BankVec::<String, 3>::from(["a", "b", "c"].map(String::from));
+            bank.insert(index, "x".to_string());
+
+            let mut expected = vec!["a", "b", "c"];
+            expected.insert(index, "x");
+            assert_eq!(bank.as_slice(), expected);
+            assert_eq!(bank.capacity(), 4);
+        }
+
+        let mut bank = BankVec::<u8, 0>::new();
+        bank.insert(0, 1);
+        assert_eq!((bank.as_slice(), bank.capacity()), (&[1][..], 1));
+    }
+
+    #[test]
+    fn shrink_to_fit() {
+        let mut bank = BankVec::<String, 2>::from(["a", "b", "c", "d"].map(String::from));
+        bank.truncate(3);
+        bank.shrink_to_fit();
+        assert!(bank.on_heap());
+        assert_eq!(bank.capacity(), 3);
+
+        bank.truncate(1);
+        bank.shrink_to_fit();
+        assert!(!bank.on_heap());
+        assert_eq!(bank.len(), 1);
+        bank.push("e".to_string());
+        assert_eq!(bank, ["a".to_string(), "e".to_string()]);
+
+        bank.clear();
+        assert!(bank.is_empty());
+    }
+
+    #[test]
+    fn try_insert() {
+        let mut bank = B::from([3, 5, 6]);
+
+        assert_eq!(bank.try_insert(4, 0), Err(InsertError::OutOfBounds(0)));
+        assert!(!bank.on_heap());
+        assert_eq!(bank.try_insert(1, 4), Ok(()));
+        assert!(bank.on_heap());
+        assert_eq!(bank, [3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn try_push() {
+        let mut bank = B::from([1, 2, 3]);
+
+        assert_eq!(bank.try_push(4), Ok(()));
+        assert_eq!(bank.try_extend_from_slice(&[5, 6]), Ok(()));
+        assert_eq!(bank, [1, 2, 3, 4, 5, 6]);
+        assert_eq!(bank.try_reserve(usize::MAX), Err(AllocErr::Overflow));
+    }
+
+    #[test]
+    fn try_push_full() {
+        #[derive(Debug, PartialEq)]
+        struct Token;
+
+        // A vec of zero-sized elements reports a capacity of `usize::MAX`, so
+        // the bank can be filled without allocating anything
+        let mut bank = BankVec::<Token, 2>::from_vec_keep_alloc(Vec::new());
+        assert_eq!(bank.capacity(), usize::MAX);
+        unsafe { bank.set_len(usize::MAX) };
+
+        assert_eq!(bank.try_push(Token).unwrap_err().element(), Token);
+        assert_eq!(bank.len(), usize::MAX);
+    }
+
+    #[test]
+    fn try_remove() {
+        let mut bank = B::from([3, 4, 5, 6]);
+
+        assert_eq!(bank.try_remove(4), None);
+        assert_eq!(bank.try_swap_remove(4), None);
+        assert_eq!(bank.try_remove(0), Some(3));
+        assert_eq!(bank.try_swap_remove(0), Some(4));
+        assert_eq!(bank, [6, 5]);
+        assert!(bank.try_drain(..3).is_none());
+        assert_eq!(bank.try_drain(..).unwrap().collect::<Vec<_>>(), [6, 5]);
+    }
+
     #[test]
     fn pop() {
         let mut bank = B::from([3, 4, 5, 6]);
@@ -935,6 +2237,20 @@ mod tests {
         //assert_eq!(bank.pop(), Some(5))
     }
 
+    #[test]
+    fn pop_inline() {
+        let mut bank = B::from([1, 2]);
+        assert_eq!(bank.pop(), Some(2));
+        assert_eq!(bank.pop(), Some(1));
+        assert_eq!(bank.pop(), None);
+
+        let mut bank = BankVec::<String, 0>::new();
+        bank.push("spilled".to_string());
+        assert!(bank.on_heap());
+        assert_eq!(bank.pop().as_deref(), Some("spilled"));
+        assert_eq!(bank.pop(), None);
+    }
+
     #[test]
     fn remove() {
         let mut bank = B::from([3, 4, 5, 6]);
@@ -983,11 +2299,103 @@ mod tests {
     fn extend() {
         let mut bank = BankVec::<i32, 4>::new();
         let arr: [i32; 8] = array::from_fn(|idx| idx as i32);
-        bank.extend(arr.clone());
+        bank.extend(arr);
 
         assert_eq!(bank, arr);
     }
 
+    #[test]
+    fn extend_from_slice() {
+        let mut bank = BankVec::<i32, 4>::from([1]);
+        bank.extend_from_slice(&[2, 3]);
+        assert!(!bank.on_heap());
+        bank.extend_from_slice(&[4, 5, 6]);
+        assert!(bank.on_heap());
+        assert_eq!(bank, [1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn extend_reserves() {
+        let mut reserved = BankVec::<i32, 2>::new();
+        reserved.reserve(100);
+
+        // An exact size hint is reserved in one go
+        let mut bank = BankVec::<i32, 2>::from([0]);
+        bank.extend(1..101);
+        assert_eq!(bank.capacity(), reserved.capacity());
+        assert!(bank.iter().copied().eq(0..101));
+
+        // A lower bound of zero still grows past the inline capacity
+        let mut bank = BankVec::<i32, 2>::new();
+        bank.extend((0..50).filter(|n| n % 2 == 0));
+        assert_eq!(bank.len(), 25);
+        assert!(bank.iter().copied().eq((0..50).step_by(2)));
+    }
+
+    #[test]
+    fn clone_exact() {
+        let mut bank = BankVec::<u16, 4>::new();
+        bank.extend(0..1025);
+        let cloned = bank.clone();
+        assert_eq!(cloned, bank);
+        assert_eq!(cloned.capacity(), 1025);
+
+        let mut bank = BankVec::<String, 2>::from(["a", "b", "c"].map(String::from));
+        bank.pop();
+        let cloned = bank.clone();
+        assert!(!cloned.on_heap());
+        assert_eq!(cloned.as_slice(), ["a", "b"]);
+    }
+
+    #[test]
+    fn from_iter() {
+        let bank: BankVec<String, 2> = ["a", "b", "c"].into_iter().map(String::from).collect();
+        assert_eq!((bank.capacity(), bank.as_slice()), (3, ["a", "b", "c"].map(String::from).as_slice()));
+
+        let bank: BankVec<i32, 4> = (0..10).filter(|x| x % 4 == 0).collect();
+        assert!(!bank.on_heap());
+        assert_eq!(bank, [0, 4, 8]);
+
+        let bank: BankVec<i32, 2> = (0..10).filter(|x| x % 2 == 0).collect();
+        assert_eq!(bank, [0, 2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn extend_bulk() {
+        let mut bank = BankVec::<u8, 4>::new();
+        bank.extend([1, 2]);
+        bank.extend(&[3, 4]);
+        assert!(!bank.on_heap());
+        bank.extend(vec![5, 6]);
+        bank.extend([7, 8].iter());
+        assert_eq!(bank, [1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn extend_ref() {
+        let mut bank = BankVec::<i32, 2>::from([1]);
+        bank.extend([2].iter());
+        assert!(!bank.on_heap());
+        bank.extend(&[3, 4]);
+        assert!(bank.on_heap());
+        assert_eq!(bank, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn add() {
+        let bank = BankVec::<String, 2>::from(["aa".to_string()]);
+        let bank = bank + &["bb".to_string()][..];
+        assert!(!bank.on_heap());
+        let bank = bank + BankVec::<String, 4>::from(["cc".to_string()]);
+        assert!(bank.on_heap());
+        assert_eq!(bank, ["aa".to_string(), "bb".to_string(), "cc".to_string()]);
+
+        let mut bank = BankVec::<i32, 2>::new();
+        bank += &[1, 2][..];
+        bank += BankVec::<i32, 2>::from([3]);
+        assert_eq!(bank, [1, 2, 3]);
+    }
+
     #[test]
     fn iter() {
         let mut bank = BankVec::<&'static str, 3>::from(["a", "b", "c"]);
@@ -1011,7 +2419,7 @@ mod tests {
         let r = &mut bank;
         for v in r { *v *= 2 }
         let r = &bank;
-        let out = r.into_iter().map(|v| *v).collect::<Vec<_>>();
+        let out = r.into_iter().copied().collect::<Vec<_>>();
         assert_eq!(out, [2, 4, 6]);
     }
 
@@ -1057,6 +2465,16 @@ mod tests {
         assert_eq!(bank.as_mut_slice(), [3, 4, 5, 6]);
     }
 
+    #[test]
+    fn debug() {
+        let mut bank = BankVec::<i32, 4>::from([1, 2]);
+        assert_eq!(format!("{bank:?}"), "BankVec<T, 4> { buf (Array): [1, 2], capacity: 4 }");
+        assert!(format!("{bank:#?}").contains("inline_headroom: 2"));
+
+        bank.extend([3, 4, 5]);
+        assert!(format!("{bank:#?}").contains("on_heap: true"));
+    }
+
     #[test]
     fn clone() {
         let bankarr = B::new();
@@ -1065,12 +2483,18 @@ mod tests {
         assert!(bankarr == bankarr.clone());
         assert!(bankvec == bankvec.clone());
         assert!(bankvec != bankarr);
+
+        let strings = ["aa".to_string(), "bb".to_string()];
+        let bankarr = BankVec::<String, 2>::from(strings.clone());
+        let bankvec = BankVec::<String, 1>::from(strings);
+        assert_eq!(bankarr, bankarr.clone());
+        assert_eq!(bankvec, bankvec.clone());
     }
 
     #[test]
     fn drain() {
         let arr: [i32; 8] = array::from_fn(|idx| idx as i32);
-        let mut bank = BankVec::<i32, 4>::from(arr.clone());
+        let mut bank = BankVec::<i32, 4>::from(arr);
 
         let drained: Vec<i32> = bank.drain(..).collect();
 
@@ -1079,6 +2503,14 @@ mod tests {
         assert_eq!(bank, []);
     }
 
+    #[test]
+    fn concat_join() {
+        let banks = [BankVec::<i32, 2>::from([1, 2, 3]), BankVec::from([4])];
+        assert_eq!(banks.concat(), [1, 2, 3, 4]);
+        assert_eq!(banks.join(&0), [1, 2, 3, 0, 4]);
+        assert_eq!(banks.join(&[0, 0][..]), [1, 2, 3, 0, 0, 4]);
+    }
+
     #[test]
     fn partial_eq() {
         let mut bank = BankVec::<i32, 2>::from([1, 2]);
@@ -1114,6 +2546,31 @@ mod tests {
         assert!(bank.try_reserve_exact(4).is_ok());
     }
 
+    #[test]
+    fn spare_capacity_mut() {
+        // Fills the spare capacity, inline and then on the heap, the same way
+        let mut bank = BankVec::<String, 2>::new();
+        for expected in [2, 2] {
+            let len = bank.len();
+            let spare = bank.spare_capacity_mut();
+            assert_eq!(spare.len(), expected);
+            spare.iter_mut().enumerate().for_each(|(i, slot)| { slot.write((len + i).to_string()); });
+            unsafe { bank.set_len(bank.capacity()) };
+            bank.reserve(2);
+        }
+        assert!(bank.on_heap());
+        assert_eq!(bank.as_slice(), ["0", "1", "2", "3"]);
+        assert_eq!(bank.spare_capacity_mut().len(), bank.capacity() - 4);
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "strict-asserts"))]
+    #[should_panic(expected = "set_len beyond the bank's capacity")]
+    fn set_len_checked() {
+        let mut bank = BankVec::<i32, 3>::from([1, 2]);
+        unsafe { bank.set_len(4) };
+    }
+
     #[test]
     fn set_len() {
         let mut bank = BankVec::<i32, 3>::from([1, 2, 3]);
@@ -1131,6 +2588,17 @@ mod tests {
 
     }
 
+    #[test]
+    fn position() {
+        let mut bank = BankVec::<u16, 4>::from([1, 2, 3]);
+        assert_eq!(bank.position(&3), Some(2));
+        assert!(!bank.contains(&20));
+
+        bank.extend(4..=20);
+        assert_eq!(bank.position(&20), Some(19));
+        assert!(bank.contains(&20));
+    }
+
     #[test]
     fn remove_item() {
         let mut bank = BankVec::<i32, 3>::from([1, 2, 3]);
@@ -7,6 +7,7 @@ use super::{
 };
 
 #[inline]
+#[track_caller]
 pub(super) fn infallible<T>(result: Result<T, AllocErr>) -> T {
     match result {
         Ok(x) => x,
@@ -19,7 +20,10 @@ pub(super) fn infallible<T>(result: Result<T, AllocErr>) -> T {
 #[inline]
 pub(super) unsafe fn deallocate<T>(ptr: NonNull<T>, cap: usize) {
     let layout = Layout::array::<T>(cap).unwrap();
-    unsafe { alloc::dealloc(ptr.as_ptr() as *mut u8, layout) };
+    // Buffers of zero-sized elements are dangling, never allocated
+    if layout.size() != 0 {
+        unsafe { alloc::dealloc(ptr.as_ptr() as *mut u8, layout) };
+    }
 }
 
 #[inline(always)]
@@ -33,30 +37,113 @@ pub(super) fn try_grow<T, const C: usize>(bank: &mut BankVec<T, C>, new_cap: usi
         
         bank.buf = BufferUnion::new_stack();
         unsafe { src.copy_to_nonoverlapping(bank.buf.stack_ptr_nn(), len) }
-        bank.capacity = new_cap;
+        bank.capacity = C;
         unsafe { deallocate(src, cap) };
     } else if new_cap != cap {
-        let layout = Layout::array::<T>(new_cap).map_err(AllocErr::layout)?;
-        debug_assert!(layout.size() > 0);
-
-        let ptr = if !bank.on_heap() {
-            let dst = NonNull::new(unsafe { alloc(layout) })
-                .ok_or(AllocErr::alloc(layout))?.cast();
-            unsafe { src.copy_to_nonoverlapping(dst, len) };
-            
-            dst
-        } else {
+        let heap_cap = bank.on_heap().then_some(cap);
+        let ptr = unsafe { allocate_heap(src, len, heap_cap, new_cap)? };
+        bank.buf = BufferUnion::heap_from(ptr);
+        bank.capacity = new_cap;
+        if heap_cap.is_none() { bank.record_spill() }
+    }
+
+    Ok(())
+}
+
+// Moves the `len` elements at `src` into a heap buffer of `new_cap` elements,
+// reallocating `src` if it's already a heap buffer of `heap_cap` elements.
+// Generic over `T` alone, so every capacity of a bank shares this copy.
+unsafe fn allocate_heap<T>(src: NonNull<T>, len: usize, heap_cap: Option<usize>, new_cap: usize) -> Result<NonNull<T>, AllocErr> {
+    let layout = Layout::array::<T>(new_cap).map_err(AllocErr::layout)?;
+
+    match heap_cap {
+        // Zero-sized elements are never given memory, so there's nothing to realloc
+        Some(_) if layout.size() == 0 => Ok(NonNull::dangling()),
+        None => {
+            let dst = allocate(layout)?;
+            unsafe { copy_spilled(src, dst, len) };
+
+            Ok(dst)
+        }
+        Some(cap) => {
             let prev_layout = Layout::array::<T>(cap).map_err(AllocErr::layout)?;
             let ptr = unsafe { realloc(src.as_ptr().cast(), prev_layout, layout.size()) };
 
-            NonNull::new(ptr).ok_or(AllocErr::alloc(layout))?.cast()
-        };
+            Ok(NonNull::new(ptr).ok_or(AllocErr::alloc(layout))?.cast())
+        }
+    }
+}
 
-        bank.buf = BufferUnion::heap_from(ptr, len);
-        bank.capacity = new_cap;
+// Zero-sized layouts can't be passed to the allocator, but any aligned,
+// non-null pointer is a valid buffer for them.
+fn allocate<T>(layout: Layout) -> Result<NonNull<T>, AllocErr> {
+    if layout.size() == 0 { return Ok(NonNull::dangling()) }
+    NonNull::new(unsafe { alloc(layout) }).map(NonNull::cast).ok_or(AllocErr::alloc(layout))
+}
+
+// Moves a full inline bank onto the heap with `element` inserted at `index`.
+// The elements either side of `index` are copied straight to their places in
+// the new buffer, rather than being moved and then shifted over again.
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(super) fn spill_insert<T, const C: usize>(bank: &mut BankVec<T, C>, index: usize, element: T) {
+    debug_assert!(!bank.on_heap() && bank.len() == C && index <= C);
+    let new_cap = (C + 1).checked_next_power_of_two().expect("allocation: capacity overflow");
+    let layout = infallible(Layout::array::<T>(new_cap).map_err(AllocErr::layout));
+    let dst = infallible(allocate::<T>(layout));
+
+    unsafe {
+        let src = bank.buf.stack_ptr_nn();
+        copy_spilled(src, dst, index);
+        dst.add(index).write(element);
+        copy_spilled(src.add(index), dst.add(index + 1), C - index);
     }
+    bank.buf = BufferUnion::heap_from(dst);
+    (bank.len, bank.capacity) = (C + 1, new_cap);
+    bank.record_spill();
+    bank.record_len();
+}
 
-    Ok(())
+// Copies the `len` elements leaving inline storage into their new heap buffer.
+// Moves are bitwise whatever `T` is, so this is always one bulk copy.
+#[inline]
+unsafe fn copy_spilled<T>(src: NonNull<T>, dst: NonNull<T>, len: usize) {
+    #[cfg(all(feature = "nontemporal", target_arch = "x86_64"))]
+    if len * size_of::<T>() >= nontemporal::THRESHOLD {
+        return unsafe { nontemporal::copy(src.cast(), dst.cast(), len * size_of::<T>()) }
+    }
+    unsafe { src.copy_to_nonoverlapping(dst, len) }
+}
+
+// Spills big enough to overrun the cache are copied with streaming stores,
+// which write around it rather than evicting everything else to make room
+// for a buffer that won't be read again soon.
+#[cfg(all(feature = "nontemporal", target_arch = "x86_64"))]
+mod nontemporal {
+
+    use std::{arch::x86_64::{__m128i, _mm_loadu_si128, _mm_sfence, _mm_stream_si128}, ptr::NonNull};
+
+    pub(super) const THRESHOLD: usize = 256 * 1024;
+
+    // `src` and `dst` must be valid for `bytes` bytes and not overlap
+    pub(super) unsafe fn copy(src: NonNull<u8>, dst: NonNull<u8>, bytes: usize) {
+        // Streaming stores need 16-byte alignment, so the bytes either side
+        // of the aligned run are copied normally
+        let head = dst.align_offset(16).min(bytes);
+        let end = head + (bytes - head) / 16 * 16;
+        unsafe {
+            src.copy_to_nonoverlapping(dst, head);
+            for offset in (head..end).step_by(16) {
+                let chunk = _mm_loadu_si128(src.add(offset).as_ptr().cast::<__m128i>());
+                _mm_stream_si128(dst.add(offset).as_ptr().cast::<__m128i>(), chunk);
+            }
+            // Streaming stores are weakly ordered, so they're fenced before
+            // the buffer is handed back
+            _mm_sfence();
+            src.add(end).copy_to_nonoverlapping(dst.add(end), bytes - end);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -96,25 +183,43 @@ mod tests {
         unsafe { deallocate(ptr, cap) };
     }
 
+    #[test]
+    #[cfg(all(feature = "nontemporal", target_arch = "x86_64"))]
+    fn nontemporal_copy() {
+        let src: Vec<u8> = (0..300).map(|x| x as u8).collect();
+        // Offsets misalign the destination, exercising both unaligned ends
+        for (offset, bytes) in [(0, 0), (1, 15), (3, 64), (5, 277), (0, 288)] {
+            let mut dst = vec![0u8; 300];
+            let dst_ptr = NonNull::from(&mut dst[offset..]).cast();
+            unsafe { nontemporal::copy(NonNull::from(&src[..]).cast(), dst_ptr, bytes) };
+            assert_eq!(dst[offset..offset + bytes], src[..bytes], "{offset} {bytes}");
+        }
+
+        let len = nontemporal::THRESHOLD / 8 + 1;
+        let src: Vec<u64> = (0..len as u64).collect();
+        let mut dst = vec![0u64; len];
+        unsafe { copy_spilled::<u64>(NonNull::from(&src[..]).cast(), NonNull::from(&mut dst[..]).cast(), len) };
+        assert_eq!(src, dst);
+    }
+
     #[test]
     fn try_grow_() {
         
         let mut bank = BankVec::<i32, 4>::new();
         assert!(try_grow(&mut bank, 3).is_ok());
-        assert_eq!(bank.capacity, 0);
+        assert_eq!(bank.capacity, 4);
 
         
         let mut bank = BankVec::<i32, 4>::from([1, 2, 3, 4, 5]);
-        // capacity is the nearest power of 2 that is greater than the 
-        // initial size of elements (5 in this case);
+        // An array spills into an allocation of exactly its length
         assert!(bank.on_heap());
-        assert_eq!(bank.capacity, 8); 
+        assert_eq!(bank.capacity, 5); 
 
         bank.drain(3..); // drop len to less than the new capacity we want to reduce to.
 
         assert!(try_grow(&mut bank, 3).is_ok());
         assert!(!bank.on_heap());
-        assert_eq!(bank.capacity, 3);
+        assert_eq!((bank.len, bank.capacity), (3, 4));
     }
 
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
 use std::{mem::{ManuallyDrop, MaybeUninit}, ptr::NonNull};
+use crate::stack_guard;
 
 
 
@@ -6,9 +7,12 @@ pub(super) type DataBuf<T> = (*const T, usize, usize);
 pub(super) type DataBufMut<'a, T> = (NonNull<T>, &'a mut usize, usize);
 
 
+// Both fields are covariant in `T`, as `Vec` is, so a `BankVec<&'long T, C>`
+// can stand in for a `BankVec<&'short T, C>`.  Storing a `*mut T` or a `Cell`
+// here instead would quietly make every bank invariant.
 pub(super) union BufferUnion<T, const C: usize> {
     pub(super) stack: ManuallyDrop<MaybeUninit<[T; C]>>,
-    pub(super) heap: (NonNull<T>, usize),
+    pub(super) heap: NonNull<T>,
 }
 
 unsafe impl<T: Send, const C: usize> Send for BufferUnion<T, C> {}
@@ -18,17 +22,14 @@ impl<T, const C: usize> BufferUnion<T, C> {
 
     #[inline]
     pub(super) const fn new_stack() -> Self { 
+        stack_guard::check_inline_size::<T, C>();
         Self { stack: ManuallyDrop::new(MaybeUninit::uninit()) }
     }
 
     #[inline]
-    pub(super) const fn new_heap() -> Self {
-        Self { heap: (NonNull::<T>::dangling(), 0) }
-    }
-
-    #[inline]
-    pub(super) const fn heap_from(ptr: NonNull<T>, len: usize) -> Self {
-        Self { heap: (ptr, len) }
+    pub(super) const fn heap_from(ptr: NonNull<T>) -> Self {
+        stack_guard::check_inline_size::<T, C>();
+        Self { heap: ptr }
     }
 
     #[inline]
@@ -0,0 +1,93 @@
+
+use super::BankVec;
+
+
+/// Statistics on how a single [`BankVec`] has used its inline storage, kept
+/// with the `instrument` feature to help choose its capacity `C`.
+/// 
+/// Returned by [`BankVec::stats`].  Each bank tracks its own, starting afresh
+/// when it's created or cloned.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SpillStats {
+    /// The number of times the bank moved its elements from inline storage
+    /// onto the heap.
+    pub spills: usize,
+    /// The greatest length the bank has reached.
+    pub max_len: usize,
+    /// The size in bytes of the bank's current heap buffer, zero while inline.
+    pub heap_bytes: usize,
+}
+
+impl SpillStats {
+    #[inline]
+    pub(super) const fn new(len: usize) -> Self {
+        Self { spills: 0, max_len: len, heap_bytes: 0 }
+    }
+}
+
+impl<T, const C: usize> BankVec<T, C> {
+
+    /// Returns the bank's [`SpillStats`].
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut bank = BankVec::<u32, 2>::from([1, 2]);
+    /// bank.push(3);
+    /// bank.truncate(1);
+    /// 
+    /// let stats = bank.stats();
+    /// assert_eq!(stats.spills, 1);
+    /// assert_eq!(stats.max_len, 3);
+    /// assert_eq!(stats.heap_bytes, bank.capacity() * 4);
+    /// ```
+    pub fn stats(&self) -> SpillStats {
+        let heap_bytes = match self.on_heap() {
+            true => self.capacity * size_of::<T>(),
+            false => 0,
+        };
+        SpillStats { heap_bytes, ..self.stats }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn spills() {
+        let mut bank = BankVec::<String, 2>::new();
+        bank.extend(["a", "b", "c"].map(String::from));
+        assert_eq!(bank.stats().spills, 1);
+
+        bank.truncate(1);
+        bank.shrink_to_fit();
+        assert_eq!(bank.stats(), SpillStats { spills: 1, max_len: 3, heap_bytes: 0 });
+
+        bank.insert(0, "d".to_string());
+        bank.insert(0, "e".to_string());
+        assert_eq!(bank.stats().spills, 2);
+        assert_eq!(bank.stats().heap_bytes, 4 * size_of::<String>());
+    }
+
+    #[test]
+    fn max_len() {
+        let mut bank = BankVec::<u8, 4>::from([1, 2, 3]);
+        bank.clear();
+        assert_eq!(bank.stats().max_len, 3);
+
+        bank.push(1);
+        bank.extend_from_slice(&[2, 3, 4, 5]);
+        bank.truncate(2);
+        assert_eq!(bank.stats().max_len, 5);
+        assert_eq!(bank.clone().stats().max_len, 2);
+
+        let bank = BankVec::<u8, 2>::from([1, 2, 3, 4, 5]);
+        assert_eq!(bank.stats().max_len, 5);
+    }
+}
@@ -0,0 +1,579 @@
+//! A double-ended queue which spills onto the heap, see [`BankVecDeque`].
+
+use std::{collections::VecDeque, fmt, iter::FusedIterator, mem, ops::{Index, IndexMut}};
+use crate::{BankDeque, bankdeque::{Iter, IterMut}, raw};
+
+
+/// A double-ended queue which keeps up to `C` elements inline, moving onto the
+/// heap once it grows past `C`.
+/// 
+/// `BankVecDeque` is to [`BankDeque`] what [`BankVec`](crate::BankVec) is to
+/// [`BankArr`](crate::BankArr): while it holds `C` or fewer elements it's an inline
+/// ring buffer, and past that it becomes a [`VecDeque`].  Pushes never fail for
+/// lack of room.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::BankVecDeque;
+/// 
+/// let mut deque = BankVecDeque::<i32, 2>::new();
+/// deque.push_back(2);
+/// deque.push_front(1);
+/// assert!(!deque.on_heap());
+/// 
+/// deque.push_back(3);
+/// assert!(deque.on_heap());
+/// assert_eq!(deque, [1, 2, 3]);
+/// assert_eq!(deque.pop_front(), Some(1));
+/// ```
+pub struct BankVecDeque<T, const C: usize> {
+    buf: Buffer<T, C>,
+}
+
+#[derive(Clone)]
+enum Buffer<T, const C: usize> {
+    Inline(BankDeque<T, C>),
+    Heap(VecDeque<T>),
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: fmt::Debug, const C: usize> fmt::Debug for BankVecDeque<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone, const C: usize> Clone for BankVecDeque<T, C> {
+    fn clone(&self) -> Self {
+        Self { buf: self.buf.clone() }
+    }
+}
+
+impl<T, const C: usize> Index<usize> for BankVecDeque<T, C> {
+    type Output = T;
+
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(value) => value,
+            None => raw::index_out_of_bounds(index, self.len()),
+        }
+    }
+}
+
+impl<T, const C: usize> IndexMut<usize> for BankVecDeque<T, C> {
+    #[inline]
+    #[track_caller]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len();
+        match self.get_mut(index) {
+            Some(value) => value,
+            None => raw::index_out_of_bounds(index, len),
+        }
+    }
+}
+
+impl<T: PartialEq, const C: usize> PartialEq for BankVecDeque<T, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: PartialEq, const C: usize, const N: usize> PartialEq<[T; N]> for BankVecDeque<T, C> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.len() == N && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const C: usize> Eq for BankVecDeque<T, C> {}
+
+impl<T, const C: usize> Extend<T> for BankVecDeque<T, C> {
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        let items = items.into_iter();
+        self.reserve(items.size_hint().0);
+        items.for_each(|value| self.push_back(value));
+    }
+}
+
+impl<T, const C: usize, const N: usize> From<[T; N]> for BankVecDeque<T, C> {
+
+    /// Creates a deque from an array, the first element at the front.  The deque
+    /// starts out on the heap if `N` exceeds `C`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVecDeque;
+    /// 
+    /// let deque = BankVecDeque::<i32, 2>::from([1, 2, 3]);
+    /// assert!(deque.on_heap());
+    /// assert_eq!(deque.front(), Some(&1));
+    /// ```
+    fn from(arr: [T; N]) -> Self {
+        let mut deque = Self::new();
+        deque.extend(arr);
+        deque
+    }
+}
+
+impl<T, const C: usize> From<BankDeque<T, C>> for BankVecDeque<T, C> {
+    #[inline]
+    fn from(deque: BankDeque<T, C>) -> Self { Self { buf: Buffer::Inline(deque) } }
+}
+
+impl<T, const C: usize> From<VecDeque<T>> for BankVecDeque<T, C> {
+
+    /// Wraps a `VecDeque`, keeping its allocation.
+    #[inline]
+    fn from(deque: VecDeque<T>) -> Self { Self { buf: Buffer::Heap(deque) } }
+}
+
+impl<T, const C: usize> From<BankVecDeque<T, C>> for VecDeque<T> {
+    fn from(deque: BankVecDeque<T, C>) -> Self {
+        match deque.buf {
+            Buffer::Inline(inline) => inline.into_iter().collect(),
+            Buffer::Heap(heap) => heap,
+        }
+    }
+}
+
+impl<T, const C: usize> Default for BankVecDeque<T, C> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+
+impl<T, const C: usize> IntoIterator for BankVecDeque<T, C> {
+    type Item = T;
+    type IntoIter = IntoIter<T, C>;
+
+    /// Creates a consuming iterator, moving each element out of the deque from
+    /// front to back.
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { IntoIter { deque: self } }
+}
+
+impl<'a, T, const C: usize> IntoIterator for &'a BankVecDeque<T, C> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<'a, T, const C: usize> IntoIterator for &'a mut BankVecDeque<T, C> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter_mut() }
+}
+
+// Forwards a call to whichever buffer is in use.
+macro_rules! dispatch {
+    ($self:ident.$method:ident($($arg:expr),*)) => {
+        match &$self.buf {
+            Buffer::Inline(inline) => inline.$method($($arg),*),
+            Buffer::Heap(heap) => heap.$method($($arg),*),
+        }
+    };
+    (mut $self:ident.$method:ident($($arg:expr),*)) => {
+        match &mut $self.buf {
+            Buffer::Inline(inline) => inline.$method($($arg),*),
+            Buffer::Heap(heap) => heap.$method($($arg),*),
+        }
+    };
+}
+
+impl<T, const C: usize> BankVecDeque<T, C> {
+
+    /// Constructs a new, empty `BankVecDeque<T, C>`.  Doesn't allocate until more
+    /// than `C` elements are pushed.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVecDeque;
+    /// 
+    /// let deque = BankVecDeque::<i32, 8>::new();
+    /// assert!(deque.is_empty());
+    /// ```
+    pub const fn new() -> Self {
+        Self { buf: Buffer::Inline(BankDeque::new()) }
+    }
+
+    /// Constructs a new, empty `BankVecDeque<T, C>` with room for at least
+    /// `capacity` elements.  Allocates on the heap only if `capacity` exceeds `C`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVecDeque;
+    /// 
+    /// assert!(!BankVecDeque::<i32, 4>::with_capacity(4).on_heap());
+    /// assert!(BankVecDeque::<i32, 4>::with_capacity(5).on_heap());
+    /// ```
+    #[track_caller]
+    pub fn with_capacity(capacity: usize) -> Self {
+        match capacity > C {
+            true => Self { buf: Buffer::Heap(VecDeque::with_capacity(capacity)) },
+            false => Self::new(),
+        }
+    }
+
+    /// Returns `true` if the deque's elements have moved onto the heap.
+    #[inline]
+    pub const fn on_heap(&self) -> bool { matches!(self.buf, Buffer::Heap(_)) }
+
+    /// Returns the number of elements in the deque.
+    #[inline]
+    pub fn len(&self) -> usize { dispatch!(self.len()) }
+
+    /// Returns `true` if the deque contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Returns the number of elements the deque can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize { dispatch!(self.capacity()) }
+
+    /// Reserves capacity for at least `additional` more elements, moving the
+    /// deque onto the heap if they won't fit within `C`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the new capacity overflows `usize`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVecDeque;
+    /// 
+    /// let mut deque = BankVecDeque::<i32, 4>::from([1, 2]);
+    /// deque.reserve(2);
+    /// assert!(!deque.on_heap());
+    /// deque.reserve(3);
+    /// assert!(deque.capacity() >= 5);
+    /// ```
+    #[track_caller]
+    pub fn reserve(&mut self, additional: usize) {
+        match &mut self.buf {
+            Buffer::Inline(inline) if additional > inline.remaining_capacity() => {
+                let Some(capacity) = inline.len().checked_add(additional) else { raw::capacity_overflow() };
+                self.spill(capacity);
+            }
+            Buffer::Inline(_) => (),
+            Buffer::Heap(heap) => heap.reserve(additional),
+        }
+    }
+
+    // Moves the inline elements into a new `VecDeque` with at least `capacity` room.
+    #[cold]
+    #[track_caller]
+    fn spill(&mut self, capacity: usize) {
+        let Buffer::Inline(inline) = &mut self.buf else { return };
+        let mut heap = VecDeque::with_capacity(capacity.max(C.saturating_mul(2)));
+        heap.extend(inline.drain(..));
+        self.buf = Buffer::Heap(heap);
+    }
+
+    /// Shrinks the deque's heap allocation to fit its length, moving the elements
+    /// back inline if they fit within `C`.  Does nothing for an inline deque.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVecDeque;
+    /// 
+    /// let mut deque = BankVecDeque::<i32, 2>::from([1, 2, 3]);
+    /// deque.pop_back();
+    /// deque.shrink_to_fit();
+    /// assert!(!deque.on_heap());
+    /// assert_eq!(deque, [1, 2]);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        let Buffer::Heap(heap) = &mut self.buf else { return };
+        if heap.len() > C { return heap.shrink_to_fit() }
+
+        let mut inline = BankDeque::new();
+        inline.extend(mem::take(heap));
+        self.buf = Buffer::Inline(inline);
+    }
+
+    /// Appends an element to the back of the deque, moving onto the heap if the
+    /// deque is full.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVecDeque;
+    /// 
+    /// let mut deque = BankVecDeque::<i32, 2>::from([1, 2]);
+    /// deque.push_back(3);
+    /// assert_eq!(deque, [1, 2, 3]);
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes amortized *O*(1) time, and *O*(`C`) time to move onto the heap.
+    #[inline]
+    #[track_caller]
+    pub fn push_back(&mut self, value: T) {
+        if let Buffer::Inline(inline) = &mut self.buf {
+            match inline.try_push_back(value) {
+                Ok(()) => return,
+                Err(err) => {
+                    self.spill(C + 1);
+                    return self.push_back(err.element());
+                }
+            }
+        }
+        if let Buffer::Heap(heap) = &mut self.buf { heap.push_back(value) }
+    }
+
+    /// Prepends an element to the front of the deque, moving onto the heap if the
+    /// deque is full.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVecDeque;
+    /// 
+    /// let mut deque = BankVecDeque::<i32, 2>::from([2, 3]);
+    /// deque.push_front(1);
+    /// assert_eq!(deque, [1, 2, 3]);
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes amortized *O*(1) time, and *O*(`C`) time to move onto the heap.
+    #[inline]
+    #[track_caller]
+    pub fn push_front(&mut self, value: T) {
+        if let Buffer::Inline(inline) = &mut self.buf {
+            match inline.try_push_front(value) {
+                Ok(()) => return,
+                Err(err) => {
+                    self.spill(C + 1);
+                    return self.push_front(err.element());
+                }
+            }
+        }
+        if let Buffer::Heap(heap) = &mut self.buf { heap.push_front(value) }
+    }
+
+    /// Removes the last element and returns it, or `None` if the deque is empty.
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(1) time.
+    #[inline]
+    pub fn pop_back(&mut self) -> Option<T> { dispatch!(mut self.pop_back()) }
+
+    /// Removes the first element and returns it, or `None` if the deque is empty.
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(1) time.
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<T> { dispatch!(mut self.pop_front()) }
+
+    /// Returns a reference to the element at `index`, counting from the front,
+    /// or `None` if it's out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> { dispatch!(self.get(index)) }
+
+    /// Returns a mutable reference to the element at `index`, counting from the
+    /// front, or `None` if it's out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> { dispatch!(mut self.get_mut(index)) }
+
+    /// Returns a reference to the front element, or `None` if the deque is empty.
+    #[inline]
+    pub fn front(&self) -> Option<&T> { dispatch!(self.front()) }
+
+    /// Returns a mutable reference to the front element, or `None` if the deque
+    /// is empty.
+    #[inline]
+    pub fn front_mut(&mut self) -> Option<&mut T> { dispatch!(mut self.front_mut()) }
+
+    /// Returns a reference to the back element, or `None` if the deque is empty.
+    #[inline]
+    pub fn back(&self) -> Option<&T> { dispatch!(self.back()) }
+
+    /// Returns a mutable reference to the back element, or `None` if the deque
+    /// is empty.
+    #[inline]
+    pub fn back_mut(&mut self) -> Option<&mut T> { dispatch!(mut self.back_mut()) }
+
+    /// Returns the deque's contents as a pair of slices, which in order hold
+    /// every element from front to back.
+    #[inline]
+    pub fn as_slices(&self) -> (&[T], &[T]) { dispatch!(self.as_slices()) }
+
+    /// Returns the deque's contents as a pair of mutable slices, see
+    /// [`as_slices`](Self::as_slices).
+    #[inline]
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) { dispatch!(mut self.as_mut_slices()) }
+
+    /// Returns a front-to-back iterator over the deque.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVecDeque;
+    /// 
+    /// let deque = BankVecDeque::<i32, 2>::from([1, 2, 3]);
+    /// assert_eq!(deque.iter().rev().collect::<Vec<_>>(), [&3, &2, &1]);
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+        Iter::new(front, back)
+    }
+
+    /// Returns a front-to-back iterator over the deque's mutable elements.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (front, back) = self.as_mut_slices();
+        IterMut::new(front, back)
+    }
+
+    /// Shortens the deque, keeping the first `len` elements and dropping the rest.
+    /// The deque stays on the heap if it's already there.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) { dispatch!(mut self.truncate(len)) }
+
+    /// Removes every element from the deque, leaving its capacity unchanged.
+    #[inline]
+    pub fn clear(&mut self) { dispatch!(mut self.clear()) }
+}
+
+
+/// A consuming iterator over the elements of a [`BankVecDeque`].
+/// 
+/// Created by the `into_iter` method on [`BankVecDeque`], provided by the
+/// [`IntoIterator`] trait.
+#[derive(Debug, Clone)]
+pub struct IntoIter<T, const C: usize> {
+    deque: BankVecDeque<T, C>,
+}
+
+impl<T, const C: usize> Iterator for IntoIter<T, C> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> { self.deque.pop_front() }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.deque.len(), Some(self.deque.len())) }
+}
+
+impl<T, const C: usize> DoubleEndedIterator for IntoIter<T, C> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> { self.deque.pop_back() }
+}
+
+impl<T, const C: usize> ExactSizeIterator for IntoIter<T, C> {}
+
+impl<T, const C: usize> FusedIterator for IntoIter<T, C> {}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn spill() {
+        let mut deque = BankVecDeque::<String, 2>::new();
+        deque.push_back("b".to_string());
+        deque.push_front("a".to_string());
+        assert!(!deque.on_heap());
+        assert_eq!(deque.capacity(), 2);
+
+        deque.push_front("_".to_string());
+        deque.push_back("c".to_string());
+        assert!(deque.on_heap());
+        assert!(deque.capacity() >= 4);
+        assert_eq!(deque.iter().map(String::as_str).collect::<Vec<_>>(), ["_", "a", "b", "c"]);
+
+        assert_eq!(deque.pop_front().unwrap(), "_");
+        assert_eq!(deque.pop_back().unwrap(), "c");
+        assert_eq!(deque[1], "b");
+        deque[0].push('a');
+        assert_eq!(deque.front().unwrap(), "aa");
+    }
+
+    #[test]
+    fn spill_wrapped() {
+        let mut deque = BankVecDeque::<i32, 3>::from([1, 2, 3]);
+        deque.pop_front();
+        deque.push_back(4);
+        assert!(!deque.as_slices().1.is_empty());
+
+        deque.push_front(1);
+        assert!(deque.on_heap());
+        assert_eq!(deque, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity overflow")]
+    fn reserve_overflow() {
+        let mut deque = BankVecDeque::<i32, 2>::from([1]);
+        deque.reserve(usize::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 2 out of bounds for length 2")]
+    fn index_panics() {
+        let deque = BankVecDeque::<i32, 4>::from([1, 2]);
+        let _ = deque[2];
+    }
+
+    #[test]
+    #[should_panic(expected = "index 5 out of bounds for length 3")]
+    fn index_mut_panics() {
+        let mut deque = BankVecDeque::<i32, 2>::from([1, 2, 3]);
+        deque[5] = 0;
+    }
+
+    #[test]
+    fn shrink_to_fit() {
+        let mut deque = BankVecDeque::<i32, 2>::with_capacity(10);
+        deque.extend([1, 2, 3, 4]);
+        deque.truncate(3);
+        deque.shrink_to_fit();
+        assert!(deque.on_heap());
+        assert_eq!(deque.capacity(), 3);
+
+        deque.pop_front();
+        deque.shrink_to_fit();
+        assert!(!deque.on_heap());
+        assert_eq!(deque, [2, 3]);
+    }
+
+    #[test]
+    fn into_iter() {
+        let deque = BankVecDeque::<i32, 2>::from([1, 2, 3]);
+        assert_eq!(deque.clone().into_iter().rev().collect::<Vec<_>>(), [3, 2, 1]);
+        assert_eq!(VecDeque::from(deque), [1, 2, 3]);
+
+        let deque = BankVecDeque::<i32, 4>::from([1, 2]);
+        let mut iter = deque.into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(VecDeque::from(BankVecDeque::<i32, 4>::from([1, 2])), [1, 2]);
+    }
+
+    #[test]
+    fn dropping_types() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        let mut deque = BankVecDeque::<Rc<()>, 2>::new();
+        for _ in 0..3 {
+            deque.push_front(rc.clone());
+        }
+        assert_eq!(Rc::strong_count(&rc), 4);
+        deque.clear();
+        assert_eq!(Rc::strong_count(&rc), 1);
+
+        deque.push_back(rc.clone());
+        drop(deque);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
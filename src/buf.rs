@@ -0,0 +1,109 @@
+use bytes::{buf::UninitSlice, Buf, BufMut};
+
+use crate::{drain::Drain, raw::RawBank, BankArr, BankVec};
+
+
+// A drain over a byte bank is a natural `Buf`: bytes are read from the front and
+// removed from the bank once the drain is dropped.  `u8` has no drop glue, so
+// advancing past bytes without reading them is free.
+impl<'a, B: RawBank<u8>> Buf for Drain<'a, u8, B> {
+    #[inline]
+    fn remaining(&self) -> usize { self.iter.len() }
+
+    #[inline]
+    fn chunk(&self) -> &[u8] { self.iter.as_slice() }
+
+    #[inline]
+    fn advance(&mut self, cnt: usize) {
+        let rest = self.iter.as_slice();
+        assert!(cnt <= rest.len(), "cannot advance past `remaining`: {} <= {}", cnt, rest.len());
+        self.iter = rest[cnt..].iter();
+    }
+}
+
+unsafe impl<const C: usize> BufMut for BankArr<u8, C> {
+    #[inline]
+    fn remaining_mut(&self) -> usize { self.remaining_capacity() }
+
+    #[inline]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        let remaining = self.remaining_capacity();
+        assert!(cnt <= remaining, "cannot advance past `remaining_mut`: {:?} <= {:?}", cnt, remaining);
+        self.len += cnt;
+    }
+
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        UninitSlice::uninit(&mut self.data[self.len..])
+    }
+}
+
+unsafe impl<const C: usize> BufMut for BankVec<u8, C> {
+    #[inline]
+    fn remaining_mut(&self) -> usize { isize::MAX as usize - self.len() }
+
+    #[inline]
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        let (len, cap) = (self.len(), self.capacity());
+        assert!(cnt <= cap - len, "cannot advance past `remaining_mut`: {:?} <= {:?}", cnt, cap - len);
+        unsafe { self.set_len(len + cnt) }
+    }
+
+    #[inline]
+    fn chunk_mut(&mut self) -> &mut UninitSlice {
+        // Mirrors `Vec`: grow when there's no spare room so writers never stall.
+        if self.len() == self.capacity() { self.reserve(64) }
+        let (ptr, &mut len, cap) = self.data_buf_mut();
+        unsafe { UninitSlice::from_raw_parts_mut(ptr.as_ptr().add(len), cap - len) }
+    }
+
+    #[inline]
+    fn put_slice(&mut self, src: &[u8]) { self.extend_from_slice(src) }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drain_buf() {
+        let mut bank = BankVec::<u8, 4>::from([1, 2, 3, 4, 5, 6]);
+        let mut buf = bank.drain(..4);
+
+        assert_eq!(buf.remaining(), 4);
+        assert_eq!(buf.get_u16(), 0x0102);
+        buf.advance(1);
+        assert_eq!(buf.chunk(), [4]);
+        drop(buf);
+
+        assert_eq!(bank, [5, 6]);
+    }
+
+    #[test]
+    fn bankarr_buf_mut() {
+        let mut bank = BankArr::<u8, 4>::new();
+        bank.put_u16(0x0102);
+        bank.put_slice(&[3]);
+        assert_eq!(bank.remaining_mut(), 1);
+        assert_eq!(bank, [1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bankarr_buf_mut_overflow() {
+        let mut bank = BankArr::<u8, 2>::new();
+        bank.put_slice(&[1, 2, 3]);
+    }
+
+    #[test]
+    fn bankvec_buf_mut() {
+        let mut bank = BankVec::<u8, 2>::new();
+        bank.put_u8(1);
+        assert!(!bank.on_heap());
+        bank.put_u32(0x02030405);
+        assert!(bank.on_heap());
+        bank.put_bytes(6, 2);
+        assert_eq!(bank, [1, 2, 3, 4, 5, 6, 6]);
+    }
+}
@@ -0,0 +1,44 @@
+
+use std::slice;
+
+
+// Stand-ins for the slice methods of the same names, which need Rust 1.88.
+// `K` must be non-zero.
+
+#[inline]
+pub(crate) const fn as_chunks<T, const K: usize>(slice: &[T]) -> (&[[T; K]], &[T]) {
+    const { assert!(K > 0, "chunk size must be non-zero") }
+    let count = slice.len() / K;
+    let (chunks, rest) = slice.split_at(count * K);
+    // `chunks` holds exactly `count` runs of `K` elements
+    (unsafe { slice::from_raw_parts(chunks.as_ptr().cast(), count) }, rest)
+}
+
+#[inline]
+pub(crate) const fn as_chunks_mut<T, const K: usize>(slice: &mut [T]) -> (&mut [[T; K]], &mut [T]) {
+    const { assert!(K > 0, "chunk size must be non-zero") }
+    let count = slice.len() / K;
+    let (chunks, rest) = slice.split_at_mut(count * K);
+    (unsafe { slice::from_raw_parts_mut(chunks.as_mut_ptr().cast(), count) }, rest)
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn as_chunks_() {
+        let values = [1, 2, 3, 4, 5, 6, 7];
+        assert_eq!(as_chunks::<_, 3>(&values), (&[[1, 2, 3], [4, 5, 6]][..], &[7][..]));
+        assert_eq!(as_chunks::<_, 8>(&values), (&[][..], &values[..]));
+        assert_eq!(as_chunks::<i32, 1>(&[]), (&[][..], &[][..]));
+
+        let mut values = values;
+        let (chunks, rest) = as_chunks_mut::<_, 2>(&mut values);
+        chunks.iter_mut().for_each(|chunk| chunk.swap(0, 1));
+        rest[0] = 0;
+        assert_eq!(values, [2, 1, 4, 3, 6, 5, 0]);
+    }
+}
@@ -0,0 +1,254 @@
+use std::{cmp, io::{self, BufRead, Read, Seek, SeekFrom, Write}};
+
+use crate::{BankArr, BankVec};
+
+#[cfg(any(feature = "futures-io", feature = "tokio"))]
+mod async_io;
+
+
+/// A cursor over a byte bank, tracking a read/write position.
+/// 
+/// Works like [`io::Cursor`], implementing [`Read`], [`BufRead`] and [`Seek`]
+/// for any bank, and [`Write`] for [`BankArr<u8, C>`] and [`BankVec<u8, C>`].
+/// 
+/// Writing overwrites bytes at the current position and appends past the end.
+/// Seeking past the end and then writing fills the gap with zeroes.  A `BankArr`
+/// never grows beyond `C`, so writes into a full bank are short, whereas a
+/// `BankVec` will move to the heap.
+/// 
+/// With the `futures-io` or `tokio` features enabled the cursor also implements
+/// the respective async IO traits, all of which complete immediately.
+/// 
+/// # Examples
+/// 
+/// ```
+/// use std::io::{Read, Seek, SeekFrom, Write};
+/// use bankarr::{BankArr, BankCursor};
+/// 
+/// let mut cursor = BankCursor::new(BankArr::<u8, 8>::new());
+/// cursor.write_all(b"hello").unwrap();
+/// 
+/// cursor.seek(SeekFrom::Start(1)).unwrap();
+/// let mut buf = [0; 4];
+/// cursor.read_exact(&mut buf).unwrap();
+/// assert_eq!(&buf, b"ello");
+/// 
+/// // Only 3 bytes of space remain
+/// assert_eq!(cursor.write(b"world").unwrap(), 3);
+/// assert_eq!(cursor.into_inner(), *b"hellowor");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct BankCursor<B> {
+    inner: B,
+    pos: u64,
+}
+
+impl<B> BankCursor<B> {
+
+    /// Creates a new cursor wrapping the provided bank, positioned at its start.
+    #[inline]
+    pub const fn new(inner: B) -> Self { Self { inner, pos: 0 } }
+
+    /// Consumes the cursor, returning the underlying bank.
+    #[inline]
+    pub fn into_inner(self) -> B { self.inner }
+
+    /// Gets a reference to the underlying bank.
+    #[inline]
+    pub const fn get_ref(&self) -> &B { &self.inner }
+
+    /// Gets a mutable reference to the underlying bank.
+    #[inline]
+    pub const fn get_mut(&mut self) -> &mut B { &mut self.inner }
+
+    /// Returns the current position of the cursor.
+    #[inline]
+    pub const fn position(&self) -> u64 { self.pos }
+
+    /// Sets the position of the cursor.
+    #[inline]
+    pub const fn set_position(&mut self, pos: u64) { self.pos = pos }
+}
+
+impl<B: AsRef<[u8]>> BankCursor<B> {
+
+    #[inline]
+    fn remaining_slice(&self) -> &[u8] {
+        let data = self.inner.as_ref();
+        let start = cmp::min(self.pos, data.len() as u64) as usize;
+        &data[start..]
+    }
+}
+
+impl<B: AsRef<[u8]>> Read for BankCursor<B> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = Read::read(&mut self.remaining_slice(), buf)?;
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl<B: AsRef<[u8]>> BufRead for BankCursor<B> {
+    #[inline]
+    fn fill_buf(&mut self) -> io::Result<&[u8]> { Ok(self.remaining_slice()) }
+
+    #[inline]
+    fn consume(&mut self, amt: usize) { self.pos += amt as u64 }
+}
+
+impl<B: AsRef<[u8]>> Seek for BankCursor<B> {
+    fn seek(&mut self, style: SeekFrom) -> io::Result<u64> {
+        let (base, offset) = match style {
+            SeekFrom::Start(n) => {
+                self.pos = n;
+                return Ok(n);
+            }
+            SeekFrom::End(n) => (self.inner.as_ref().len() as u64, n),
+            SeekFrom::Current(n) => (self.pos, n),
+        };
+
+        match base.checked_add_signed(offset) {
+            Some(n) => {
+                self.pos = n;
+                Ok(n)
+            }
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+
+    #[inline]
+    fn stream_position(&mut self) -> io::Result<u64> { Ok(self.pos) }
+}
+
+
+/// The operations a byte bank needs to back a writable cursor.
+trait WriteBank: AsMut<[u8]> {
+    fn len(&self) -> usize;
+    fn max_len(&self) -> usize;
+    fn extend_from_slice(&mut self, bytes: &[u8]);
+}
+
+impl<const C: usize> WriteBank for BankArr<u8, C> {
+    #[inline]
+    fn len(&self) -> usize { self.len() }
+    #[inline]
+    fn max_len(&self) -> usize { C }
+    #[inline]
+    fn extend_from_slice(&mut self, bytes: &[u8]) { self.extend_from_slice(bytes) }
+}
+
+impl<const C: usize> WriteBank for BankVec<u8, C> {
+    #[inline]
+    fn len(&self) -> usize { self.len() }
+    #[inline]
+    fn max_len(&self) -> usize { isize::MAX as usize }
+    #[inline]
+    fn extend_from_slice(&mut self, bytes: &[u8]) { self.extend_from_slice(bytes) }
+}
+
+fn write_at<B: WriteBank>(cursor: &mut BankCursor<B>, buf: &[u8]) -> io::Result<usize> {
+    let bank = &mut cursor.inner;
+    let max_len = bank.max_len();
+    let pos = usize::try_from(cursor.pos).map_or(max_len, |pos| cmp::min(pos, max_len));
+
+    // Zero-fill any gap left by seeking past the end.
+    let len = bank.len();
+    if pos > len {
+        const ZEROES: [u8; 64] = [0; 64];
+        let mut gap = pos - len;
+        while gap > 0 {
+            let n = cmp::min(gap, ZEROES.len());
+            bank.extend_from_slice(&ZEROES[..n]);
+            gap -= n;
+        }
+    }
+
+    let amt = cmp::min(buf.len(), max_len - pos);
+    let overlap = cmp::min(amt, bank.len() - pos);
+    bank.as_mut()[pos..pos + overlap].copy_from_slice(&buf[..overlap]);
+    bank.extend_from_slice(&buf[overlap..amt]);
+
+    cursor.pos = (pos + amt) as u64;
+    Ok(amt)
+}
+
+impl<const C: usize> Write for BankCursor<BankArr<u8, C>> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> { write_at(self, buf) }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> { Ok(()) }
+}
+
+impl<const C: usize> Write for BankCursor<BankVec<u8, C>> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> { write_at(self, buf) }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> { Ok(()) }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read() {
+        let mut cursor = BankCursor::new(BankArr::<u8, 4>::from([1, 2, 3]));
+        let mut buf = [0; 2];
+
+        assert_eq!(cursor.read(&mut buf).unwrap(), 2);
+        assert_eq!(buf, [1, 2]);
+        assert_eq!(cursor.read(&mut buf).unwrap(), 1);
+        assert_eq!(cursor.read(&mut buf).unwrap(), 0);
+        assert_eq!(cursor.position(), 3);
+    }
+
+    #[test]
+    fn buf_read() {
+        let mut cursor = BankCursor::new(BankVec::<u8, 8>::from(*b"ab\ncd"));
+        let mut line = String::new();
+        cursor.read_line(&mut line).unwrap();
+        assert_eq!(line, "ab\n");
+        assert_eq!(cursor.fill_buf().unwrap(), b"cd");
+        cursor.consume(1);
+        assert_eq!(cursor.fill_buf().unwrap(), b"d");
+    }
+
+    #[test]
+    fn seek() {
+        let mut cursor = BankCursor::new(BankArr::<u8, 4>::from([1, 2, 3]));
+        assert_eq!(cursor.seek(SeekFrom::End(-1)).unwrap(), 2);
+        assert_eq!(cursor.seek(SeekFrom::Current(-2)).unwrap(), 0);
+        assert_eq!(cursor.seek(SeekFrom::Start(10)).unwrap(), 10);
+        assert!(cursor.seek(SeekFrom::End(-4)).is_err());
+        assert_eq!(cursor.fill_buf().unwrap(), b"");
+    }
+
+    #[test]
+    fn write_bankarr() {
+        let mut cursor = BankCursor::new(BankArr::<u8, 4>::from([1, 2, 3]));
+        cursor.set_position(1);
+        assert_eq!(cursor.write(&[7, 8, 9, 10]).unwrap(), 3);
+        assert_eq!(cursor.write(&[11]).unwrap(), 0);
+        assert!(cursor.write_all(&[11]).is_err());
+        assert_eq!(cursor.get_ref(), &[1, 7, 8, 9]);
+    }
+
+    #[test]
+    fn write_bankvec() {
+        let mut cursor = BankCursor::new(BankVec::<u8, 2>::from([1]));
+        cursor.seek(SeekFrom::End(2)).unwrap();
+        cursor.write_all(&[5, 6]).unwrap();
+        assert!(cursor.get_ref().on_heap());
+        assert_eq!(cursor.get_ref(), &[1, 0, 0, 5, 6]);
+
+        cursor.set_position(0);
+        cursor.write_all(&[9]).unwrap();
+        assert_eq!(cursor.into_inner(), [9, 0, 0, 5, 6]);
+    }
+}
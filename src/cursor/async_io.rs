@@ -0,0 +1,176 @@
+//! Async IO for [`BankCursor`].  Every operation completes immediately since the
+//! bank lives in memory, so these simply forward to the blocking impls.
+
+use std::{io::{self, BufRead, Seek, SeekFrom, Write}, pin::Pin, task::{Context, Poll}};
+
+use crate::{BankArr, BankVec};
+use super::BankCursor;
+
+
+#[cfg(feature = "futures-io")]
+mod futures {
+    use std::io::Read;
+    use futures_io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite};
+    use super::*;
+
+    impl<B: AsRef<[u8]> + Unpin> AsyncRead for BankCursor<B> {
+        #[inline]
+        fn poll_read(self: Pin<&mut Self>, _: &mut Context<'_>, buf: &mut [u8]) -> Poll<io::Result<usize>> {
+            Poll::Ready(self.get_mut().read(buf))
+        }
+    }
+
+    impl<B: AsRef<[u8]> + Unpin> AsyncBufRead for BankCursor<B> {
+        #[inline]
+        fn poll_fill_buf(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+            Poll::Ready(self.get_mut().fill_buf())
+        }
+
+        #[inline]
+        fn consume(self: Pin<&mut Self>, amt: usize) { BufRead::consume(self.get_mut(), amt) }
+    }
+
+    impl<B: AsRef<[u8]> + Unpin> AsyncSeek for BankCursor<B> {
+        #[inline]
+        fn poll_seek(self: Pin<&mut Self>, _: &mut Context<'_>, pos: SeekFrom) -> Poll<io::Result<u64>> {
+            Poll::Ready(self.get_mut().seek(pos))
+        }
+    }
+
+    macro_rules! impl_async_write {
+        ($bank:ident) => {
+            impl<const C: usize> AsyncWrite for BankCursor<$bank<u8, C>> {
+                #[inline]
+                fn poll_write(self: Pin<&mut Self>, _: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+                    Poll::Ready(self.get_mut().write(buf))
+                }
+
+                #[inline]
+                fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+                    Poll::Ready(Ok(()))
+                }
+
+                #[inline]
+                fn poll_close(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+                    Poll::Ready(Ok(()))
+                }
+            }
+        };
+    }
+
+    impl_async_write!(BankArr);
+    impl_async_write!(BankVec);
+}
+
+#[cfg(feature = "tokio")]
+mod tokio {
+    use ::tokio::io::{AsyncBufRead, AsyncRead, AsyncSeek, AsyncWrite, ReadBuf};
+    use super::*;
+
+    impl<B: AsRef<[u8]> + Unpin> AsyncRead for BankCursor<B> {
+        fn poll_read(self: Pin<&mut Self>, _: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+            let cursor = self.get_mut();
+            let src = cursor.remaining_slice();
+            let amt = src.len().min(buf.remaining());
+            buf.put_slice(&src[..amt]);
+            cursor.pos += amt as u64;
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    impl<B: AsRef<[u8]> + Unpin> AsyncBufRead for BankCursor<B> {
+        #[inline]
+        fn poll_fill_buf(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<&[u8]>> {
+            Poll::Ready(self.get_mut().fill_buf())
+        }
+
+        #[inline]
+        fn consume(self: Pin<&mut Self>, amt: usize) { BufRead::consume(self.get_mut(), amt) }
+    }
+
+    impl<B: AsRef<[u8]> + Unpin> AsyncSeek for BankCursor<B> {
+        #[inline]
+        fn start_seek(self: Pin<&mut Self>, position: SeekFrom) -> io::Result<()> {
+            self.get_mut().seek(position).map(drop)
+        }
+
+        #[inline]
+        fn poll_complete(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<u64>> {
+            Poll::Ready(Ok(self.pos))
+        }
+    }
+
+    macro_rules! impl_async_write {
+        ($bank:ident) => {
+            impl<const C: usize> AsyncWrite for BankCursor<$bank<u8, C>> {
+                #[inline]
+                fn poll_write(self: Pin<&mut Self>, _: &mut Context<'_>, buf: &[u8]) -> Poll<io::Result<usize>> {
+                    Poll::Ready(self.get_mut().write(buf))
+                }
+
+                #[inline]
+                fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+                    Poll::Ready(Ok(()))
+                }
+
+                #[inline]
+                fn poll_shutdown(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<io::Result<()>> {
+                    Poll::Ready(Ok(()))
+                }
+            }
+        };
+    }
+
+    impl_async_write!(BankArr);
+    impl_async_write!(BankVec);
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::task::Waker;
+    use super::*;
+
+    fn ready<T>(poll: Poll<T>) -> T {
+        match poll {
+            Poll::Ready(val) => val,
+            Poll::Pending => panic!("in-memory IO should never be pending"),
+        }
+    }
+
+    #[cfg(feature = "futures-io")]
+    #[test]
+    fn futures_io() {
+        use futures_io::{AsyncRead, AsyncSeek, AsyncWrite};
+
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut cursor = BankCursor::new(BankArr::<u8, 4>::new());
+        let mut pin = Pin::new(&mut cursor);
+
+        assert_eq!(ready(pin.as_mut().poll_write(&mut cx, &[1, 2, 3])).unwrap(), 3);
+        assert_eq!(ready(pin.as_mut().poll_seek(&mut cx, SeekFrom::Start(1))).unwrap(), 1);
+
+        let mut buf = [0; 4];
+        assert_eq!(ready(pin.as_mut().poll_read(&mut cx, &mut buf)).unwrap(), 2);
+        assert_eq!(buf[..2], [2, 3]);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn tokio() {
+        use ::tokio::io::{AsyncRead, AsyncSeek, AsyncWrite, ReadBuf};
+
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut cursor = BankCursor::new(BankVec::<u8, 2>::new());
+        let mut pin = Pin::new(&mut cursor);
+
+        assert_eq!(ready(pin.as_mut().poll_write(&mut cx, &[1, 2, 3])).unwrap(), 3);
+        pin.as_mut().start_seek(SeekFrom::Current(-2)).unwrap();
+        assert_eq!(ready(pin.as_mut().poll_complete(&mut cx)).unwrap(), 1);
+
+        let mut buf = [0; 4];
+        let mut buf = ReadBuf::new(&mut buf);
+        ready(pin.as_mut().poll_read(&mut cx, &mut buf)).unwrap();
+        assert_eq!(buf.filled(), [2, 3]);
+    }
+}
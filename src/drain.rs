@@ -1,13 +1,16 @@
-use std::{fmt::Debug, iter::FusedIterator, ops, ptr::{self, NonNull}, slice};
+use std::{fmt::Debug, iter::FusedIterator, mem, ops, ptr::{self, NonNull}, slice};
+
+use crate::{BankArr, BankVec, raw::{self, RawBank}, storage::{Storage, StorageBank}};
 
 
 #[inline]
-const fn ptr_copy<'a, T>(elt: &'a T) -> T { unsafe { ptr::read(elt as *const T) } }
+const fn ptr_copy<T>(elt: &T) -> T { unsafe { ptr::read(elt as *const T) } }
 
 
 // This function was effectively pulled verbatim from the unstable `slice_range`
 // feature in `core::slice::index`
 #[inline]
+#[track_caller]
 pub(crate) fn slice_range<R>(range: R, bounds: ops::RangeTo<usize>) -> ops::Range<usize>
 where
     R: ops::RangeBounds<usize>,
@@ -17,10 +20,9 @@ where
 
     let start = match range.start_bound() {
         ops::Bound::Included(&start) => start,
+        ops::Bound::Excluded(start) => start.checked_add(1)
+            .expect("attempted to index slice from after maximum usize"),
         ops::Bound::Unbounded => 0,
-        _ => unreachable!(),
-        //ops::Bound::Excluded(start) => start.checked_add(1)
-        //    .expect("attempted to index slice from after maximum usize"),
     };
 
     let end = match range.end_bound() {
@@ -41,12 +43,49 @@ where
     ops::Range { start, end }
 }
 
+// Non-panicking counterpart of `slice_range`, returns `None` wherever it would
+// otherwise panic.
+#[inline]
+pub(crate) fn try_slice_range<R>(range: R, bounds: ops::RangeTo<usize>) -> Option<ops::Range<usize>>
+where
+    R: ops::RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        ops::Bound::Included(&start) => start,
+        ops::Bound::Excluded(start) => start.checked_add(1)?,
+        ops::Bound::Unbounded => 0,
+    };
 
-pub trait Drainable<'a, T> {
-    fn drain_parts(&'a mut self) -> (NonNull<T>, &'a mut usize);
+    let end = match range.end_bound() {
+        ops::Bound::Included(end) => end.checked_add(1)?,
+        ops::Bound::Excluded(&end) => end,
+        ops::Bound::Unbounded => bounds.end,
+    };
+
+    (start <= end && end <= bounds.end).then_some(ops::Range { start, end })
 }
 
-pub struct Drain<'a, T, B: 'a + Drainable<'a, T>> {
+
+/// A bank that the `drain_into` methods, such as
+/// [`BankVec::drain_into`](crate::BankVec::drain_into), can move elements onto.
+/// 
+/// This trait is sealed, it's implemented for [`BankArr`], [`BankVec`] and
+/// [`StorageBank`] and can't be implemented outside this crate.
+pub trait DrainTarget<T>: RawBank<T> {}
+
+impl<T, const C: usize> DrainTarget<T> for BankArr<T, C> {}
+impl<T, const C: usize> DrainTarget<T> for BankVec<T, C> {}
+impl<T, S: Storage<T>> DrainTarget<T> for StorageBank<T, S> {}
+
+
+/// A draining iterator over a range of a bank's elements.
+/// 
+/// The bank is truncated to the start of the range as soon as the drain is
+/// made, and the elements after the range are moved back into place when it's
+/// dropped.  Leaking the drain, say with [`mem::forget`], can never expose a
+/// moved-out or uninitialized element, but the bank loses that tail as well,
+/// leaking the elements in it.
+pub struct Drain<'a, T, B: 'a + RawBank<T>> {
     pub(super) tail_start: usize,
     pub(super) tail_len: usize,
     pub(super) iter: slice::Iter<'a, T>,
@@ -54,16 +93,16 @@ pub struct Drain<'a, T, B: 'a + Drainable<'a, T>> {
 }
 
 #[cfg(not(tarpaulin_include))]
-impl<'a, T: 'a + Debug, B: Drainable<'a, T>> Debug for Drain<'a, T, B> {
+impl<'a, T: 'a + Debug, B: RawBank<T>> Debug for Drain<'a, T, B> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_tuple("Drain").field(&self.iter.as_slice()).finish()
     }
 }
 
-unsafe impl<'a, T: Sync, B: Drainable<'a, T>> Sync for Drain<'a, T, B> {}
-unsafe impl<'a, T: Send, B: Drainable<'a, T>> Send for Drain<'a, T, B> {}
+unsafe impl<'a, T: Sync, B: RawBank<T> + Sync> Sync for Drain<'a, T, B> {}
+unsafe impl<'a, T: Send, B: RawBank<T> + Send> Send for Drain<'a, T, B> {}
 
-impl<'a, T: 'a, B: Drainable<'a, T>> Iterator for Drain<'a, T, B> {
+impl<'a, T: 'a, B: RawBank<T>> Iterator for Drain<'a, T, B> {
     type Item = T;
 
     #[inline]
@@ -73,38 +112,104 @@ impl<'a, T: 'a, B: Drainable<'a, T>> Iterator for Drain<'a, T, B> {
     fn size_hint(&self) -> (usize, Option<usize>) { self.iter.size_hint() }
 }
 
-impl<'a, T: 'a, B: Drainable<'a, T>> DoubleEndedIterator for Drain<'a, T, B> {
+impl<'a, T: 'a, B: RawBank<T>> DoubleEndedIterator for Drain<'a, T, B> {
     #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
         self.iter.next_back().map(ptr_copy)
     }
 }
 
-impl<'a, T: 'a, B: Drainable<'a, T>> ExactSizeIterator for Drain<'a, T, B> {
+impl<'a, T: 'a, B: RawBank<T>> ExactSizeIterator for Drain<'a, T, B> {
     #[inline]
     fn len(&self) -> usize { self.iter.len() }
 }
 
-impl<'a, T: 'a, B: Drainable<'a, T>> FusedIterator for Drain<'a, T, B> {}
+impl<'a, T: 'a, B: RawBank<T>> FusedIterator for Drain<'a, T, B> {}
 
-impl<'a, T: 'a, B: Drainable<'a, T>> Drop for Drain<'a, T, B> {
+impl<'a, T: 'a, B: RawBank<T>> Drop for Drain<'a, T, B> {
     fn drop(&mut self) {
-        self.for_each(drop);
+        // Closes the gap even if dropping one of the remaining elements panics
+        struct TailGuard<'r, 'a, T, B: RawBank<T>>(&'r mut Drain<'a, T, B>);
+
+        impl<T, B: RawBank<T>> Drop for TailGuard<'_, '_, T, B> {
+            fn drop(&mut self) {
+                // With no tail, as when the whole bank was drained, it's already empty
+                if self.0.tail_len > 0 {
+                    let (ptr, len, _) = unsafe { self.0.bank.as_mut().raw_parts() };
+                    unsafe { raw::close_gap(ptr, len, self.0.tail_start, self.0.tail_len) }
+                }
+            }
+        }
 
-        if self.tail_len > 0 {
-            let (ptr, len) = unsafe { self.bank.as_mut().drain_parts() };
-            let start = *len;
-            let tail = self.tail_start;
+        let remaining = mem::take(&mut self.iter).as_slice();
+        let guard = TailGuard(self);
+
+        // Elements without drop glue are simply left behind.  The rest are
+        // dropped through a pointer from the bank itself, as the iterator only
+        // ever had shared access to them.
+        if mem::needs_drop::<T>() && !remaining.is_empty() {
+            let bank = unsafe { guard.0.bank.as_mut() };
+            // Zero-sized elements all share one address, so any offset will do
+            let offset = match mem::size_of::<T>() {
+                0 => 0,
+                _ => unsafe { remaining.as_ptr().offset_from_unsigned(bank.raw_ptr()) },
+            };
+            let (ptr, ..) = bank.raw_parts();
+            unsafe { ptr::slice_from_raw_parts_mut(ptr.as_ptr().add(offset), remaining.len()).drop_in_place() }
+        }
+    }
+}
 
-            if tail != start {
-                unsafe { ptr.add(start).copy_from(ptr.add(tail), self.tail_len) }
-            }
 
-            *len = start + self.tail_len;
+pub struct DrainChunks<'a, T, B: 'a + RawBank<T>, const K: usize> {
+    drain: Drain<'a, T, B>,
+}
+
+impl<'a, T, B: 'a + RawBank<T>, const K: usize> DrainChunks<'a, T, B, K> {
+    #[inline]
+    pub(crate) fn new(drain: Drain<'a, T, B>) -> Self {
+        const { assert!(K > 0, "chunk size must be non-zero") }
+        Self { drain }
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<'a, T: 'a + Debug, B: RawBank<T>, const K: usize> Debug for DrainChunks<'a, T, B, K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("DrainChunks").field(&self.drain.iter.as_slice()).finish()
+    }
+}
+
+impl<'a, T: 'a, B: RawBank<T>, const K: usize> Iterator for DrainChunks<'a, T, B, K> {
+    type Item = BankArr<T, K>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.drain.iter.as_slice();
+        if remaining.is_empty() { return None }
+
+        let count = remaining.len().min(K);
+        let mut chunk = BankArr::new();
+        unsafe {
+            ptr::copy_nonoverlapping(remaining.as_ptr(), chunk.data.as_mut_ptr().cast(), count);
         }
+        chunk.len = count;
+        // Moved elements are skipped so the drain doesn't drop them
+        self.drain.iter = remaining[count..].iter();
+
+        Some(chunk)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.drain.len().div_ceil(K);
+        (len, Some(len))
     }
 }
 
+impl<'a, T: 'a, B: RawBank<T>, const K: usize> ExactSizeIterator for DrainChunks<'a, T, B, K> {}
+
+impl<'a, T: 'a, B: RawBank<T>, const K: usize> FusedIterator for DrainChunks<'a, T, B, K> {}
+
 
 #[cfg(test)]
 mod tests {
@@ -115,6 +220,7 @@ mod tests {
     use std::panic;
 
     #[test]
+    #[allow(clippy::reversed_empty_ranges)]
     fn slice_range_() {
 
         // unbounded start, unbounded end
@@ -131,6 +237,54 @@ mod tests {
 
         // end is greater than limit
         assert!(panic::catch_unwind(|| slice_range(0..11, ..10)).is_err());
+
+        // excluded start, unbounded end
+        let range = (ops::Bound::Excluded(2), ops::Bound::Unbounded);
+        assert_eq!(slice_range(range, ..10), ops::Range { start: 3, end: 10 });
+
+        // excluded start past maximum usize
+        let range = (ops::Bound::Excluded(usize::MAX), ops::Bound::Unbounded);
+        assert!(panic::catch_unwind(|| slice_range(range, ..10)).is_err());
+    }
+
+    #[test]
+    fn drain_chunks() {
+        let mut bank = BankVec::<String, 2>::from(["a", "b", "c", "d", "e"].map(String::from));
+        let mut chunks = bank.drain_chunks::<2>();
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks.next().unwrap(), ["a".to_string(), "b".to_string()]);
+        assert_eq!(chunks.len(), 2);
+        drop(chunks);
+        assert!(bank.is_empty());
+
+        let mut bank = BankArr::<i32, 5>::from([1, 2, 3, 4, 5]);
+        let chunks: Vec<_> = bank.drain_chunks::<3>().collect();
+        assert_eq!(chunks[0], [1, 2, 3]);
+        assert_eq!(chunks[1], [4, 5]);
+        assert_eq!(bank.drain_chunks::<3>().next(), None);
+    }
+
+    #[test]
+    fn drain_excluded_start() {
+        let mut bank = BankVec::<i32, 3>::from([1, 2, 3, 4]);
+        let drained: Vec<_> = bank.drain((ops::Bound::Excluded(1), ops::Bound::Included(2))).collect();
+
+        assert_eq!(drained, [3]);
+        assert_eq!(bank, [1, 2, 4]);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn try_slice_range_() {
+        use ops::Bound;
+
+        assert_eq!(try_slice_range(.., ..10), Some(0..10));
+        assert_eq!(try_slice_range(1..=5, ..10), Some(1..6));
+        assert_eq!(try_slice_range((Bound::Excluded(1), Bound::Unbounded), ..10), Some(2..10));
+        assert_eq!(try_slice_range(5..0, ..10), None);
+        assert_eq!(try_slice_range(0..11, ..10), None);
+        assert_eq!(try_slice_range(..=usize::MAX, ..10), None);
     }
 
     #[test]
@@ -143,6 +297,63 @@ mod tests {
         assert_eq!(drain.len(), 2);
     }
 
+    #[test]
+    fn drain_whole() {
+        let mut bank = BankVec::<String, 2>::from(["a", "b", "c"].map(String::from));
+        let mut drain = bank.drain(..);
+        assert_eq!(drain.next_back().as_deref(), Some("c"));
+        drop(drain);
+        assert!(bank.is_empty());
+
+        bank.push("d".to_string());
+        assert_eq!(bank, ["d".to_string()]);
+    }
+
+    #[test]
+    fn drain_forget() {
+        let mut bank = BankVec::<String, 2>::from(["a", "b", "c", "d"].map(String::from));
+        let mut drain = bank.drain(1..3);
+        assert_eq!(drain.next().as_deref(), Some("b"));
+        mem::forget(drain);
+
+        // The tail is lost with the drain, but nothing is read twice
+        assert_eq!(bank.as_slice(), ["a"]);
+        bank.push("e".to_string());
+        assert_eq!(bank.as_slice(), ["a", "e"]);
+    }
+
+    // Panics when dropped if armed
+    struct Bomb(bool, i32);
+
+    impl Drop for Bomb {
+        fn drop(&mut self) {
+            if self.0 { panic!("boom") }
+        }
+    }
+
+    #[test]
+    fn drain_drop_panic() {
+        let mut bank = BankArr::<Bomb, 4>::from([0, 1, 2, 3].map(|i| Bomb(i == 1, i)));
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| drop(bank.drain(1..3))));
+        assert!(result.is_err());
+
+        // The tail is moved back despite the panic
+        let values: Vec<_> = bank.iter().map(|bomb| bomb.1).collect();
+        assert_eq!(values, [0, 3]);
+    }
+
+    #[test]
+    fn drain_iter_panic() {
+        let mut bank = BankVec::<String, 2>::from(["a", "b", "c", "d"].map(String::from));
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            for s in bank.drain(..2) {
+                assert_ne!(s, "b");
+            }
+        }));
+        assert!(result.is_err());
+        assert_eq!(bank.as_slice(), ["c", "d"]);
+    }
+
     #[test]
     fn drain_drop() {
         let mut bank = BankVec::<i32, 3>::from([1, 2, 3, 4]);
@@ -0,0 +1,323 @@
+use crate::{Bank, BankArr, BankVec, errors::DecodeError};
+
+
+const HEX: &[u8; 16] = b"0123456789abcdef";
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn encode_hex<B: Bank<u8>>(src: &[u8], dst: &mut B) {
+    for &byte in src {
+        dst.push(HEX[(byte >> 4) as usize]);
+        dst.push(HEX[(byte & 0xf) as usize]);
+    }
+}
+
+fn hex_len(src: &[u8]) -> Result<usize, DecodeError> {
+    match src.len() % 2 {
+        0 => Ok(src.len() / 2),
+        _ => Err(DecodeError::InvalidLength),
+    }
+}
+
+const fn hex_value(byte: u8) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' => Some(byte - b'a' + 10),
+        b'A'..=b'F' => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+// `dst` must have room for `hex_len(src)` more bytes
+fn decode_hex<B: Bank<u8>>(src: &[u8], dst: &mut B) -> Result<(), DecodeError> {
+    for (index, &byte) in src.iter().enumerate() {
+        let digit = hex_value(byte).ok_or(DecodeError::InvalidByte { index, byte })?;
+        match index % 2 {
+            0 => dst.push(digit << 4),
+            _ => *dst.as_mut_slice().last_mut().unwrap() |= digit,
+        }
+    }
+    Ok(())
+}
+
+fn encode_base64<B: Bank<u8>>(src: &[u8], dst: &mut B) {
+    for chunk in src.chunks(3) {
+        let byte = |idx: usize| chunk.get(idx).copied().unwrap_or(0) as u32;
+        let bits = byte(0) << 16 | byte(1) << 8 | byte(2);
+
+        // A chunk of n bytes takes n + 1 digits, padded out to four
+        for idx in 0..4 {
+            dst.push(match idx <= chunk.len() {
+                true => BASE64[(bits >> (18 - 6 * idx) & 0x3f) as usize],
+                false => b'=',
+            });
+        }
+    }
+}
+
+// Returns the number of bytes `src` decodes to and the length of its digits
+// without padding.
+fn base64_len(src: &[u8]) -> Result<(usize, usize), DecodeError> {
+    if !src.len().is_multiple_of(4) { return Err(DecodeError::InvalidLength) }
+    let padding = src.iter().rev().take(2).take_while(|&&byte| byte == b'=').count();
+    Ok((src.len() / 4 * 3 - padding, src.len() - padding))
+}
+
+const fn base64_value(byte: u8) -> Option<u32> {
+    match byte {
+        b'A'..=b'Z' => Some((byte - b'A') as u32),
+        b'a'..=b'z' => Some((byte - b'a') as u32 + 26),
+        b'0'..=b'9' => Some((byte - b'0') as u32 + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+// `dst` must have room for the bytes `src` decodes to
+fn decode_base64<B: Bank<u8>>(src: &[u8], dst: &mut B) -> Result<(), DecodeError> {
+    let (len, digits) = base64_len(src)?;
+
+    for (quad, chunk) in src.chunks_exact(4).enumerate() {
+        let mut bits = 0;
+        for (idx, &byte) in chunk.iter().enumerate() {
+            let index = quad * 4 + idx;
+            let value = match index < digits {
+                true => base64_value(byte).ok_or(DecodeError::InvalidByte { index, byte })?,
+                false => 0,
+            };
+            bits = bits << 6 | value;
+        }
+
+        let count = (len - quad * 3).min(3);
+        // Bits past the last byte must be zero, so each input has one decoding
+        if bits & (0xff_ffff >> (8 * count)) != 0 {
+            return Err(DecodeError::InvalidByte { index: digits - 1, byte: src[digits - 1] })
+        }
+        for idx in 0..count {
+            dst.push((bits >> (16 - 8 * idx)) as u8);
+        }
+    }
+    Ok(())
+}
+
+
+impl<const C: usize> BankArr<u8, C> {
+
+    /// Appends the bank's bytes to `dst` as lowercase hex, two digits a byte.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `dst` can't hold them, as `push` would.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let mut hex = BankArr::<u8, 8>::new();
+    /// BankArr::<u8, 4>::from([0xde, 0xad, 0xbe, 0xef]).encode_hex_into(&mut hex);
+    /// assert_eq!(hex, *b"deadbeef");
+    /// ```
+    #[track_caller]
+    pub fn encode_hex_into<B: Bank<u8>>(&self, dst: &mut B) { encode_hex(self, dst) }
+
+    /// Decodes hex digits of either case into a bank.
+    /// 
+    /// # Errors
+    /// 
+    /// Fails if `hex` has an odd length, holds anything besides hex digits or
+    /// decodes to more than `C` bytes.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::{BankArr, DecodeError};
+    /// 
+    /// let bank = BankArr::<u8, 4>::from_hex(b"DEADbeef").unwrap();
+    /// assert_eq!(bank, [0xde, 0xad, 0xbe, 0xef]);
+    /// 
+    /// assert_eq!(BankArr::<u8, 2>::from_hex(b"beef00"), Err(DecodeError::Full));
+    /// ```
+    pub fn from_hex(hex: &[u8]) -> Result<Self, DecodeError> {
+        if hex_len(hex)? > C { return Err(DecodeError::Full) }
+        let mut bank = Self::new();
+        decode_hex(hex, &mut bank).map(|_| bank)
+    }
+
+    /// Appends the bank's bytes to `dst` as padded Base64, in the standard
+    /// alphabet.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `dst` can't hold them, as `push` would.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let mut encoded = BankArr::<u8, 8>::new();
+    /// BankArr::<u8, 4>::from(*b"bank").encode_base64_into(&mut encoded);
+    /// assert_eq!(encoded, *b"YmFuaw==");
+    /// ```
+    #[track_caller]
+    pub fn encode_base64_into<B: Bank<u8>>(&self, dst: &mut B) { encode_base64(self, dst) }
+
+    /// Decodes padded Base64, in the standard alphabet, into a bank.
+    /// 
+    /// # Errors
+    /// 
+    /// Fails if `base64` isn't a whole number of padded groups of four digits,
+    /// holds anything besides Base64 digits or decodes to more than `C` bytes.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let bank = BankArr::<u8, 4>::from_base64(b"YmFuaw==").unwrap();
+    /// assert_eq!(bank, *b"bank");
+    /// ```
+    pub fn from_base64(base64: &[u8]) -> Result<Self, DecodeError> {
+        if base64_len(base64)?.0 > C { return Err(DecodeError::Full) }
+        let mut bank = Self::new();
+        decode_base64(base64, &mut bank).map(|_| bank)
+    }
+}
+
+impl<const C: usize> BankVec<u8, C> {
+
+    /// Appends the bank's bytes to `dst` as lowercase hex, two digits a byte.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `dst` can't hold them, as `push` would.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut hex = BankVec::<u8, 4>::new();
+    /// BankVec::<u8, 4>::from([0xde, 0xad, 0xbe, 0xef]).encode_hex_into(&mut hex);
+    /// assert_eq!(hex, *b"deadbeef");
+    /// ```
+    #[track_caller]
+    pub fn encode_hex_into<B: Bank<u8>>(&self, dst: &mut B) { encode_hex(self, dst) }
+
+    /// Decodes hex digits of either case into a bank, on the heap if there
+    /// are more than `C` bytes.
+    /// 
+    /// # Errors
+    /// 
+    /// Fails if `hex` has an odd length or holds anything besides hex digits.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::{BankVec, DecodeError};
+    /// 
+    /// let bank = BankVec::<u8, 2>::from_hex(b"DEADbeef").unwrap();
+    /// assert_eq!(bank, [0xde, 0xad, 0xbe, 0xef]);
+    /// 
+    /// let err = DecodeError::InvalidByte { index: 1, byte: b'g' };
+    /// assert_eq!(BankVec::<u8, 2>::from_hex(b"0g"), Err(err));
+    /// ```
+    pub fn from_hex(hex: &[u8]) -> Result<Self, DecodeError> {
+        let mut bank = Self::new();
+        bank.reserve_exact(hex_len(hex)?);
+        decode_hex(hex, &mut bank).map(|_| bank)
+    }
+
+    /// Appends the bank's bytes to `dst` as padded Base64, in the standard
+    /// alphabet.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `dst` can't hold them, as `push` would.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut encoded = BankVec::<u8, 4>::new();
+    /// BankVec::<u8, 4>::from(*b"bank").encode_base64_into(&mut encoded);
+    /// assert_eq!(encoded, *b"YmFuaw==");
+    /// ```
+    #[track_caller]
+    pub fn encode_base64_into<B: Bank<u8>>(&self, dst: &mut B) { encode_base64(self, dst) }
+
+    /// Decodes padded Base64, in the standard alphabet, into a bank, on the
+    /// heap if there are more than `C` bytes.
+    /// 
+    /// # Errors
+    /// 
+    /// Fails if `base64` isn't a whole number of padded groups of four digits
+    /// or holds anything besides Base64 digits.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let bank = BankVec::<u8, 2>::from_base64(b"YmFuaw==").unwrap();
+    /// assert_eq!(bank, *b"bank");
+    /// ```
+    pub fn from_base64(base64: &[u8]) -> Result<Self, DecodeError> {
+        let mut bank = Self::new();
+        bank.reserve_exact(base64_len(base64)?.0);
+        decode_base64(base64, &mut bank).map(|_| bank)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    // The test vectors of RFC 4648
+    const VECTORS: [(&[u8], &[u8], &[u8]); 7] = [
+        (b"", b"", b""),
+        (b"f", b"66", b"Zg=="),
+        (b"fo", b"666f", b"Zm8="),
+        (b"foo", b"666f6f", b"Zm9v"),
+        (b"foob", b"666f6f62", b"Zm9vYg=="),
+        (b"fooba", b"666f6f6261", b"Zm9vYmE="),
+        (b"foobar", b"666f6f626172", b"Zm9vYmFy"),
+    ];
+
+    #[test]
+    fn round_trip() {
+        for (bytes, hex, base64) in VECTORS {
+            let mut bank = BankArr::<u8, 6>::new();
+            bank.extend_from_slice(bytes);
+
+            let mut encoded = BankVec::<u8, 4>::new();
+            bank.encode_hex_into(&mut encoded);
+            assert_eq!(encoded, hex);
+            assert_eq!(BankArr::<u8, 6>::from_hex(hex).unwrap(), bytes);
+
+            let mut encoded = BankArr::<u8, 8>::new();
+            BankVec::<u8, 2>::from_slice(bytes).encode_base64_into(&mut encoded);
+            assert_eq!(encoded, base64);
+            assert_eq!(BankVec::<u8, 2>::from_base64(base64).unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn decode_errors() {
+        let invalid = |index, byte| Err(DecodeError::InvalidByte { index, byte });
+
+        assert_eq!(BankVec::<u8, 4>::from_hex(b"abc"), Err(DecodeError::InvalidLength));
+        assert_eq!(BankVec::<u8, 4>::from_hex(b"ab x"), invalid(2, b' '));
+        assert_eq!(BankArr::<u8, 1>::from_hex(b"zzzz"), Err(DecodeError::Full));
+
+        assert_eq!(BankVec::<u8, 4>::from_base64(b"Zg="), Err(DecodeError::InvalidLength));
+        assert_eq!(BankVec::<u8, 4>::from_base64(b"Z=g="), invalid(1, b'='));
+        assert_eq!(BankVec::<u8, 4>::from_base64(b"Zg==Zg=="), invalid(2, b'='));
+        assert_eq!(BankVec::<u8, 4>::from_base64(b"Zh=="), invalid(1, b'h'));
+        assert_eq!(BankArr::<u8, 2>::from_base64(b"Zm9v"), Err(DecodeError::Full));
+    }
+}
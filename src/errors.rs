@@ -1,22 +1,88 @@
-use std::{alloc::{Layout, LayoutError}, fmt};
+use std::{alloc::{Layout, LayoutError}, error::Error, fmt};
 
 
-#[derive(Debug, Clone)]
-pub struct BankFullError {}
+/// The error returned when an element doesn't fit into a full bank.
+/// 
+/// Holds onto the rejected element so it can be recovered with
+/// [`element`](BankFullError::element).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BankFullError<T = ()> {
+    element: T,
+}
+
+impl<T> BankFullError<T> {
+
+    #[inline]
+    pub(crate) const fn new(element: T) -> Self { Self { element } }
+
+    /// Returns the element which didn't fit into the bank.
+    #[inline]
+    pub fn element(self) -> T { self.element }
+
+    /// Discards the rejected element, leaving just the error.
+    #[inline]
+    pub fn simplify(self) -> BankFullError { BankFullError { element: () } }
+}
 
 // Theres no reason to provide `test` coverage for these implementations.
 
 #[cfg(not(tarpaulin_include))]
-impl fmt::Display for BankFullError {
+impl<T> fmt::Display for BankFullError<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "bank is full")
     }
 }
 
-#[derive(Debug, Clone)]
+#[cfg(not(tarpaulin_include))]
+impl<T: fmt::Debug> Error for BankFullError<T> {}
+
+
+/// The error returned by `try_insert` when an element can't be inserted.
+/// 
+/// Either variant hands back the rejected element, see
+/// [`element`](InsertError::element).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InsertError<T> {
+    /// The insertion index was greater than the bank's length.
+    OutOfBounds(T),
+    /// There was no room for the element.  For a [`BankVec`](crate::BankVec) 
+    /// this means growing its allocation failed.
+    Full(T),
+}
+
+impl<T> InsertError<T> {
+
+    /// Returns the element which couldn't be inserted.
+    #[inline]
+    pub fn element(self) -> T {
+        match self {
+            Self::OutOfBounds(element) | Self::Full(element) => element
+        }
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T> fmt::Display for InsertError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfBounds(_) => write!(f, "insertion index out of bounds"),
+            Self::Full(_) => write!(f, "bank is full"),
+        }
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: fmt::Debug> Error for InsertError<T> {}
+
+/// The error returned when a [`BankVec`](crate::BankVec) fails to reserve
+/// heap memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AllocErr {
+    /// The requested capacity exceeded `usize::MAX`.
     Overflow,
+    /// The requested capacity couldn't be described by a valid [`Layout`].
     Layout,
+    /// The allocator failed to provide memory for `layout`.
     Alloc { layout: Layout }
 }
 
@@ -36,3 +102,65 @@ impl fmt::Display for AllocErr {
     }
 }
 
+#[cfg(not(tarpaulin_include))]
+impl Error for AllocErr {}
+
+
+/// The error returned when parsing a delimiter-joined bank fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseJoinedError<E> {
+    /// An item failed to parse.
+    Item(E),
+    /// There were more items than the bank can hold.
+    Full,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<E: fmt::Display> fmt::Display for ParseJoinedError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Item(err) => write!(f, "failed to parse item: {err}"),
+            Self::Full => write!(f, "too many items for bank"),
+        }
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<E: Error + 'static> Error for ParseJoinedError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Item(err) => Some(err),
+            Self::Full => None,
+        }
+    }
+}
+
+
+/// The error returned when decoding hex or Base64 into a byte bank fails.
+#[cfg(feature = "encoding")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The input held `byte` at `index`, which isn't a valid digit there.
+    InvalidByte { index: usize, byte: u8 },
+    /// The input's length isn't a multiple of two digits for hex, or of four
+    /// for Base64.
+    InvalidLength,
+    /// There were more decoded bytes than the bank can hold.
+    Full,
+}
+
+#[cfg(feature = "encoding")]
+#[cfg(not(tarpaulin_include))]
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidByte { index, byte } => write!(f, "invalid byte {byte:#04x} at index {index}"),
+            Self::InvalidLength => write!(f, "invalid input length"),
+            Self::Full => write!(f, "too many decoded bytes for bank"),
+        }
+    }
+}
+
+#[cfg(feature = "encoding")]
+#[cfg(not(tarpaulin_include))]
+impl Error for DecodeError {}
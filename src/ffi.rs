@@ -0,0 +1,200 @@
+//! Helpers for passing banks across a C boundary.
+//! 
+//! [`BankArr`] is `#[repr(C)]`, laid out as its element array followed by a
+//! `usize` length, so the aliases in this module can be handed straight to
+//! `cbindgen`.  Only the first `len` elements of the array are initialized.
+
+use std::slice;
+
+use crate::{BankArr, BankVec};
+
+
+/// A `BankArr<u8, 16>`.
+pub type ByteBank16 = BankArr<u8, 16>;
+/// A `BankArr<u8, 32>`.
+pub type ByteBank32 = BankArr<u8, 32>;
+/// A `BankArr<u8, 64>`.
+pub type ByteBank64 = BankArr<u8, 64>;
+/// A `BankArr<u8, 128>`.
+pub type ByteBank128 = BankArr<u8, 128>;
+/// A `BankArr<u8, 256>`.
+pub type ByteBank256 = BankArr<u8, 256>;
+
+/// Borrows `len` elements from `ptr` as a slice, allowing a null `ptr` when
+/// `len` is zero.
+#[inline]
+unsafe fn c_slice<'a, T>(ptr: *const T, len: usize) -> &'a [T] {
+    match len {
+        0 => &[],
+        _ => {
+            strict_assert!(!ptr.is_null() && ptr.is_aligned(), "C pointer is null or misaligned");
+            unsafe { slice::from_raw_parts(ptr, len) }
+        }
+    }
+}
+
+impl<T, const C: usize> BankArr<T, C> {
+
+    /// Returns a raw pointer to the bank's elements along with its length.
+    /// 
+    /// The pointer is valid for as long as the bank is neither moved nor
+    /// modified.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let bank = BankArr::<u8, 4>::from([1, 2]);
+    /// let (ptr, len) = bank.as_ffi_parts();
+    /// assert_eq!(unsafe { *ptr.add(1) }, 2);
+    /// assert_eq!(len, 2);
+    /// ```
+    #[inline]
+    pub const fn as_ffi_parts(&self) -> (*const T, usize) {
+        (self.as_slice().as_ptr(), self.len)
+    }
+
+    /// Returns a raw mutable pointer to the bank's elements along with its
+    /// length.
+    /// 
+    /// The pointer is valid for as long as the bank is neither moved nor
+    /// modified through any other path.
+    #[inline]
+    pub const fn as_ffi_parts_mut(&mut self) -> (*mut T, usize) {
+        (self.as_mut_slice().as_mut_ptr(), self.len)
+    }
+}
+
+impl<T: Copy, const C: usize> BankArr<T, C> {
+
+    /// Creates a bank by copying `len` elements from `ptr`.
+    /// 
+    /// # Safety
+    /// 
+    /// `ptr` must be valid for reads of `len` properly aligned, initialized
+    /// elements.  `ptr` may be null only if `len` is zero.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `len` exceeds `C`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let data = [1u8, 2, 3];
+    /// let bank = unsafe { BankArr::<u8, 4>::from_c_parts(data.as_ptr(), data.len()) };
+    /// assert_eq!(bank, [1, 2, 3]);
+    /// ```
+    #[track_caller]
+    pub unsafe fn from_c_parts(ptr: *const T, len: usize) -> Self {
+        let mut bank = Self::new();
+        bank.extend_from_slice(unsafe { c_slice(ptr, len) });
+        bank
+    }
+}
+
+impl<T, const C: usize> BankVec<T, C> {
+
+    /// Returns a raw pointer to the bank's elements along with its length.
+    /// 
+    /// The pointer is valid for as long as the bank is neither moved nor
+    /// modified.  Moving a bank that is on the heap does not invalidate it.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let bank = BankVec::<u8, 1>::from([1, 2]);
+    /// let (ptr, len) = bank.as_ffi_parts();
+    /// assert_eq!(unsafe { *ptr.add(1) }, 2);
+    /// assert_eq!(len, 2);
+    /// ```
+    #[inline]
+    pub fn as_ffi_parts(&self) -> (*const T, usize) {
+        let slice = self.as_slice();
+        (slice.as_ptr(), slice.len())
+    }
+
+    /// Returns a raw mutable pointer to the bank's elements along with its
+    /// length.
+    /// 
+    /// The pointer is valid for as long as the bank is neither moved nor
+    /// modified through any other path.
+    #[inline]
+    pub fn as_ffi_parts_mut(&mut self) -> (*mut T, usize) {
+        let slice = self.as_mut_slice();
+        (slice.as_mut_ptr(), slice.len())
+    }
+}
+
+impl<T: Copy, const C: usize> BankVec<T, C> {
+
+    /// Creates a bank by copying `len` elements from `ptr`, placing it on the
+    /// heap if `len` exceeds `C`.
+    /// 
+    /// # Safety
+    /// 
+    /// `ptr` must be valid for reads of `len` properly aligned, initialized
+    /// elements.  `ptr` may be null only if `len` is zero.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let data = [1u8, 2, 3];
+    /// let bank = unsafe { BankVec::<u8, 2>::from_c_parts(data.as_ptr(), data.len()) };
+    /// assert!(bank.on_heap());
+    /// assert_eq!(bank, [1, 2, 3]);
+    /// ```
+    pub unsafe fn from_c_parts(ptr: *const T, len: usize) -> Self {
+        let mut bank = Self::new();
+        bank.reserve_exact(len);
+        bank.extend_from_slice(unsafe { c_slice(ptr, len) });
+        bank
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+    use super::*;
+
+    #[test]
+    fn ffi_parts() {
+        let mut bank = ByteBank16::from([1, 2, 3]);
+        let (ptr, len) = bank.as_ffi_parts_mut();
+        unsafe { *ptr = 9 };
+        assert_eq!(len, 3);
+        assert_eq!(bank, [9, 2, 3]);
+
+        let mut bank = BankVec::<u8, 2>::from([1, 2, 3]);
+        let (ptr, len) = bank.as_ffi_parts_mut();
+        unsafe { *ptr.add(2) = 9 };
+        assert_eq!(len, 3);
+        assert_eq!(bank, [1, 2, 9]);
+    }
+
+    #[test]
+    fn from_c_parts() {
+        let bank = unsafe { BankArr::<u32, 2>::from_c_parts(ptr::null(), 0) };
+        assert!(bank.is_empty());
+
+        let data = [1u32, 2, 3];
+        let bank = unsafe { BankVec::<u32, 2>::from_c_parts(data.as_ptr(), 3) };
+        assert_eq!(bank.capacity(), 3);
+        assert_eq!(bank, data);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_c_parts_exceeds_capacity() {
+        let data = [1u32, 2, 3];
+        unsafe { BankArr::<u32, 2>::from_c_parts(data.as_ptr(), 3) };
+    }
+}
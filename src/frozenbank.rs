@@ -0,0 +1,194 @@
+
+use std::{borrow::Borrow, fmt, hash::{Hash, Hasher}, ops::Deref, slice, sync::Arc};
+use crate::{BankArr, BankVec};
+
+
+/// An immutable bank, built once and then shared read-only, created by
+/// [`BankVec::freeze`].
+/// 
+/// Elements which fit in `C` stay inline, and cloning copies them as cheaply as
+/// a `BankArr` would.  Spilled elements move into an [`Arc<[T]>`](Arc), so
+/// clones only bump a reference count, never cloning the elements themselves.
+/// Either way a `FrozenBank` is `Send` and `Sync` whenever `T` is.
+/// 
+/// # Examples
+/// ```
+/// use std::thread;
+/// use bankarr::BankVec;
+/// 
+/// let mut bank = BankVec::<u32, 4>::new();
+/// bank.extend(0..100);
+/// let frozen = bank.freeze();
+/// 
+/// thread::scope(|s| {
+///     for _ in 0..4 {
+///         let frozen = frozen.clone();
+///         s.spawn(move || assert_eq!(frozen.iter().sum::<u32>(), 4950));
+///     }
+/// });
+/// ```
+pub struct FrozenBank<T, const C: usize> {
+    repr: Repr<T, C>,
+}
+
+enum Repr<T, const C: usize> {
+    Inline(BankArr<T, C>),
+    Shared(Arc<[T]>),
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: fmt::Debug, const C: usize> fmt::Debug for FrozenBank<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrozenBank")
+            .field("data", &self.as_slice())
+            .field("shared", &self.is_shared())
+            .finish()
+    }
+}
+
+impl<T: Clone, const C: usize> Clone for FrozenBank<T, C> {
+
+    /// Clones the bank, copying inline elements or sharing spilled ones.
+    fn clone(&self) -> Self {
+        let repr = match &self.repr {
+            Repr::Inline(bank) => Repr::Inline(bank.clone()),
+            Repr::Shared(shared) => Repr::Shared(Arc::clone(shared)),
+        };
+        Self { repr }
+    }
+}
+
+impl<T, const C: usize> Default for FrozenBank<T, C> {
+    #[inline]
+    fn default() -> Self { Self { repr: Repr::Inline(BankArr::new()) } }
+}
+
+impl<T, const C: usize> Deref for FrozenBank<T, C> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target { self.as_slice() }
+}
+
+impl<T, const C: usize> AsRef<[T]> for FrozenBank<T, C> {
+    #[inline]
+    fn as_ref(&self) -> &[T] { self }
+}
+
+impl<T, const C: usize> Borrow<[T]> for FrozenBank<T, C> {
+    #[inline]
+    fn borrow(&self) -> &[T] { self }
+}
+
+impl<'a, T, const C: usize> IntoIterator for &'a FrozenBank<T, C> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<T: PartialEq, const C: usize> PartialEq for FrozenBank<T, C> {
+    fn eq(&self, other: &Self) -> bool { self.as_slice() == other.as_slice() }
+}
+
+impl<T: Eq, const C: usize> Eq for FrozenBank<T, C> {}
+
+impl<T: PartialEq, const C: usize> PartialEq<[T]> for FrozenBank<T, C> {
+    fn eq(&self, other: &[T]) -> bool { self.as_slice() == other }
+}
+
+impl<T: PartialEq, const C: usize, const N: usize> PartialEq<[T; N]> for FrozenBank<T, C> {
+    fn eq(&self, other: &[T; N]) -> bool { self.as_slice() == other }
+}
+
+impl<T: Hash, const C: usize> Hash for FrozenBank<T, C> {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_slice().hash(state) }
+}
+
+impl<T, const C: usize> From<BankVec<T, C>> for FrozenBank<T, C> {
+    #[inline]
+    fn from(bank: BankVec<T, C>) -> Self { bank.freeze() }
+}
+
+impl<T, const C: usize> FrozenBank<T, C> {
+
+    /// Extracts a slice containing the entire bank.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        match &self.repr {
+            Repr::Inline(bank) => bank,
+            Repr::Shared(shared) => shared,
+        }
+    }
+
+    /// Returns `true` if the elements live in a shared heap allocation, which
+    /// clones point to rather than copy.
+    #[inline]
+    pub fn is_shared(&self) -> bool { matches!(self.repr, Repr::Shared(_)) }
+}
+
+impl<T, const C: usize> BankVec<T, C> {
+
+    /// Converts the bank into an immutable [`FrozenBank`], which can be cloned
+    /// and shared across threads without cloning a spilled bank's elements.
+    /// 
+    /// An inline bank stays inline, while a spilled one moves its elements into
+    /// an `Arc<[T]>` once, here, rather than on every clone.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let frozen = BankVec::<i32, 2>::from([1, 2, 3]).freeze();
+    /// let copy = frozen.clone();
+    /// 
+    /// assert!(frozen.is_shared());
+    /// assert_eq!(copy.as_ptr(), frozen.as_ptr());
+    /// assert_eq!(copy, [1, 2, 3]);
+    /// ```
+    pub fn freeze(self) -> FrozenBank<T, C> {
+        let repr = match self.on_heap() {
+            true => Repr::Shared(Arc::from(Vec::from(self))),
+            false => {
+                let mut bank = BankArr::new();
+                bank.extend(self);
+                Repr::Inline(bank)
+            }
+        };
+        FrozenBank { repr }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use std::rc::Rc;
+    use super::*;
+
+    #[test]
+    fn freeze() {
+        let frozen = BankVec::<String, 2>::from(["a".to_string(), "b".to_string()]).freeze();
+        assert!(!frozen.is_shared());
+        assert_eq!(frozen.clone(), frozen);
+        assert_eq!(frozen.len(), 2);
+
+        let frozen = FrozenBank::from(BankVec::<_, 2>::from([1, 2, 3]));
+        assert!(frozen.is_shared());
+        assert_eq!(frozen, [1, 2, 3]);
+        assert_eq!(FrozenBank::<i32, 2>::default(), []);
+    }
+
+    #[test]
+    fn shares_elements() {
+        let rc = Rc::new(());
+        let frozen = BankVec::<_, 1>::from([rc.clone(), rc.clone()]).freeze();
+        let copies = [frozen.clone(), frozen.clone()];
+        assert_eq!(Rc::strong_count(&rc), 3);
+
+        drop(frozen);
+        drop(copies);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
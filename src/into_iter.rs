@@ -0,0 +1,182 @@
+use std::{fmt::Debug, iter::FusedIterator, marker::PhantomData, mem, ops, ptr, slice};
+use crate::raw::RawBank;
+
+
+/// An iterator that moves out of a bank.
+/// 
+/// Created by the `into_iter` method on [`BankArr`](crate::BankArr) and
+/// [`BankVec`](crate::BankVec), provided by the [`IntoIterator`] trait.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::BankVec;
+/// 
+/// let bank = BankVec::<i32, 2>::from([1, 2, 3]);
+/// let mut iter = bank.into_iter();
+/// 
+/// assert_eq!(iter.next(), Some(1));
+/// assert_eq!(iter.next_back(), Some(3));
+/// assert_eq!(iter.as_slice(), [2]);
+/// ```
+pub struct IntoIter<T, B: RawBank<T>> {
+    // The bank's length is zeroed while owned by the iterator, so dropping it
+    // only releases its storage.
+    bank: B,
+    alive: ops::Range<usize>,
+    _marker: PhantomData<T>,
+}
+
+impl<T, B: RawBank<T>> IntoIter<T, B> {
+
+    #[inline]
+    pub(crate) fn new(mut bank: B) -> Self {
+        let len = bank.take_len();
+        Self { bank, alive: 0..len, _marker: PhantomData }
+    }
+
+    /// Returns the remaining elements as a slice.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let mut iter = BankArr::<i32, 3>::from([1, 2, 3]).into_iter();
+    /// iter.next();
+    /// assert_eq!(iter.as_slice(), [2, 3]);
+    /// ```
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.bank.raw_ptr().add(self.alive.start), self.alive.len()) }
+    }
+
+    /// Returns the remaining elements as a mutable slice.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let mut iter = BankArr::<i32, 3>::from([1, 2, 3]).into_iter();
+    /// iter.as_mut_slice()[2] = 5;
+    /// assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 5]);
+    /// ```
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.bank.raw_parts().0.as_ptr().add(self.alive.start), self.alive.len()) }
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: Debug, B: RawBank<T>> Debug for IntoIter<T, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.as_slice()).finish()
+    }
+}
+
+impl<T: Clone, B: RawBank<T> + Default + Extend<T>> Clone for IntoIter<T, B> {
+    fn clone(&self) -> Self {
+        let mut bank = B::default();
+        bank.extend(self.as_slice().iter().cloned());
+        Self::new(bank)
+    }
+}
+
+impl<T, B: RawBank<T>> Iterator for IntoIter<T, B> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.alive.next()?;
+        Some(unsafe { self.bank.raw_ptr().add(idx).read() })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { self.alive.size_hint() }
+
+    #[inline]
+    fn count(self) -> usize { self.len() }
+}
+
+impl<T, B: RawBank<T>> DoubleEndedIterator for IntoIter<T, B> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let idx = self.alive.next_back()?;
+        Some(unsafe { self.bank.raw_ptr().add(idx).read() })
+    }
+}
+
+impl<T, B: RawBank<T>> ExactSizeIterator for IntoIter<T, B> {
+    #[inline]
+    fn len(&self) -> usize { self.alive.len() }
+}
+
+impl<T, B: RawBank<T>> FusedIterator for IntoIter<T, B> {}
+
+impl<T, B: RawBank<T>> Drop for IntoIter<T, B> {
+    fn drop(&mut self) {
+        if mem::needs_drop::<T>() {
+            unsafe { ptr::drop_in_place(self.as_mut_slice()) }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{BankArr, BankVec};
+
+    #[test]
+    fn double_ended() {
+        let bank = BankArr::<i32, 4>::from([1, 2, 3, 4]);
+        let iter = bank.into_iter();
+
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.rev().collect::<Vec<_>>(), [4, 3, 2, 1]);
+
+        let bank = BankVec::<i32, 2>::from([1, 2, 3, 4]);
+        let mut iter = bank.into_iter();
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.as_slice(), [2, 3]);
+    }
+
+    #[test]
+    fn fused() {
+        let mut iter = BankVec::<i32, 2>::from([1]).into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn clone() {
+        let mut iter = BankVec::<String, 2>::from(["aa".to_string(), "bb".to_string(), "cc".to_string()])
+            .into_iter();
+        iter.next();
+        let cloned = iter.clone();
+        assert_eq!(iter.collect::<Vec<_>>(), cloned.collect::<Vec<_>>());
+
+        let mut iter = BankArr::<String, 2>::from(["aa".to_string(), "bb".to_string()]).into_iter();
+        iter.next_back();
+        assert_eq!(iter.clone().as_slice(), ["aa".to_string()]);
+    }
+
+    #[test]
+    fn drop_remaining() {
+        use std::rc::Rc;
+
+        let rc = Rc::new(());
+        let mut iter = BankVec::<Rc<()>, 2>::from([rc.clone(), rc.clone(), rc.clone()]).into_iter();
+        iter.next();
+        assert_eq!(Rc::strong_count(&rc), 3);
+        drop(iter);
+        assert_eq!(Rc::strong_count(&rc), 1);
+
+        let mut iter = BankArr::<Rc<()>, 2>::from([rc.clone(), rc.clone()]).into_iter();
+        iter.next_back();
+        drop(iter);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
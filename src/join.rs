@@ -0,0 +1,164 @@
+use std::{fmt, str::FromStr};
+
+use crate::{errors::ParseJoinedError, BankArr, BankVec};
+
+
+/// Displays the elements of a bank separated by a delimiter.
+/// 
+/// Created by [`BankArr::display`] or [`BankVec::display`].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayJoined<'a, T> {
+    items: &'a [T],
+    sep: &'a str,
+}
+
+impl<T: fmt::Display> fmt::Display for DisplayJoined<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut items = self.items.iter();
+        if let Some(first) = items.next() {
+            first.fmt(f)?;
+            for item in items {
+                f.write_str(self.sep)?;
+                item.fmt(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Splits `s` on `sep`, trimming whitespace around each item.  An empty (or
+/// all whitespace) string holds no items.
+#[inline]
+fn split_joined<'a>(s: &'a str, sep: &'a str) -> impl Iterator<Item = &'a str> {
+    let s = s.trim();
+    s.split(sep)
+        .filter(move |_| !s.is_empty())
+        .map(str::trim)
+}
+
+impl<T: fmt::Display, const C: usize> BankArr<T, C> {
+
+    /// Returns a wrapper displaying the elements of the bank separated by `sep`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let bank = BankArr::<i32, 4>::from([1, 2, 3]);
+    /// assert_eq!(bank.display(", ").to_string(), "1, 2, 3");
+    /// ```
+    #[inline]
+    pub fn display<'a>(&'a self, sep: &'a str) -> DisplayJoined<'a, T> {
+        DisplayJoined { items: self.as_slice(), sep }
+    }
+}
+
+impl<T: FromStr, const C: usize> BankArr<T, C> {
+
+    /// Parses a bank from items in `s` separated by `sep`.
+    /// 
+    /// Whitespace surrounding each item is ignored, and an empty string parses
+    /// to an empty bank.  This is the inverse of [`display`](BankArr::display).
+    /// 
+    /// # Errors
+    /// 
+    /// Returns [`ParseJoinedError::Item`] if any item fails to parse, or
+    /// [`ParseJoinedError::Full`] if there are more than `C` items.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankArr;
+    /// 
+    /// let bank = BankArr::<i32, 4>::parse_joined("1, 2, 3", ",").unwrap();
+    /// assert_eq!(bank, [1, 2, 3]);
+    /// 
+    /// assert!(BankArr::<i32, 2>::parse_joined("1, 2, 3", ",").is_err());
+    /// assert!(BankArr::<i32, 4>::parse_joined("1, b", ",").is_err());
+    /// ```
+    pub fn parse_joined(s: &str, sep: &str) -> Result<Self, ParseJoinedError<T::Err>> {
+        let mut bank = Self::new();
+        for item in split_joined(s, sep) {
+            let val = item.parse().map_err(ParseJoinedError::Item)?;
+            bank.try_push(val).map_err(|_| ParseJoinedError::Full)?;
+        }
+        Ok(bank)
+    }
+}
+
+impl<T: fmt::Display, const C: usize> BankVec<T, C> {
+
+    /// Returns a wrapper displaying the elements of the bank separated by `sep`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let bank = BankVec::<i32, 2>::from([1, 2, 3]);
+    /// assert_eq!(bank.display(", ").to_string(), "1, 2, 3");
+    /// ```
+    #[inline]
+    pub fn display<'a>(&'a self, sep: &'a str) -> DisplayJoined<'a, T> {
+        DisplayJoined { items: self.as_slice(), sep }
+    }
+}
+
+impl<T: FromStr, const C: usize> BankVec<T, C> {
+
+    /// Parses a bank from items in `s` separated by `sep`.
+    /// 
+    /// Whitespace surrounding each item is ignored, and an empty string parses
+    /// to an empty bank.  This is the inverse of [`display`](BankVec::display).
+    /// 
+    /// # Errors
+    /// 
+    /// Returns the first error produced while parsing an item.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let bank = BankVec::<i32, 2>::parse_joined("1, 2, 3", ",").unwrap();
+    /// assert_eq!(bank, [1, 2, 3]);
+    /// 
+    /// assert!(BankVec::<i32, 2>::parse_joined("1, b", ",").is_err());
+    /// ```
+    pub fn parse_joined(s: &str, sep: &str) -> Result<Self, T::Err> {
+        let mut bank = Self::new();
+        for item in split_joined(s, sep) {
+            bank.push(item.parse()?);
+        }
+        Ok(bank)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let bank = BankArr::<f32, 3>::from([1.5, 2.0]);
+        assert_eq!(format!("[{:.2}]", bank.display("; ")), "[1.50; 2.00]");
+
+        let bank = BankVec::<i32, 3>::new();
+        assert_eq!(bank.display(", ").to_string(), "");
+    }
+
+    #[test]
+    fn parse_joined() {
+        let bank = BankArr::<i32, 3>::parse_joined(" 1 |2| 3 ", "|").unwrap();
+        assert_eq!(bank, [1, 2, 3]);
+        assert_eq!(BankArr::<i32, 3>::parse_joined("  ", ",").unwrap(), []);
+        assert_eq!(BankArr::<i32, 2>::parse_joined("1,2,3", ","), Err(ParseJoinedError::Full));
+        assert!(matches!(BankArr::<i32, 2>::parse_joined("1,,3", ","), Err(ParseJoinedError::Item(_))));
+
+        let bank = BankVec::<String, 1>::parse_joined("a, b", ",").unwrap();
+        assert_eq!(bank, ["a".to_string(), "b".to_string()]);
+        assert_eq!(bank.display(", ").to_string(), "a, b");
+    }
+}
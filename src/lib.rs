@@ -6,6 +6,90 @@
 //! [`BankVec<T, C>`] is a fixed-size as well, but can exceed `C`, reallocating onto the
 //! heap when doing so.
 //! 
+//! The [`Bank<T>`] trait covers the operations the two share, for code which
+//! accepts either.
+//! 
+//! [`BankRef<'a, T>`] offers the same operations over memory the caller
+//! provides, such as a borrowed `&mut [MaybeUninit<T>]`, its capacity being
+//! the length of that memory.
+//! 
+//! [`BankString<C>`] is a fixed-capacity string over a `BankArr<u8, C>`, which
+//! can be formatted into with `write!`, or built by [`format_bank!`] much as
+//! `format!` builds a `String`.
+//! 
+//! [`BankStr<C>`] is the string counterpart to `BankVec`, storing up to `C` bytes
+//! inline and spilling onto the heap beyond that.
+//! 
+//! [`BankDeque<T, C>`] is a fixed-size double-ended queue, a ring buffer over
+//! the same inline storage as `BankArr`.
+//! 
+//! [`BankVecDeque<T, C>`] is its spilling counterpart, moving onto the heap as a
+//! `VecDeque` once it exceeds `C`.
+//! 
+//! [`BankRing<T, C>`] is a fixed-size circular buffer which overwrites its oldest
+//! element once full, keeping the last `C` pushed.  [`RollingBank<T, C>`] keeps
+//! the sum, minimum and maximum of such a window up to date as samples arrive.
+//! 
+//! [`BankMap<K, V, C>`] and [`BankSet<T, C>`] are fixed-size maps and sets,
+//! searching their inline pairs by linear scan.  [`SortedBankMap<K, V, C>`] and
+//! [`SortedBankSet<T, C>`] keep them ordered by key instead, finding them by
+//! binary search.
+//! 
+//! [`BankLru<K, V, C>`] is a fixed-size cache, evicting the least recently
+//! used pair to make room for a new one.
+//! 
+//! [`BankHashMap<K, V, C>`] is a hash map holding up to `C` pairs inline with
+//! linear probing, moving them into a [`HashMap`](std::collections::HashMap)
+//! once it grows past `C`.
+//! 
+//! [`BankHeap<T, C>`] is a fixed-size priority queue, a binary max-heap over
+//! inline storage which can be drained or sorted in priority order without
+//! allocating.
+//! 
+//! [`SortedBankVec<T, C>`] is a `BankVec` which keeps its elements in order,
+//! answering `contains` and `range` queries by binary search.
+//! 
+//! [`SegmentedBank<T, C>`] grows by boxed `BankArr` segments rather than
+//! reallocating, so its elements never move.
+//! 
+//! [`BankBitSet<BITS>`] is a fixed-size set of flags packed into inline words,
+//! with bulk `&`, `|` and `^` operations.
+//! 
+//! [`FrozenBank<T, C>`] is an immutable `BankVec`, from [`BankVec::freeze`],
+//! whose spilled elements are shared behind an `Arc` rather than cloned.
+//! 
+//! [`BankPool<T, C>`] keeps the heap buffers of dropped `BankVec`s for later
+//! spills to reuse, sparing the allocator in repeated spill-and-discard cycles.
+//! 
+//! [`BankCow<'a, T, C>`] borrows a slice until its first mutation, then copies
+//! it into a `BankVec`.
+//! 
+//! [`BankSpsc<T, C>`] is a bounded, lock-free queue passing elements from one
+//! thread to another over inline storage.  [`BankQueue<T, C>`] is its
+//! multi-producer, multi-consumer counterpart.
+//! 
+//! [`StaticBank<T, C>`] wraps a `BankArr` for use as a `static`, lending it out
+//! to one closure at a time behind an atomic flag.
+//! 
+//! [`BankBox<T, BYTES>`] is a box which stores values of up to `BYTES` bytes
+//! inline, `dyn Trait` objects included, and anything larger on the heap.
+//! 
+//! [`BankInterner<B, N>`] maps short byte strings to dense [`Symbol`]s, keeping up
+//! to `B` bytes of strings and `N` symbols inline.
+//! 
+//! [`Banks<R, C>`] reads an [`io::Read`](std::io::Read) in chunks of `C` bytes,
+//! each a `BankArr<u8, C>`, and [`IterBanks<I, C>`] batches an iterator's items
+//! likewise.
+//! 
+//! [`Bank2D<T, W, H>`] is a `W` by `H` grid stored inline, indexed by `(x, y)`
+//! and walked by row or by column.
+//! 
+//! [`BankArena<BYTES>`] is a bump allocator handing out values of any type from
+//! `BYTES` bytes of inline storage, all freed at once by a reset.
+//! 
+//! [`Banklist<T, C>`] is a slab built on a `BankVec`, handing out stable [`Key`]s
+//! to the values it stores.
+//! 
 //! 
 //! # Performance
 //! 
@@ -23,6 +107,21 @@
 //! managing its variants but especially when tranforming into a heap allocation. Spilling over `C` requires
 //! *O*(`C`) time complexity to move over to the heap.
 //! 
+//! # Panic-free API
+//! 
+//! The methods of [`BankArr`] and [`BankVec`] which panic on a full bank or an
+//! out-of-bounds index or range have a `try_` counterpart returning a [`Result`]
+//! or [`Option`] instead, e.g. [`BankArr::try_insert`], [`BankVec::try_push`] or
+//! [`BankVec::try_drain`].  `BankArr`'s panicking trait impls have inherent
+//! counterparts too: [`BankArr::try_extend`] for [`Extend`], and
+//! [`BankArr::try_from_array`] and [`BankArr::try_from_vec`] for [`From`].  Errors
+//! that reject an element, such as [`BankFullError`] and [`InsertError`], hand it
+//! back to the caller.
+//! 
+//! The rest of the crate's types, and `BankVec`'s trait impls, still panic, and
+//! a `BankVec` still aborts if it fails to allocate outside of its `try_`
+//! methods.
+//! 
 //! # Similar Crates
 //! 
 //! This crate was inspired heavily from a few existing crate with similar intent,
@@ -33,15 +132,166 @@
 //! is generally equivalent, but in some cases this crate is favored.
 //! 
 //! 
+//! # Features
+//! 
+//! * `bytes`: Implements `bytes::BufMut` for byte banks, and
+//!   `bytes::Buf` for a `Drain` over them.
+//! * `futures-io`, `tokio`: Implements the async IO traits of either crate for
+//!   [`BankCursor`].
+//! * `rand`: Adds `from_rng` and `fill_random` to both banks, and re-exports
+//!   `rand::seq::SliceRandom` for shuffling and choosing elements.
+//! * `encoding`: Adds hex and Base64 encoding of byte banks into any other
+//!   bank, and decoding back with `from_hex` and `from_base64`.
+//! * `derive`: Adds `#[derive(BankSoA)]`, generating a structure-of-arrays bank
+//!   for a struct, one `BankArr` per field with rows pushed and removed whole.
+//! * `serde`: Implements `Serialize` and `Deserialize` for [`Banklist`],
+//!   [`Key`] and [`BankString`].  A list is written as a map of keys to values,
+//!   so stored keys stay valid once it's read back, and a string as a plain string.
+//! * `specialization` *(nightly)*: Clones banks of `Copy` types with a single
+//!   `memcpy` rather than element by element.
+//! * `simd` *(nightly)*: Searches banks of `u8`, `u16`, `u32` and `u64` with
+//!   `core::simd`, speeding up `position`, `contains` and `remove_item`.
+//! * `const-mut` *(nightly)*: Makes [`BankArr::push`] and [`BankArr::clear`]
+//!   `const fn`, so banks can be filled and emptied inside `const` blocks.  `pop`,
+//!   `try_push` and `as_slice` are `const` regardless.
+//! * `may-dangle` *(nightly)*: Lets a `BankArr` or `BankVec` be dropped after
+//!   borrows held by its elements end, as a `Vec` can, so a bank of
+//!   `&mut` references may be declared before the values they point to.
+//! * `instrument`: Has each `BankVec` count its spills onto the heap and the
+//!   greatest length it reached, returned with its heap usage by
+//!   `BankVec::stats`, as evidence for choosing `C`.
+//! * `nontemporal`: Moves a `BankVec` of more than 256 KiB onto the heap with
+//!   streaming stores on x86-64, sparing the cache the copy.
+//! * `strict-asserts`: Checks the safety requirements of unchecked methods
+//!   such as [`BankArr::push_unchecked`] and [`BankVec::set_len`] in release
+//!   builds too, panicking on misuse rather than risking undefined behavior.
+//!   They're always checked in debug builds.
+//! * `stack-guard`: Fails compilation wherever a `BankArr` or `BankVec` would
+//!   store more than 64 KiB inline, guarding against banks too large for the
+//!   stack.  Set `BANKARR_MAX_INLINE_BYTES` while building to change the limit.
+//! 
 
+#![cfg_attr(feature = "specialization", allow(incomplete_features), feature(specialization))]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+#![cfg_attr(feature = "const-mut", allow(internal_features), feature(const_trait_impl, const_destruct, const_drop_in_place, const_eval_select, core_intrinsics))]
+#![cfg_attr(feature = "may-dangle", feature(dropck_eyepatch))]
+
+// Checks the contract of an unsafe method.  Like `debug_assert!`, except the
+// `strict-asserts` feature keeps the check in release builds as well.
+macro_rules! strict_assert {
+    ($($arg:tt)*) => {
+        if cfg!(any(debug_assertions, feature = "strict-asserts")) { assert!($($arg)*) }
+    };
+}
+
+mod bank;
+pub mod bank2d;
+mod bankarena;
 mod bankarray;
+pub mod banks;
+pub mod bankbitset;
+mod bankbox;
+mod bankcow;
+pub mod bankdeque;
+pub mod bankhashmap;
+pub mod bankheap;
+pub mod bankinterner;
+pub mod banklist;
+pub mod banklru;
+pub mod bankmap;
+pub mod bankpool;
+mod bankqueue;
+mod bankring;
+pub mod bankspsc;
+mod bankstr;
+mod bankstring;
 mod bankvec;
+pub mod bankvecdeque;
+mod chunks;
+mod cursor;
 mod drain;
 pub(crate)mod errors;
+pub mod ffi;
+mod frozenbank;
+mod into_iter;
+mod join;
+mod raw;
+mod rollingbank;
+pub mod segmentedbank;
+mod sort;
+mod sortedbankvec;
+mod staticbank;
+mod spec;
+mod stack_guard;
+pub mod storage;
+mod writer;
+
+#[cfg(feature = "bytes")]
+mod buf;
+
+#[cfg(feature = "rand")]
+mod random;
+
+#[cfg(feature = "encoding")]
+mod encoding;
 
 
+pub use bank::Bank;
+pub use bank2d::Bank2D;
+pub use bankarena::BankArena;
 pub use bankarray::BankArr;
+pub use banks::{Banks, IterBanks};
+pub use bankbitset::BankBitSet;
+pub use bankbox::BankBox;
+pub use bankcow::BankCow;
+pub use bankdeque::BankDeque;
 pub use bankvec::BankVec;
+#[cfg(feature = "instrument")]
+pub use bankvec::SpillStats;
+pub use bankvecdeque::BankVecDeque;
+pub use banklist::{Banklist, Key};
+pub use banklru::BankLru;
+pub use bankhashmap::BankHashMap;
+pub use bankheap::BankHeap;
+pub use bankinterner::{BankInterner, Symbol};
+pub use bankmap::{BankMap, BankSet, SortedBankMap, SortedBankSet};
+pub use bankpool::BankPool;
+pub use bankqueue::BankQueue;
+pub use bankring::BankRing;
+pub use bankspsc::BankSpsc;
+pub use bankstr::BankStr;
+pub use bankstring::BankString;
+pub use cursor::BankCursor;
+pub use rollingbank::{RollingBank, Sample};
+pub use frozenbank::FrozenBank;
+pub use segmentedbank::SegmentedBank;
+pub use sortedbankvec::SortedBankVec;
+pub use staticbank::StaticBank;
+pub use storage::{BankRef, StorageBank};
+pub use into_iter::IntoIter;
+pub use drain::DrainTarget;
+pub use writer::{FmtWriter, TailWriter};
+pub use join::DisplayJoined;
+pub use errors::{AllocErr, BankFullError, InsertError, ParseJoinedError};
+#[cfg(feature = "encoding")]
+pub use errors::DecodeError;
+
+/// Re-exported from `rand` so `choose`, `shuffle` and friends can be called
+/// directly on any bank.
+#[cfg(feature = "rand")]
+pub use rand::seq::SliceRandom;
+
+#[cfg(feature = "derive")]
+pub use bankarr_derive::BankSoA;
+
+// Items the derive macros' expansions rely on, which aren't public API.
+#[doc(hidden)]
+pub mod __private {
+    use crate::BankFullError;
+
+    #[inline]
+    pub const fn bank_full<T>(element: T) -> BankFullError<T> { BankFullError::new(element) }
+}
 
 
 #[cfg(test)]
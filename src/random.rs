@@ -0,0 +1,135 @@
+use rand::{distributions::{Distribution, Standard}, Rng};
+
+use crate::{BankArr, BankVec};
+
+
+impl<T, const C: usize> BankArr<T, C> where Standard: Distribution<T> {
+
+    /// Creates a bank of `n` randomly generated elements.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `n` exceeds `C`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankArr;
+    /// use rand::rngs::mock::StepRng;
+    /// 
+    /// let bank = BankArr::<u8, 4>::from_rng(3, &mut StepRng::new(1, 1));
+    /// assert_eq!(bank.len(), 3);
+    /// ```
+    #[track_caller]
+    pub fn from_rng<R: Rng + ?Sized>(n: usize, rng: &mut R) -> Self {
+        assert!(n <= C, "length {n} exceeds capacity {C}");
+        let mut bank = Self::new();
+        for _ in 0..n { unsafe { bank.push_unchecked(rng.r#gen()) } }
+        bank
+    }
+
+    /// Overwrites every element of the bank with a randomly generated one.
+    /// 
+    /// The length of the bank is unchanged.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankArr;
+    /// use rand::rngs::mock::StepRng;
+    /// 
+    /// let mut bank = BankArr::<u8, 4>::from([0; 2]);
+    /// bank.fill_random(&mut StepRng::new(1, 1));
+    /// assert_eq!(bank.len(), 2);
+    /// ```
+    #[inline]
+    pub fn fill_random<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.iter_mut().for_each(|val| *val = rng.r#gen());
+    }
+}
+
+impl<T, const C: usize> BankVec<T, C> where Standard: Distribution<T> {
+
+    /// Creates a bank of `n` randomly generated elements, placing it on the heap
+    /// if `n` exceeds `C`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// use rand::rngs::mock::StepRng;
+    /// 
+    /// let bank = BankVec::<u8, 4>::from_rng(6, &mut StepRng::new(1, 1));
+    /// assert_eq!(bank.len(), 6);
+    /// assert!(bank.on_heap());
+    /// ```
+    pub fn from_rng<R: Rng + ?Sized>(n: usize, rng: &mut R) -> Self {
+        let mut bank = Self::new();
+        bank.reserve_exact(n);
+        bank.extend((0..n).map(|_| rng.r#gen()));
+        bank
+    }
+
+    /// Overwrites every element of the bank with a randomly generated one.
+    /// 
+    /// The length of the bank is unchanged.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use bankarr::BankVec;
+    /// use rand::rngs::mock::StepRng;
+    /// 
+    /// let mut bank = BankVec::<u8, 4>::from([0; 2]);
+    /// bank.fill_random(&mut StepRng::new(1, 1));
+    /// assert_eq!(bank.len(), 2);
+    /// ```
+    #[inline]
+    pub fn fill_random<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.iter_mut().for_each(|val| *val = rng.r#gen());
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::mock::StepRng, seq::SliceRandom};
+    use super::*;
+
+    #[test]
+    fn from_rng() {
+        let bank = BankArr::<u64, 4>::from_rng(3, &mut StepRng::new(1, 1));
+        assert_eq!(bank, [1, 2, 3]);
+
+        let bank = BankVec::<u64, 2>::from_rng(3, &mut StepRng::new(1, 1));
+        assert_eq!(bank.capacity(), 3);
+        assert_eq!(bank, [1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_rng_exceeds_capacity() {
+        BankArr::<u64, 2>::from_rng(3, &mut StepRng::new(1, 1));
+    }
+
+    #[test]
+    fn fill_random() {
+        let mut bank = BankArr::<u64, 4>::from([0; 2]);
+        bank.fill_random(&mut StepRng::new(5, 1));
+        assert_eq!(bank, [5, 6]);
+
+        let mut bank = BankVec::<u64, 1>::from([0; 2]);
+        bank.fill_random(&mut StepRng::new(5, 1));
+        assert_eq!(bank, [5, 6]);
+    }
+
+    #[test]
+    fn slice_random() {
+        let mut rng = StepRng::new(0, 1);
+        let mut bank = BankVec::<u64, 4>::from([1, 2, 3]);
+        assert!(bank.choose(&mut rng).is_some());
+        bank.shuffle(&mut rng);
+        bank.sort();
+        assert_eq!(bank, [1, 2, 3]);
+    }
+}
@@ -0,0 +1,296 @@
+
+use std::{hint, mem, ops, ptr::{self, NonNull}, slice};
+use crate::drain::Drain;
+
+
+// The panics raised by the banks' hot methods are kept out of line, so the
+// methods themselves stay small enough to inline into their callers.
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn capacity_exceeded(operation: &str, capacity: usize) -> ! {
+    panic!("capacity exceeded during operation `{operation}`: capacity is {capacity}")
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn capacity_overflow() -> ! {
+    panic!("capacity overflow")
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn insert_out_of_bounds(index: usize, len: usize) -> ! {
+    panic!("insertion index (is {index}) should be <= len (is {len})")
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn index_out_of_bounds(index: usize, len: usize) -> ! {
+    panic!("index {index} out of bounds for length {len}")
+}
+
+
+// The shifting work of `RawBank` lives in free functions generic over `T`
+// alone, so banks of the same element type at different capacities share a
+// single copy of it.
+
+// `ptr` must be valid for `len + 1` elements, the first `len` initialized,
+// and `index` <= len
+unsafe fn insert<T>(ptr: NonNull<T>, len: &mut usize, index: usize, element: T) {
+    unsafe {
+        let ptr = ptr.as_ptr().add(index);
+        if index < *len {
+            ptr.copy_to(ptr.add(1), *len - index);
+        }
+        ptr.write(element);
+    }
+    *len += 1;
+}
+
+// `index` must be < len
+unsafe fn remove<T>(ptr: NonNull<T>, len: &mut usize, index: usize) -> T {
+    *len -= 1;
+    unsafe {
+        let ptr = ptr.as_ptr().add(index);
+        let removed = ptr.read();
+        ptr.copy_from(ptr.add(1), *len - index);
+        removed
+    }
+}
+
+// `new_len` must be < len
+unsafe fn truncate<T>(ptr: NonNull<T>, len: &mut usize, new_len: usize) {
+    let tail = ptr::slice_from_raw_parts_mut(unsafe { ptr.as_ptr().add(new_len) }, *len - new_len);
+    // Shorten first, so a panicking destructor can't cause a double drop
+    *len = new_len;
+    unsafe { tail.drop_in_place() }
+}
+
+// Keeps the elements for which `keep` returns true, in order.  This
+// implementation was pulled from `Vec::retain_mut`: should `keep` or a
+// destructor panic, the elements not yet visited are kept.
+unsafe fn retain<T, F: FnMut(&mut T) -> bool>(ptr: NonNull<T>, len: &mut usize, mut keep: F) {
+    struct Guard<'a, T> {
+        ptr: NonNull<T>,
+        len: &'a mut usize,
+        old_len: usize,
+        processed: usize,
+        deleted: usize,
+    }
+
+    impl<T> Drop for Guard<'_, T> {
+        fn drop(&mut self) {
+            let Self { ptr, processed, deleted, old_len, .. } = *self;
+            if deleted > 0 {
+                unsafe { ptr.add(processed - deleted).copy_from(ptr.add(processed), old_len - processed) }
+            }
+            *self.len = old_len - deleted;
+        }
+    }
+
+    // The bank looks empty until the guard sets its length
+    let old_len = mem::take(len);
+    let mut guard = Guard { ptr, len, old_len, processed: 0, deleted: 0 };
+    while guard.processed < old_len {
+        let current = unsafe { ptr.add(guard.processed) };
+        let kept = keep(unsafe { &mut *current.as_ptr() });
+        guard.processed += 1;
+        match kept {
+            true if guard.deleted > 0 => unsafe {
+                current.copy_to_nonoverlapping(current.sub(guard.deleted), 1)
+            },
+            true => {}
+            false => {
+                guard.deleted += 1;
+                unsafe { current.drop_in_place() }
+            }
+        }
+    }
+}
+
+// Moves the `tail_len` elements at `tail_start` down to `len`, closing the gap
+// left by a drain.
+pub(crate) unsafe fn close_gap<T>(ptr: NonNull<T>, len: &mut usize, tail_start: usize, tail_len: usize) {
+    let start = *len;
+    if tail_start != start {
+        unsafe { ptr.add(start).copy_from(ptr.add(tail_start), tail_len) }
+    }
+    *len = start + tail_len;
+}
+
+/// The contiguous buffer behind a bank: a pointer to its elements, the length
+/// of their initialized prefix and the buffer's capacity.
+/// 
+/// Every bank storing its elements in one run of memory implements this, so
+/// the delicate pointer work of inserting, removing and draining is written
+/// once here and shared between them.  The `raw_*` methods skip the bounds and
+/// capacity checks, leaving those, along with growing the buffer, to the
+/// public methods calling them.
+/// 
+/// # Safety
+/// 
+/// `raw_parts` must return a pointer valid for `capacity` elements whose first
+/// `len` are initialized, and `raw_ptr` the same pointer.  Neither may move the
+/// elements.
+pub unsafe trait RawBank<T> {
+    fn raw_ptr(&self) -> *const T;
+
+    fn raw_parts(&mut self) -> (NonNull<T>, &mut usize, usize);
+
+    /// Makes room for up to `additional` more elements where the bank can
+    /// grow, returning how many it now has room for.  A fixed-size bank just
+    /// reports its spare capacity.
+    #[inline]
+    fn raw_reserve(&mut self, additional: usize) -> usize {
+        let _ = additional;
+        let (_, &mut len, cap) = self.raw_parts();
+        cap - len
+    }
+
+    /// Sets the bank's length to zero, returning the previous length.
+    #[inline]
+    fn take_len(&mut self) -> usize { mem::take(self.raw_parts().1) }
+
+    #[inline]
+    fn raw_pop(&mut self) -> Option<T> {
+        let (ptr, len, _) = self.raw_parts();
+        *len = len.checked_sub(1)?;
+        Some(unsafe { ptr.add(*len).read() })
+    }
+
+    // `index` must be <= len and len < capacity
+    #[inline]
+    unsafe fn raw_insert(&mut self, index: usize, element: T) {
+        let (ptr, len, cap) = self.raw_parts();
+        unsafe {
+            hint::assert_unchecked(index <= *len && *len < cap);
+            insert(ptr, len, index, element)
+        }
+    }
+
+    // `index` must be < len
+    #[inline]
+    unsafe fn raw_remove(&mut self, index: usize) -> T {
+        let (ptr, len, _) = self.raw_parts();
+        unsafe {
+            hint::assert_unchecked(index < *len);
+            remove(ptr, len, index)
+        }
+    }
+
+    // `index` must be < len
+    #[inline]
+    unsafe fn raw_swap_remove(&mut self, index: usize) -> T {
+        let (ptr, len, _) = self.raw_parts();
+        unsafe { hint::assert_unchecked(index < *len) }
+        *len -= 1;
+        unsafe { ptr.add(index).replace(ptr.add(*len).read()) }
+    }
+
+    #[inline]
+    fn raw_truncate(&mut self, new_len: usize) {
+        let (ptr, len, _) = self.raw_parts();
+        if new_len >= *len { return }
+
+        match mem::needs_drop::<T>() {
+            true => unsafe { truncate(ptr, len, new_len) },
+            false => *len = new_len,
+        }
+    }
+
+    #[inline]
+    fn raw_retain<F: FnMut(&mut T) -> bool>(&mut self, keep: F) {
+        let (ptr, len, _) = self.raw_parts();
+        unsafe { retain(ptr, len, keep) }
+    }
+
+    // There must be room for `other` past len.  Its elements are copied
+    // bitwise, so they must be `Copy` or never be used again.
+    #[inline]
+    unsafe fn raw_extend_from_slice(&mut self, other: &[T]) {
+        let (ptr, len, _cap) = self.raw_parts();
+        debug_assert!(other.len() <= _cap - *len);
+        unsafe { ptr.add(*len).as_ptr().copy_from_nonoverlapping(other.as_ptr(), other.len()) }
+        *len += other.len();
+    }
+
+    // Moves as many of the elements in `range` as `dst` has room for onto its
+    // back, returning how many moved.  `range` must lie within `..len`.
+    fn raw_drain_into<D: RawBank<T>>(&mut self, range: ops::Range<usize>, dst: &mut D) -> usize
+    where
+        Self: Sized,
+    {
+        let count = dst.raw_reserve(range.len()).min(range.len());
+        let mut drain = self.raw_drain(range.start..range.start + count);
+        unsafe { dst.raw_extend_from_slice(drain.iter.as_slice()) }
+        // The elements now belong to `dst`, the drain only closes the gap
+        drain.iter = [].iter();
+        count
+    }
+
+    // `range` must lie within `..len`
+    #[inline]
+    fn raw_drain(&mut self, range: ops::Range<usize>) -> Drain<'_, T, Self> where Self: Sized {
+        // This implementation was pulled from `Vec::drain`
+        let (ptr, len, _) = self.raw_parts();
+        let ops::Range { start, end } = range;
+        let old_len = *len;
+        debug_assert!(start <= end && end <= old_len);
+
+        // Anything past `start` is owned by the drain until it's dropped
+        *len = start;
+        unsafe {
+            Drain {
+                tail_start: end,
+                tail_len: old_len - end,
+                iter: slice::from_raw_parts(ptr.as_ptr().add(start), end - start).iter(),
+                bank: NonNull::new_unchecked(self),
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{BankArr, BankVec, storage::BoxedBank};
+    use super::*;
+
+    // Runs the same operations through the core of a bank, checking its
+    // contents against `expected` after each
+    fn exercise<B: RawBank<String> + AsRef<[String]>>(mut bank: B) {
+        let expected = |bank: &B, strs: &[&str]| assert_eq!(bank.as_ref(), strs);
+        for s in ["b", "d"] {
+            let index = *bank.raw_parts().1;
+            unsafe { bank.raw_insert(index, s.to_string()) }
+        }
+        unsafe { bank.raw_insert(0, "a".to_string()) }
+        unsafe { bank.raw_insert(2, "c".to_string()) }
+        expected(&bank, &["a", "b", "c", "d"]);
+
+        assert_eq!(unsafe { bank.raw_remove(1) }, "b");
+        assert_eq!(unsafe { bank.raw_swap_remove(0) }, "a");
+        expected(&bank, &["d", "c"]);
+
+        assert_eq!(bank.raw_drain(0..1).collect::<Vec<_>>(), ["d"]);
+        assert_eq!(bank.raw_pop().as_deref(), Some("c"));
+        assert_eq!(bank.raw_pop(), None);
+
+        unsafe { bank.raw_insert(0, "e".to_string()) }
+        bank.raw_truncate(0);
+        assert_eq!(bank.take_len(), 0);
+    }
+
+    #[test]
+    fn shared_core() {
+        exercise(BankArr::<String, 4>::new());
+        exercise(BankVec::<String, 4>::new());
+        exercise(BoxedBank::<String>::with_capacity(4));
+    }
+}
@@ -0,0 +1,244 @@
+
+use std::fmt;
+use crate::{BankDeque, BankRing};
+
+
+/// A number [`RollingBank`] can aggregate, implemented for the primitive
+/// integers and floats.
+pub trait Sample: Copy + PartialOrd {
+    /// The sum of no samples.
+    const ZERO: Self;
+
+    /// Adds two samples, wrapping on overflow for integers, so a running sum
+    /// stays exact as long as the true sum fits.
+    fn add(self, other: Self) -> Self;
+
+    /// Subtracts `other` from `self`, wrapping on overflow for integers.
+    fn sub(self, other: Self) -> Self;
+
+    /// Converts the sample to an `f64`, rounding if need be.
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_sample {
+    (int: $($t:ty),*) => {$(
+        impl Sample for $t {
+            const ZERO: Self = 0;
+            #[inline]
+            fn add(self, other: Self) -> Self { self.wrapping_add(other) }
+            #[inline]
+            fn sub(self, other: Self) -> Self { self.wrapping_sub(other) }
+            #[inline]
+            fn to_f64(self) -> f64 { self as f64 }
+        }
+    )*};
+    (float: $($t:ty),*) => {$(
+        impl Sample for $t {
+            const ZERO: Self = 0.0;
+            #[inline]
+            fn add(self, other: Self) -> Self { self + other }
+            #[inline]
+            fn sub(self, other: Self) -> Self { self - other }
+            #[inline]
+            fn to_f64(self) -> f64 { self as f64 }
+        }
+    )*};
+}
+
+impl_sample!(int: u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_sample!(float: f32, f64);
+
+
+/// A window over the last `C` samples pushed, keeping their sum, minimum and
+/// maximum up to date as samples come and go.
+/// 
+/// Samples are held in a [`BankRing`], and every aggregate is *O*(1) to read.
+/// The sum is updated by adding each new sample and subtracting each evicted
+/// one, while the minimum and maximum are each tracked by a monotonic deque,
+/// holding only the samples which could still become the window's extreme.
+/// Everything lives inline, making `RollingBank` suitable for moving averages
+/// and metrics in code which can't allocate.
+/// 
+/// A floating-point sum gathers rounding error as samples are added and
+/// subtracted, and a `NaN` sample leaves the minimum and maximum unspecified.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::RollingBank;
+/// 
+/// let mut window = RollingBank::<u32, 3>::new();
+/// window.extend([4, 1, 7]);
+/// assert_eq!((window.min(), window.max()), (Some(1), Some(7)));
+/// 
+/// // Evicts the 4
+/// assert_eq!(window.push(2), Some(4));
+/// assert_eq!(window.sum(), 10);
+/// assert_eq!(window.mean(), Some(10.0 / 3.0));
+/// 
+/// // Evicts the 1
+/// window.push(5);
+/// assert_eq!((window.min(), window.max()), (Some(2), Some(7)));
+/// ```
+#[derive(Clone)]
+pub struct RollingBank<T, const C: usize> {
+    samples: BankRing<T, C>,
+    sum: T,
+    // Non-decreasing and non-increasing from front to back, respectively, so
+    // their fronts are the window's minimum and maximum
+    mins: BankDeque<T, C>,
+    maxs: BankDeque<T, C>,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: Sample + fmt::Debug, const C: usize> fmt::Debug for RollingBank<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RollingBank")
+            .field("samples", &self.samples)
+            .field("sum", &self.sum)
+            .field("min", &self.min())
+            .field("max", &self.max())
+            .finish()
+    }
+}
+
+impl<T: Sample, const C: usize> Default for RollingBank<T, C> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+
+impl<T: Sample, const C: usize> Extend<T> for RollingBank<T, C> {
+
+    /// Pushes every sample into the window, evicting the oldest as needed.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, samples: I) {
+        samples.into_iter().for_each(|sample| { self.push(sample); });
+    }
+}
+
+impl<T: Sample, const C: usize> RollingBank<T, C> {
+
+    /// Constructs a new, empty `RollingBank<T, C>`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { samples: BankRing::new(), sum: T::ZERO, mins: BankDeque::new(), maxs: BankDeque::new() }
+    }
+
+    /// Returns the number of samples in the window.
+    #[inline]
+    pub const fn len(&self) -> usize { self.samples.len() }
+
+    /// Returns `true` if the window contains no samples.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.samples.is_empty() }
+
+    /// Returns `true` if the window holds `C` samples, so the next push
+    /// evicts the oldest.
+    #[inline]
+    pub const fn is_full(&self) -> bool { self.samples.is_full() }
+
+    /// Returns the number of samples the window can hold, `C`.
+    #[inline]
+    pub const fn capacity(&self) -> usize { C }
+
+    /// Returns the samples in the window, from oldest to newest.
+    #[inline]
+    pub const fn samples(&self) -> &BankRing<T, C> { &self.samples }
+
+    /// Pushes a sample into the window, returning the oldest sample if it was
+    /// evicted to make room.
+    /// 
+    /// A window with a capacity of zero hands the sample straight back.
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes amortized *O*(1) time.
+    pub fn push(&mut self, sample: T) -> Option<T> {
+        if C == 0 { return Some(sample) }
+
+        let evicted = self.samples.push_overwrite(sample);
+        if let Some(old) = evicted {
+            self.sum = self.sum.sub(old);
+            // Samples equal to a later one are kept, so the front is only the
+            // evicted sample if it really was the extreme
+            if self.mins.front() == Some(&old) { self.mins.pop_front(); }
+            if self.maxs.front() == Some(&old) { self.maxs.pop_front(); }
+        }
+        self.sum = self.sum.add(sample);
+
+        while self.mins.back().is_some_and(|&back| back > sample) { self.mins.pop_back(); }
+        while self.maxs.back().is_some_and(|&back| back < sample) { self.maxs.pop_back(); }
+        // Each deque holds at most one entry per sample in the window
+        self.mins.push_back(sample);
+        self.maxs.push_back(sample);
+        evicted
+    }
+
+    /// Returns the sum of the samples in the window, or zero if it's empty.
+    #[inline]
+    pub const fn sum(&self) -> T { self.sum }
+
+    /// Returns the smallest sample in the window, or `None` if it's empty.
+    #[inline]
+    pub fn min(&self) -> Option<T> { self.mins.front().copied() }
+
+    /// Returns the largest sample in the window, or `None` if it's empty.
+    #[inline]
+    pub fn max(&self) -> Option<T> { self.maxs.front().copied() }
+
+    /// Returns the mean of the samples in the window, or `None` if it's empty.
+    #[inline]
+    pub fn mean(&self) -> Option<f64> {
+        (!self.is_empty()).then(|| self.sum.to_f64() / self.len() as f64)
+    }
+
+    /// Removes every sample from the window, resetting its aggregates.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.samples.clear();
+        self.mins.clear();
+        self.maxs.clear();
+        self.sum = T::ZERO;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn aggregates() {
+        let mut window = RollingBank::<i32, 4>::new();
+        assert_eq!((window.min(), window.max(), window.mean()), (None, None, None));
+
+        let samples = [5, -3, 8, 8, 0, 2, -3, 7, 7, 1, 9, -4];
+        for (i, &sample) in samples.iter().enumerate() {
+            window.push(sample);
+            let expected = &samples[i.saturating_sub(3)..=i];
+            assert_eq!(window.sum(), expected.iter().sum::<i32>());
+            assert_eq!(window.min(), expected.iter().min().copied());
+            assert_eq!(window.max(), expected.iter().max().copied());
+        }
+        assert_eq!(window.samples(), &[7, 1, 9, -4]);
+        assert_eq!(window.mean(), Some(3.25));
+
+        window.clear();
+        assert_eq!(window.sum(), 0);
+        assert_eq!(window.max(), None);
+    }
+
+    #[test]
+    fn wrapping_sum() {
+        let mut window = RollingBank::<u8, 2>::new();
+        window.extend([200, 100, 50]);
+        assert_eq!(window.sum(), 150);
+        assert_eq!(window.mean(), Some(75.0));
+    }
+
+    #[test]
+    fn zero_capacity() {
+        let mut window = RollingBank::<f32, 0>::new();
+        assert_eq!(window.push(1.5), Some(1.5));
+        assert_eq!((window.len(), window.min()), (0, None));
+    }
+}
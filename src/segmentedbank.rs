@@ -0,0 +1,440 @@
+//! A chunked vector which never moves its elements, see [`SegmentedBank`].
+
+use std::{fmt, iter::FusedIterator, ops::{Index, IndexMut}, slice, vec};
+use crate::BankArr;
+
+
+/// A growable vector stored as a list of boxed `BankArr<T, C>` segments.
+/// 
+/// Where a `Vec` reallocates and moves its elements as it grows, a
+/// `SegmentedBank` only ever adds a segment, so elements keep their address
+/// for as long as they're in the bank and pushing is *O*(1) without the
+/// occasional *O*(`n`) copy.  That suits arenas handing out pointers to their
+/// items, at the cost of the elements not being one contiguous slice; see
+/// [`segments`](SegmentedBank::segments).
+/// 
+/// # Examples
+/// ```
+/// use bankarr::SegmentedBank;
+/// 
+/// let mut bank = SegmentedBank::<String, 4>::new();
+/// let first: *const String = bank.push("first".to_string());
+/// 
+/// bank.extend((0..100).map(|i| i.to_string()));
+/// assert_eq!(bank.len(), 101);
+/// assert_eq!(bank.segments().count(), 26);
+/// 
+/// // Growing never moved the first element
+/// assert!(std::ptr::eq(first, &bank[0]));
+/// ```
+pub struct SegmentedBank<T, const C: usize> {
+    // Every segment before `len / C` is full, any after it empty
+    segments: Vec<Box<BankArr<T, C>>>,
+    len: usize,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: fmt::Debug, const C: usize> fmt::Debug for SegmentedBank<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Clone, const C: usize> Clone for SegmentedBank<T, C> {
+    fn clone(&self) -> Self {
+        Self { segments: self.segments.clone(), len: self.len }
+    }
+}
+
+impl<T, const C: usize> Default for SegmentedBank<T, C> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+
+impl<T: PartialEq, const C: usize> PartialEq for SegmentedBank<T, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const C: usize> Eq for SegmentedBank<T, C> {}
+
+impl<T, const C: usize> Index<usize> for SegmentedBank<T, C> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, index: usize) -> &T {
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!("index out of bounds: the len is {} but the index is {index}", self.len),
+        }
+    }
+}
+
+impl<T, const C: usize> IndexMut<usize> for SegmentedBank<T, C> {
+    #[track_caller]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len;
+        match self.get_mut(index) {
+            Some(value) => value,
+            None => panic!("index out of bounds: the len is {len} but the index is {index}"),
+        }
+    }
+}
+
+impl<T, const C: usize> Extend<T> for SegmentedBank<T, C> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|value| { self.push(value); });
+    }
+}
+
+impl<T, const C: usize> FromIterator<T> for SegmentedBank<T, C> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut bank = Self::new();
+        bank.extend(iter);
+        bank
+    }
+}
+
+impl<T, const C: usize> IntoIterator for SegmentedBank<T, C> {
+    type Item = T;
+    type IntoIter = IntoIter<T, C>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            segments: self.segments.into_iter(),
+            current: BankArr::new().into_iter(),
+            remaining: self.len,
+        }
+    }
+}
+
+impl<'a, T, const C: usize> IntoIterator for &'a SegmentedBank<T, C> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, C>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<'a, T, const C: usize> IntoIterator for &'a mut SegmentedBank<T, C> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T, C>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter_mut() }
+}
+
+impl<T, const C: usize> SegmentedBank<T, C> {
+
+    const NONZERO: () = assert!(C > 0, "SegmentedBank segments must hold at least one element");
+
+    /// Constructs a new, empty `SegmentedBank<T, C>`, without allocating.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::SegmentedBank;
+    /// 
+    /// let bank = SegmentedBank::<i32, 16>::new();
+    /// assert_eq!(bank.capacity(), 0);
+    /// ```
+    pub const fn new() -> Self {
+        let () = Self::NONZERO;
+        Self { segments: Vec::new(), len: 0 }
+    }
+
+    /// Returns the number of elements in the bank.
+    #[inline]
+    pub const fn len(&self) -> usize { self.len }
+
+    /// Returns `true` if the bank contains no elements.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// Returns the number of elements the bank can hold without allocating
+    /// another segment.
+    #[inline]
+    pub fn capacity(&self) -> usize { self.segments.len() * C }
+
+    /// Returns a reference to the element at `index`, or `None` if it's out of
+    /// bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        match index < self.len {
+            true => Some(&self.segments[index / C][index % C]),
+            false => None,
+        }
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if it's
+    /// out of bounds.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match index < self.len {
+            true => Some(&mut self.segments[index / C][index % C]),
+            false => None,
+        }
+    }
+
+    /// Returns a reference to the last element, or `None` if the bank is empty.
+    #[inline]
+    pub fn last(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|index| self.get(index))
+    }
+
+    /// Appends an element to the back of the bank, returning a reference to
+    /// it.  A new segment is allocated if the last one is full, but existing
+    /// elements are never moved.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::SegmentedBank;
+    /// 
+    /// let mut bank = SegmentedBank::<i32, 2>::new();
+    /// *bank.push(1) += 10;
+    /// bank.push(2);
+    /// bank.push(3);
+    /// 
+    /// assert_eq!(bank.capacity(), 4);
+    /// assert!(bank.iter().eq(&[11, 2, 3]));
+    /// ```
+    pub fn push(&mut self, value: T) -> &mut T {
+        let segment = self.len / C;
+        if segment == self.segments.len() {
+            self.segments.push(Box::new(BankArr::new()));
+        }
+        let segment = &mut self.segments[segment];
+        segment.push(value);
+        self.len += 1;
+        segment.last_mut().unwrap()
+    }
+
+    /// Removes the last element from the bank and returns it, or `None` if the
+    /// bank is empty.  Emptied segments are kept for later pushes.
+    pub fn pop(&mut self) -> Option<T> {
+        self.len = self.len.checked_sub(1)?;
+        self.segments[self.len / C].pop()
+    }
+
+    /// Shortens the bank to `len` elements, dropping the rest.  Has no effect
+    /// if `len` is greater than the bank's length.
+    pub fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            self.pop();
+        }
+    }
+
+    /// Removes every element, keeping the segments allocated.
+    #[inline]
+    pub fn clear(&mut self) { self.truncate(0) }
+
+    /// Frees any segments left empty by popping.
+    pub fn shrink_to_fit(&mut self) {
+        self.segments.truncate(self.len.div_ceil(C));
+        self.segments.shrink_to_fit();
+    }
+
+    /// Returns an iterator over the bank's segments as slices, each `C` long
+    /// except for the last.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::SegmentedBank;
+    /// 
+    /// let bank = SegmentedBank::<i32, 2>::from_iter(0..5);
+    /// let segments = bank.segments().collect::<Vec<_>>();
+    /// assert_eq!(segments, [&[0, 1][..], &[2, 3], &[4]]);
+    /// ```
+    pub fn segments(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.segments[..self.len.div_ceil(C)].iter().map(|segment| segment.as_slice())
+    }
+
+    /// Returns an iterator over the bank's elements.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T, C> {
+        Iter { segments: self.segments.iter(), current: [].iter(), remaining: self.len }
+    }
+
+    /// Returns an iterator over the bank's elements, allowing them to be
+    /// modified.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, C> {
+        IterMut { segments: self.segments.iter_mut(), current: [].iter_mut(), remaining: self.len }
+    }
+}
+
+
+macro_rules! impl_iter {
+    ($name:ident, $item:ty, $to_iter:ident) => {
+        impl<'a, T, const C: usize> Iterator for $name<'a, T, C> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                loop {
+                    if let Some(value) = self.current.next() {
+                        self.remaining -= 1;
+                        return Some(value);
+                    }
+                    self.current = self.segments.next()?.$to_iter();
+                }
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) { (self.remaining, Some(self.remaining)) }
+        }
+
+        impl<T, const C: usize> ExactSizeIterator for $name<'_, T, C> {}
+        impl<T, const C: usize> FusedIterator for $name<'_, T, C> {}
+    };
+}
+
+/// An iterator over the elements of a [`SegmentedBank`].
+/// 
+/// Created by [`SegmentedBank::iter`].
+pub struct Iter<'a, T, const C: usize> {
+    segments: slice::Iter<'a, Box<BankArr<T, C>>>,
+    current: slice::Iter<'a, T>,
+    remaining: usize,
+}
+
+impl<T, const C: usize> Clone for Iter<'_, T, C> {
+    fn clone(&self) -> Self {
+        Self { segments: self.segments.clone(), current: self.current.clone(), remaining: self.remaining }
+    }
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: fmt::Debug, const C: usize> fmt::Debug for Iter<'_, T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.clone()).finish()
+    }
+}
+
+impl_iter!(Iter, &'a T, iter);
+
+/// A mutable iterator over the elements of a [`SegmentedBank`].
+/// 
+/// Created by [`SegmentedBank::iter_mut`].
+pub struct IterMut<'a, T, const C: usize> {
+    segments: slice::IterMut<'a, Box<BankArr<T, C>>>,
+    current: slice::IterMut<'a, T>,
+    remaining: usize,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: fmt::Debug, const C: usize> fmt::Debug for IterMut<'_, T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IterMut").field("remaining", &self.remaining).finish()
+    }
+}
+
+impl_iter!(IterMut, &'a mut T, iter_mut);
+
+/// An owning iterator over the elements of a [`SegmentedBank`].
+/// 
+/// Created by [`SegmentedBank::into_iter`].
+pub struct IntoIter<T, const C: usize> {
+    segments: vec::IntoIter<Box<BankArr<T, C>>>,
+    current: crate::IntoIter<T, BankArr<T, C>>,
+    remaining: usize,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T, const C: usize> fmt::Debug for IntoIter<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntoIter").field("remaining", &self.remaining).finish()
+    }
+}
+
+impl<T, const C: usize> Iterator for IntoIter<T, C> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        loop {
+            if let Some(value) = self.current.next() {
+                self.remaining -= 1;
+                return Some(value);
+            }
+            self.current = (*self.segments.next()?).into_iter();
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.remaining, Some(self.remaining)) }
+}
+
+impl<T, const C: usize> ExactSizeIterator for IntoIter<T, C> {}
+impl<T, const C: usize> FusedIterator for IntoIter<T, C> {}
+
+
+#[cfg(test)]
+mod tests {
+
+    use std::rc::Rc;
+    use super::*;
+
+    #[test]
+    fn stable_addresses() {
+        let mut bank = SegmentedBank::<u64, 3>::new();
+        let ptrs = (0..50).map(|i| bank.push(i) as *const u64).collect::<Vec<_>>();
+        assert!(ptrs.iter().enumerate().all(|(i, ptr)| std::ptr::eq(*ptr, &bank[i])));
+        assert_eq!(bank.capacity(), 51);
+    }
+
+    #[test]
+    fn push_pop() {
+        let mut bank = SegmentedBank::<String, 2>::new();
+        assert_eq!(bank.pop(), None);
+        bank.extend(["a", "b", "c"].map(String::from));
+        assert_eq!(bank.last().unwrap(), "c");
+
+        assert_eq!(bank.pop().unwrap(), "c");
+        assert_eq!(bank.pop().unwrap(), "b");
+        assert_eq!(bank.capacity(), 4);
+        bank.shrink_to_fit();
+        assert_eq!(bank.capacity(), 2);
+
+        bank.push("d".to_string());
+        bank.push("e".to_string());
+        bank[2].push('!');
+        assert_eq!(bank.get(3), None);
+        assert!(bank.iter().eq(["a", "d", "e!"]));
+        assert_eq!(bank.segments().len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 1 but the index is 1")]
+    fn index_out_of_bounds() {
+        let bank = SegmentedBank::<i32, 4>::from_iter([1]);
+        let _ = bank[1];
+    }
+
+    #[test]
+    fn iter() {
+        let mut bank = SegmentedBank::<i32, 3>::from_iter(0..10);
+        bank.iter_mut().for_each(|value| *value *= 2);
+        assert_eq!(bank.iter().len(), 10);
+        assert_eq!(bank.iter().copied().collect::<Vec<_>>(), (0..20).step_by(2).collect::<Vec<_>>());
+        assert_eq!(bank.clone(), bank);
+
+        bank.truncate(4);
+        let mut iter = bank.into_iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.collect::<Vec<_>>(), [2, 4, 6]);
+    }
+
+    #[test]
+    fn drops() {
+        let rc = Rc::new(());
+        let mut bank = SegmentedBank::<_, 2>::from_iter((0..5).map(|_| rc.clone()));
+        bank.truncate(3);
+        assert_eq!(Rc::strong_count(&rc), 4);
+
+        let mut iter = bank.into_iter();
+        iter.next();
+        drop(iter);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
@@ -0,0 +1,69 @@
+
+// Batcher's odd-even merge sorting network for 16 inputs.  Each half is sorted
+// before the halves are merged, so its prefixes sort the first 2, 4 and 8
+// inputs on their own.
+const NETWORK: [(u8, u8); 63] = [
+    (0, 1), (2, 3), (0, 2), (1, 3), (1, 2), (4, 5), (6, 7), (4, 6), (5, 7), (5, 6),
+    (0, 4), (2, 6), (2, 4), (1, 5), (3, 7), (3, 5), (1, 2), (3, 4), (5, 6),
+    (8, 9), (10, 11), (8, 10), (9, 11), (9, 10), (12, 13), (14, 15), (12, 14),
+    (13, 15), (13, 14), (8, 12), (10, 14), (10, 12), (9, 13), (11, 15), (11, 13),
+    (9, 10), (11, 12), (13, 14), (0, 8), (4, 12), (4, 8), (2, 10), (6, 14), (6, 10),
+    (2, 4), (6, 8), (10, 12), (1, 9), (5, 13), (5, 9), (3, 11), (7, 15), (7, 11),
+    (3, 5), (7, 9), (11, 13), (1, 2), (3, 4), (5, 6), (7, 8), (9, 10), (11, 12),
+    (13, 14),
+];
+
+/// The longest slice [`sort_small`] sorts with a network.
+pub(crate) const MAX_NETWORK_LEN: usize = 16;
+
+/// Sorts `v` without preserving the order of equal elements, with a sorting
+/// network if it holds at most [`MAX_NETWORK_LEN`] elements, otherwise with
+/// `sort_unstable`.
+pub(crate) fn sort_small<T: Ord>(v: &mut [T]) {
+    let len = v.len();
+    let network = match len {
+        0..=1 => return,
+        2 => &NETWORK[..1],
+        3..=4 => &NETWORK[..5],
+        5..=8 => &NETWORK[..19],
+        9..=MAX_NETWORK_LEN => &NETWORK[..],
+        _ => return v.sort_unstable(),
+    };
+
+    // Slots past the end act as elements greater than any other, which never
+    // move, so the comparators touching them can be skipped
+    for &(i, j) in network {
+        let (i, j) = (i as usize, j as usize);
+        if j < len && v[j] < v[i] {
+            v.swap(i, j);
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn zero_one_principle() {
+        // A network sorting every sequence of 0s and 1s sorts everything
+        for len in 0..=MAX_NETWORK_LEN {
+            for bits in 0..1u32 << len {
+                let mut v: Vec<u32> = (0..len).map(|i| bits >> i & 1).collect();
+                sort_small(&mut v);
+                assert!(v.is_sorted(), "{len} {bits:b}");
+            }
+        }
+    }
+
+    #[test]
+    fn falls_back() {
+        let mut v: Vec<i32> = (0..40).map(|i| (i * 17) % 23 - 11).collect();
+        let mut expected = v.clone();
+        expected.sort();
+        sort_small(&mut v);
+        assert_eq!(v, expected);
+    }
+}
@@ -0,0 +1,389 @@
+
+use std::{borrow::Borrow, cmp::Ordering, fmt, hash::{Hash, Hasher}, ops::{Bound, Deref, RangeBounds}};
+use crate::{BankVec, IntoIter};
+
+
+/// A [`BankVec`] which keeps its elements in ascending order, moving onto the
+/// heap once it grows past `C` elements.
+/// 
+/// Insertions find their place by binary search and shift the greater elements
+/// right, so lookups, [`contains`](Self::contains) and [`range`](Self::range)
+/// queries take *O*(log `n`) time.  Equal elements are kept in the order they
+/// were inserted.
+/// 
+/// The elements can be read as a slice through [`Deref`], but not modified in
+/// place since that could break their order.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::SortedBankVec;
+/// 
+/// let mut bank = SortedBankVec::<i32, 4>::new();
+/// bank.insert(5);
+/// bank.insert(1);
+/// bank.insert(3);
+/// 
+/// assert_eq!(bank, [1, 3, 5]);
+/// assert!(bank.contains(&3));
+/// assert_eq!(bank.range(2..), [3, 5]);
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct SortedBankVec<T, const C: usize> {
+    vec: BankVec<T, C>,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: fmt::Debug, const C: usize> fmt::Debug for SortedBankVec<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const C: usize> Default for SortedBankVec<T, C> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+
+impl<T, const C: usize> Deref for SortedBankVec<T, C> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target { self.as_slice() }
+}
+
+impl<T, const C: usize> AsRef<[T]> for SortedBankVec<T, C> {
+    #[inline]
+    fn as_ref(&self) -> &[T] { self.as_slice() }
+}
+
+impl<T: Eq, const C: usize> Eq for SortedBankVec<T, C> {}
+
+impl<T: PartialOrd, const C: usize> PartialOrd for SortedBankVec<T, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { self.as_slice().partial_cmp(other.as_slice()) }
+}
+
+impl<T: Ord, const C: usize> Ord for SortedBankVec<T, C> {
+    fn cmp(&self, other: &Self) -> Ordering { self.as_slice().cmp(other.as_slice()) }
+}
+
+impl<T: Hash, const C: usize> Hash for SortedBankVec<T, C> {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_slice().hash(state) }
+}
+
+impl<T: PartialEq, const C: usize, const N: usize> PartialEq<[T; N]> for SortedBankVec<T, C> {
+    fn eq(&self, other: &[T; N]) -> bool { self.as_slice() == other }
+}
+
+impl<T: PartialEq, const C: usize> PartialEq<[T]> for SortedBankVec<T, C> {
+    fn eq(&self, other: &[T]) -> bool { self.as_slice() == other }
+}
+
+impl<T: Ord, const C: usize> Extend<T> for SortedBankVec<T, C> {
+
+    /// Appends every item, then restores order with a single stable sort.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        self.vec.extend(items);
+        self.vec.sort();
+    }
+}
+
+impl<T: Ord, const C: usize> FromIterator<T> for SortedBankVec<T, C> {
+    fn from_iter<I: IntoIterator<Item = T>>(items: I) -> Self {
+        let mut bank = Self::new();
+        bank.extend(items);
+        bank
+    }
+}
+
+impl<T: Ord, const C: usize> From<BankVec<T, C>> for SortedBankVec<T, C> {
+
+    /// Sorts a bank in place, keeping its storage.
+    #[inline]
+    fn from(mut vec: BankVec<T, C>) -> Self {
+        vec.sort();
+        Self { vec }
+    }
+}
+
+impl<T: Ord, const C: usize, const N: usize> From<[T; N]> for SortedBankVec<T, C> {
+
+    /// Creates a sorted bank from an array, on the heap only if `N` exceeds `C`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::SortedBankVec;
+    /// 
+    /// let bank = SortedBankVec::<_, 4>::from([3, 1, 2]);
+    /// assert_eq!(bank, [1, 2, 3]);
+    /// ```
+    #[inline]
+    fn from(arr: [T; N]) -> Self { Self::from(BankVec::from(arr)) }
+}
+
+impl<T, const C: usize> From<SortedBankVec<T, C>> for BankVec<T, C> {
+    #[inline]
+    fn from(bank: SortedBankVec<T, C>) -> Self { bank.vec }
+}
+
+impl<T, const C: usize> IntoIterator for SortedBankVec<T, C> {
+    type Item = T;
+    type IntoIter = IntoIter<T, BankVec<T, C>>;
+
+    /// Creates a consuming iterator, moving each element out in ascending order.
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.vec.into_iter() }
+}
+
+impl<'a, T, const C: usize> IntoIterator for &'a SortedBankVec<T, C> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<T, const C: usize> SortedBankVec<T, C> {
+
+    /// Constructs a new, empty `SortedBankVec<T, C>`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::SortedBankVec;
+    /// 
+    /// let bank = SortedBankVec::<u32, 8>::new();
+    /// assert!(bank.is_empty());
+    /// ```
+    pub const fn new() -> Self { Self { vec: BankVec::new() } }
+
+    /// Returns the number of elements in the bank.
+    #[inline]
+    pub const fn len(&self) -> usize { self.vec.len() }
+
+    /// Returns `true` if the bank contains no elements.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.vec.is_empty() }
+
+    /// Returns the number of elements the bank can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize { self.vec.capacity() }
+
+    /// Returns `true` if the bank's elements have moved onto the heap.
+    #[inline]
+    pub const fn on_heap(&self) -> bool { self.vec.on_heap() }
+
+    /// Reserves capacity for at least `additional` more elements, moving the
+    /// bank onto the heap if they won't fit within `C`.
+    #[inline]
+    #[track_caller]
+    pub fn reserve(&mut self, additional: usize) { self.vec.reserve(additional) }
+
+    /// Shrinks the bank's heap allocation to fit its length, moving the elements
+    /// back inline if they fit within `C`.
+    #[inline]
+    #[track_caller]
+    pub fn shrink_to_fit(&mut self) { self.vec.shrink_to_fit() }
+
+    /// Extracts a slice containing the entire bank, in ascending order.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] { self.vec.as_slice() }
+
+    /// Removes and returns the element at position `index`, shifting all
+    /// elements after it to the left.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    #[track_caller]
+    pub fn remove(&mut self, index: usize) -> T { self.vec.remove(index) }
+
+    /// Removes the greatest element and returns it, or `None` if the bank is
+    /// empty.
+    #[inline]
+    pub fn pop_last(&mut self) -> Option<T> { self.vec.pop() }
+
+    /// Removes the least element and returns it, or `None` if the bank is empty.
+    #[inline]
+    pub fn pop_first(&mut self) -> Option<T> {
+        (!self.is_empty()).then(|| self.vec.remove(0))
+    }
+
+    /// Shortens the bank, keeping its `len` least elements.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) { self.vec.truncate(len) }
+
+    /// Removes every element from the bank.
+    #[inline]
+    pub fn clear(&mut self) { self.vec.clear() }
+
+    /// Retains only the elements for which `f` returns `true`.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        let mut index = 0;
+        while index < self.len() {
+            match f(&self.vec[index]) {
+                true => index += 1,
+                false => drop(self.vec.remove(index)),
+            }
+        }
+    }
+}
+
+impl<T: Ord, const C: usize> SortedBankVec<T, C> {
+
+    /// Inserts an element in order, after any equal elements, returning the
+    /// index it was placed at.  Moves onto the heap if the bank is full.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::SortedBankVec;
+    /// 
+    /// let mut bank = SortedBankVec::<_, 4>::from([1, 3]);
+    /// assert_eq!(bank.insert(2), 1);
+    /// assert_eq!(bank.insert(3), 3);
+    /// assert_eq!(bank, [1, 2, 3, 3]);
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(`n`) time, finding the index in *O*(log `n`) and shifting the
+    /// greater elements right.
+    #[inline]
+    #[track_caller]
+    pub fn insert(&mut self, value: T) -> usize {
+        let index = self.vec.partition_point(|element| *element <= value);
+        self.vec.insert(index, value);
+        index
+    }
+
+    /// Returns `true` if the bank contains an element equal to `value`.
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(log `n`) time.
+    #[inline]
+    pub fn contains<Q: Ord + ?Sized>(&self, value: &Q) -> bool where T: Borrow<Q> {
+        self.find(value).is_ok()
+    }
+
+    /// Searches for `value`, returning `Ok` with the index of a matching element,
+    /// or `Err` with the index it could be inserted at.
+    #[inline]
+    pub fn find<Q: Ord + ?Sized>(&self, value: &Q) -> Result<usize, usize> where T: Borrow<Q> {
+        self.vec.binary_search_by(|element| element.borrow().cmp(value))
+    }
+
+    /// Removes one element equal to `value`, returning it if there was one.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::SortedBankVec;
+    /// 
+    /// let mut bank = SortedBankVec::<_, 4>::from([1, 2, 2, 3]);
+    /// assert_eq!(bank.remove_item(&2), Some(2));
+    /// assert_eq!(bank.remove_item(&4), None);
+    /// assert_eq!(bank, [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn remove_item<Q: Ord + ?Sized>(&mut self, value: &Q) -> Option<T> where T: Borrow<Q> {
+        self.find(value).ok().map(|index| self.vec.remove(index))
+    }
+
+    /// Returns the slice of elements lying within `range`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::SortedBankVec;
+    /// 
+    /// let bank = SortedBankVec::<_, 8>::from([1, 3, 3, 5, 7, 9]);
+    /// assert_eq!(bank.range(3..7), [3, 3, 5]);
+    /// assert_eq!(bank.range(..=3), [1, 3, 3]);
+    /// assert!(bank.range(10..).is_empty());
+    /// ```
+    /// 
+    /// # Time Complexity
+    /// 
+    /// Takes *O*(log `n`) time.
+    pub fn range<Q: Ord + ?Sized, R: RangeBounds<Q>>(&self, range: R) -> &[T] where T: Borrow<Q> {
+        let start = match range.start_bound() {
+            Bound::Included(start) => self.vec.partition_point(|element| element.borrow() < start),
+            Bound::Excluded(start) => self.vec.partition_point(|element| element.borrow() <= start),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => self.vec.partition_point(|element| element.borrow() <= end),
+            Bound::Excluded(end) => self.vec.partition_point(|element| element.borrow() < end),
+            Bound::Unbounded => self.len(),
+        };
+        &self.vec[start..end.max(start)]
+    }
+
+    /// Removes consecutive equal elements, leaving each value once.
+    #[inline]
+    pub fn dedup(&mut self) {
+        let mut index = 1;
+        while index < self.len() {
+            match self.vec[index] == self.vec[index - 1] {
+                true => drop(self.vec.remove(index)),
+                false => index += 1,
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn insert_spill() {
+        let mut bank = SortedBankVec::<i32, 3>::new();
+        for value in [4, 2, 8, 6] {
+            bank.insert(value);
+        }
+        assert!(bank.on_heap());
+        assert_eq!(bank, [2, 4, 6, 8]);
+
+        assert_eq!(bank.pop_first(), Some(2));
+        assert_eq!(bank.pop_last(), Some(8));
+        bank.shrink_to_fit();
+        assert!(!bank.on_heap());
+        assert_eq!(bank, [4, 6]);
+    }
+
+    #[test]
+    fn stable_inserts() {
+        #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+        struct Key(i32, #[allow(dead_code)] char);
+
+        // Only the first field takes part in ordering
+        impl Borrow<i32> for Key {
+            fn borrow(&self) -> &i32 { &self.0 }
+        }
+
+        let mut bank = SortedBankVec::<Key, 4>::new();
+        bank.insert(Key(1, 'a'));
+        bank.insert(Key(0, 'b'));
+        assert_eq!(bank.insert(Key(1, 'a')), 2);
+        assert!(bank.contains(&0));
+        assert_eq!(bank.range(1..).len(), 2);
+        assert_eq!(bank.remove_item(&0), Some(Key(0, 'b')));
+    }
+
+    #[test]
+    fn queries() {
+        let mut bank: SortedBankVec<_, 8> = [5, 1, 4, 1, 5, 9, 2, 6].into_iter().collect();
+        assert_eq!(bank, [1, 1, 2, 4, 5, 5, 6, 9]);
+        assert_eq!(bank.find(&4), Ok(3));
+        assert_eq!(bank.find(&3), Err(3));
+        assert_eq!(bank.range(2..=5), [2, 4, 5, 5]);
+        assert_eq!(bank.range((Bound::Excluded(5), Bound::Unbounded)), [6, 9]);
+        assert!(bank.range((Bound::Included(7), Bound::Excluded(3))).is_empty());
+
+        bank.dedup();
+        assert_eq!(bank, [1, 2, 4, 5, 6, 9]);
+        bank.retain(|value| value % 2 == 0);
+        assert_eq!(bank.into_iter().collect::<Vec<_>>(), [2, 4, 6]);
+    }
+}
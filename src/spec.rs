@@ -0,0 +1,122 @@
+//! Internal helpers which pick faster code paths for particular element types.
+//! 
+//! On stable these fall back to generic element-wise operations.  Enabling the
+//! nightly `specialization` feature lets `Copy` types take a single `memcpy`
+//! when cloning or extending from a slice, array or vec iterator, and the
+//! `simd` feature searches primitive integers a vector at a time.
+
+use std::{mem, ptr};
+
+#[cfg(feature = "specialization")]
+mod nightly;
+
+#[cfg(feature = "simd")]
+mod simd;
+
+#[cfg(feature = "specialization")]
+pub(crate) use nightly::{clone_to_uninit, copy_slice, ref_slice};
+
+#[cfg(feature = "simd")]
+pub(crate) use simd::find;
+
+/// Writes a clone of every element in `src` to `dst`.
+/// 
+/// # Safety
+/// 
+/// `dst` must be valid for writes of `src.len()` elements and must not overlap
+/// `src`.
+#[cfg(not(feature = "specialization"))]
+#[inline]
+pub(crate) unsafe fn clone_to_uninit<T: Clone>(src: &[T], dst: *mut T) {
+    unsafe { clone_each(src, dst) }
+}
+
+/// The element-wise path of [`clone_to_uninit`].  Should a clone panic, the
+/// clones already written are dropped rather than leaked.
+/// 
+/// # Safety
+/// 
+/// As for [`clone_to_uninit`].
+#[inline]
+pub(crate) unsafe fn clone_each<T: Clone>(src: &[T], dst: *mut T) {
+    struct Written<T> {
+        dst: *mut T,
+        len: usize,
+    }
+
+    impl<T> Drop for Written<T> {
+        fn drop(&mut self) {
+            unsafe { ptr::slice_from_raw_parts_mut(self.dst, self.len).drop_in_place() }
+        }
+    }
+
+    let mut written = Written { dst, len: 0 };
+    for val in src {
+        unsafe { dst.add(written.len).write(val.clone()) }
+        written.len += 1;
+    }
+    // Every clone is now the caller's
+    mem::forget(written);
+}
+
+/// Returns the items left in `iter` as a slice if they're `Copy` and stored
+/// contiguously, so they can be copied out in one go.  Dropping `iter` after
+/// copying its items leaves nothing to clean up.
+#[cfg(not(feature = "specialization"))]
+#[inline]
+pub(crate) fn copy_slice<I: Iterator>(_iter: &I) -> Option<&[I::Item]> { None }
+
+/// Returns the elements left in `iter` as a slice if it iterates over one.
+#[cfg(not(feature = "specialization"))]
+#[inline]
+pub(crate) fn ref_slice<'a, T, I: Iterator<Item = &'a T>>(_iter: &I) -> Option<&'a [T]> { None }
+
+/// Returns the index of the first element in `slice` equal to `value`.
+#[cfg(not(feature = "simd"))]
+#[inline]
+pub(crate) fn find<T: PartialEq>(slice: &[T], value: &T) -> Option<usize> {
+    slice.iter().position(|x| x == value)
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use std::{cell::Cell, mem::MaybeUninit, panic::{self, AssertUnwindSafe}};
+    use super::*;
+
+    thread_local! { static LIVE: Cell<isize> = const { Cell::new(0) } }
+
+    // Panics when cloned with `fail` set, counting the instances alive
+    struct Counted { fail: bool }
+
+    impl Counted {
+        fn new(fail: bool) -> Self {
+            LIVE.with(|live| live.set(live.get() + 1));
+            Self { fail }
+        }
+    }
+
+    impl Clone for Counted {
+        fn clone(&self) -> Self {
+            assert!(!self.fail, "clone failed");
+            Self::new(false)
+        }
+    }
+
+    impl Drop for Counted {
+        fn drop(&mut self) { LIVE.with(|live| live.set(live.get() - 1)) }
+    }
+
+    #[test]
+    fn clone_panic_drops_clones() {
+        let src = [false, false, true, false].map(Counted::new);
+        let mut dst = [const { MaybeUninit::<Counted>::uninit() }; 4];
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| unsafe {
+            clone_to_uninit(&src, dst.as_mut_ptr().cast())
+        }));
+        assert!(result.is_err());
+        assert_eq!(LIVE.with(Cell::get), 4);
+    }
+}
@@ -0,0 +1,68 @@
+use std::{array, ptr, slice, vec};
+
+trait SpecClone<T> {
+    unsafe fn spec_clone_to_uninit(&self, dst: *mut T);
+}
+
+impl<T: Clone> SpecClone<T> for [T] {
+    #[inline]
+    default unsafe fn spec_clone_to_uninit(&self, dst: *mut T) {
+        unsafe { super::clone_each(self, dst) }
+    }
+}
+
+impl<T: Copy> SpecClone<T> for [T] {
+    #[inline]
+    unsafe fn spec_clone_to_uninit(&self, dst: *mut T) {
+        unsafe { ptr::copy_nonoverlapping(self.as_ptr(), dst, self.len()) }
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn clone_to_uninit<T: Clone>(src: &[T], dst: *mut T) {
+    unsafe { src.spec_clone_to_uninit(dst) }
+}
+
+
+trait SpecCopySlice<T> {
+    fn spec_copy_slice(&self) -> Option<&[T]>;
+}
+
+impl<T, I: Iterator<Item = T>> SpecCopySlice<T> for I {
+    #[inline]
+    default fn spec_copy_slice(&self) -> Option<&[T]> { None }
+}
+
+impl<T: Copy> SpecCopySlice<T> for vec::IntoIter<T> {
+    #[inline]
+    fn spec_copy_slice(&self) -> Option<&[T]> { Some(self.as_slice()) }
+}
+
+impl<T: Copy, const N: usize> SpecCopySlice<T> for array::IntoIter<T, N> {
+    #[inline]
+    fn spec_copy_slice(&self) -> Option<&[T]> { Some(self.as_slice()) }
+}
+
+#[inline]
+pub(crate) fn copy_slice<I: Iterator>(iter: &I) -> Option<&[I::Item]> {
+    iter.spec_copy_slice()
+}
+
+trait SpecRefSlice<'a, T> {
+    fn spec_ref_slice(&self) -> Option<&'a [T]>;
+}
+
+impl<'a, T: 'a, I: Iterator<Item = &'a T>> SpecRefSlice<'a, T> for I {
+    #[inline]
+    default fn spec_ref_slice(&self) -> Option<&'a [T]> { None }
+}
+
+impl<'a, T> SpecRefSlice<'a, T> for slice::Iter<'a, T> {
+    #[inline]
+    fn spec_ref_slice(&self) -> Option<&'a [T]> { Some(self.as_slice()) }
+}
+
+#[inline]
+pub(crate) fn ref_slice<'a, T, I: Iterator<Item = &'a T>>(iter: &I) -> Option<&'a [T]> {
+    iter.spec_ref_slice()
+}
@@ -0,0 +1,64 @@
+use std::simd::{cmp::SimdPartialEq, Simd};
+
+trait SpecFind<T> {
+    fn spec_find(&self, value: &T) -> Option<usize>;
+}
+
+impl<T: PartialEq> SpecFind<T> for [T] {
+    #[inline]
+    default fn spec_find(&self, value: &T) -> Option<usize> {
+        self.iter().position(|x| x == value)
+    }
+}
+
+// Compares a full 256-bit vector of elements at a time, finishing off any
+// remainder one element at a time.
+macro_rules! impl_simd_find {
+    ($($ty:ty => $lanes:literal),*) => {$(
+        impl SpecFind<$ty> for [$ty] {
+            #[inline]
+            fn spec_find(&self, value: &$ty) -> Option<usize> {
+                let needle = Simd::<$ty, $lanes>::splat(*value);
+                let (chunks, rest) = self.as_chunks::<$lanes>();
+
+                for (idx, chunk) in chunks.iter().enumerate() {
+                    let mask = Simd::from_array(*chunk).simd_eq(needle);
+                    if let Some(lane) = mask.first_set() { return Some(idx * $lanes + lane) }
+                }
+
+                rest.iter()
+                    .position(|x| x == value)
+                    .map(|idx| chunks.len() * $lanes + idx)
+            }
+        }
+    )*};
+}
+
+impl_simd_find!(u8 => 32, u16 => 16, u32 => 8, u64 => 4);
+
+#[inline]
+pub(crate) fn find<T: PartialEq>(slice: &[T], value: &T) -> Option<usize> {
+    slice.spec_find(value)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_() {
+        let bytes: Vec<u8> = (0..100).collect();
+        assert_eq!(find(&bytes, &0), Some(0));
+        assert_eq!(find(&bytes, &40), Some(40));
+        assert_eq!(find(&bytes, &99), Some(99));
+        assert_eq!(find(&bytes, &100), None);
+
+        let words: Vec<u64> = (0..10).collect();
+        assert_eq!(find(&words, &3), Some(3));
+        assert_eq!(find(&words, &9), Some(9));
+
+        let strings = ["a".to_string(), "b".to_string()];
+        assert_eq!(find(&strings, &"b".to_string()), Some(1));
+    }
+}
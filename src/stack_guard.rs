@@ -0,0 +1,64 @@
+
+// The limit on a bank's inline storage enforced by the `stack-guard` feature,
+// taken from `BANKARR_MAX_INLINE_BYTES` at compile time, 64 KiB otherwise.
+#[cfg(feature = "stack-guard")]
+pub(crate) const MAX_INLINE_BYTES: usize = match option_env!("BANKARR_MAX_INLINE_BYTES") {
+    Some(bytes) => parse_bytes(bytes),
+    None => 64 * 1024,
+};
+
+#[cfg(feature = "stack-guard")]
+const fn parse_bytes(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    assert!(!bytes.is_empty(), "BANKARR_MAX_INLINE_BYTES must be a number of bytes");
+
+    let mut value = 0usize;
+    let mut idx = 0;
+    while idx < bytes.len() {
+        let digit = bytes[idx].wrapping_sub(b'0');
+        assert!(digit < 10, "BANKARR_MAX_INLINE_BYTES must be a number of bytes");
+        value = match value.checked_mul(10) {
+            Some(value) => value.saturating_add(digit as usize),
+            None => usize::MAX,
+        };
+        idx += 1;
+    }
+    value
+}
+
+/// Fails compilation, with the `stack-guard` feature enabled, wherever a bank
+/// storing `C` elements of `T` inline would exceed [`MAX_INLINE_BYTES`].
+#[inline(always)]
+pub(crate) const fn check_inline_size<T, const C: usize>() {
+    #[cfg(feature = "stack-guard")]
+    const {
+        assert!(
+            size_of::<T>().saturating_mul(C) <= MAX_INLINE_BYTES,
+            "bank's inline storage exceeds BANKARR_MAX_INLINE_BYTES"
+        )
+    }
+}
+
+
+#[cfg(all(test, feature = "stack-guard"))]
+mod tests {
+
+    use crate::{BankArr, BankVec};
+    use super::*;
+
+    #[test]
+    fn parse_bytes_() {
+        assert_eq!(parse_bytes("0"), 0);
+        assert_eq!(parse_bytes("1048576"), 1 << 20);
+        assert_eq!(parse_bytes("99999999999999999999999"), usize::MAX);
+    }
+
+    #[test]
+    fn within_limit() {
+        let bank = BankArr::<u8, MAX_INLINE_BYTES>::new();
+        assert_eq!(bank.remaining_capacity(), MAX_INLINE_BYTES);
+
+        let bank = BankVec::<u64, 8>::from(vec![0; 20]);
+        assert!(bank.on_heap());
+    }
+}
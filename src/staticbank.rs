@@ -0,0 +1,223 @@
+
+use std::{cell::UnsafeCell, fmt, mem, sync::atomic::{AtomicBool, Ordering}};
+use crate::{BankArr, errors::BankFullError};
+
+
+/// A `BankArr<T, C>` which can be declared `static` and mutated through a
+/// shared reference.
+/// 
+/// Access goes through a closure holding the bank exclusively, guarded by a
+/// single atomic flag rather than an OS mutex, so a fixed buffer can be shared
+/// between the main loop and an interrupt or signal handler.  An interrupt
+/// can't wait for the code it interrupted, so handlers should use the `try_`
+/// methods, which give up instead of spinning while the bank is held.
+/// 
+/// The flag is taken with an atomic swap, so only targets with atomic
+/// compare-and-swap are supported.  Cores without it, such as `thumbv6m`,
+/// would need a critical section instead.
+/// 
+/// # Examples
+/// ```
+/// use bankarr::StaticBank;
+/// 
+/// static SAMPLES: StaticBank<u16, 32> = StaticBank::new();
+/// 
+/// // In the interrupt handler
+/// let _ = SAMPLES.try_push(512);
+/// 
+/// // In the main loop
+/// let samples = SAMPLES.take();
+/// assert_eq!(samples.as_slice(), [512]);
+/// assert!(SAMPLES.with(|bank| bank.is_empty()));
+/// ```
+pub struct StaticBank<T, const C: usize> {
+    locked: AtomicBool,
+    bank: UnsafeCell<BankArr<T, C>>,
+}
+
+// The flag hands the bank to one thread at a time, so sharing it only moves
+// elements between threads
+unsafe impl<T: Send, const C: usize> Sync for StaticBank<T, C> {}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: fmt::Debug, const C: usize> fmt::Debug for StaticBank<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.try_with(|bank| fmt::Debug::fmt(bank, f)) {
+            Some(result) => result,
+            None => f.write_str("StaticBank { <locked> }"),
+        }
+    }
+}
+
+impl<T, const C: usize> Default for StaticBank<T, C> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+
+impl<T, const C: usize> From<BankArr<T, C>> for StaticBank<T, C> {
+    #[inline]
+    fn from(bank: BankArr<T, C>) -> Self {
+        Self { locked: AtomicBool::new(false), bank: UnsafeCell::new(bank) }
+    }
+}
+
+// Releases the flag when the closure returns or unwinds.
+struct Unlock<'a>(&'a AtomicBool);
+
+impl Drop for Unlock<'_> {
+    #[inline]
+    fn drop(&mut self) { self.0.store(false, Ordering::Release) }
+}
+
+impl<T, const C: usize> StaticBank<T, C> {
+
+    /// Constructs a new, empty `StaticBank<T, C>`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::StaticBank;
+    /// 
+    /// static EVENTS: StaticBank<u8, 16> = StaticBank::new();
+    /// ```
+    pub const fn new() -> Self {
+        Self { locked: AtomicBool::new(false), bank: UnsafeCell::new(BankArr::new()) }
+    }
+
+    /// Calls `f` with exclusive access to the bank, or returns `None` without
+    /// calling it if the bank is already held.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::StaticBank;
+    /// 
+    /// let bank = StaticBank::<i32, 4>::new();
+    /// assert_eq!(bank.try_with(|bank| bank.push(1)), Some(()));
+    /// 
+    /// // Nested access finds the bank held
+    /// assert_eq!(bank.with(|_| bank.try_with(|bank| bank.len())), None);
+    /// ```
+    pub fn try_with<R>(&self, f: impl FnOnce(&mut BankArr<T, C>) -> R) -> Option<R> {
+        if self.locked.swap(true, Ordering::Acquire) { return None }
+
+        let _unlock = Unlock(&self.locked);
+        // The flag was clear, so no other reference to the bank exists
+        Some(f(unsafe { &mut *self.bank.get() }))
+    }
+
+    /// Calls `f` with exclusive access to the bank, spinning until it's free.
+    /// 
+    /// This must not be called from an interrupt handler, or anywhere else the
+    /// current holder can't make progress, as it would spin forever.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::StaticBank;
+    /// 
+    /// static LOG: StaticBank<&str, 8> = StaticBank::new();
+    /// 
+    /// std::thread::scope(|s| {
+    ///     s.spawn(|| LOG.with(|bank| bank.push("spawned")));
+    ///     LOG.with(|bank| bank.push("main"));
+    /// });
+    /// assert_eq!(LOG.with(|bank| bank.len()), 2);
+    /// ```
+    pub fn with<R>(&self, f: impl FnOnce(&mut BankArr<T, C>) -> R) -> R {
+        let mut f = Some(f);
+        loop {
+            if let Some(result) = self.try_with(|bank| f.take().unwrap()(bank)) {
+                return result;
+            }
+            std::hint::spin_loop();
+        }
+    }
+
+    /// Attempts to push an element onto the bank without waiting, handing it
+    /// back inside the error if the bank is full or currently held.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::StaticBank;
+    /// 
+    /// let bank = StaticBank::<i32, 1>::new();
+    /// assert!(bank.try_push(1).is_ok());
+    /// assert_eq!(bank.try_push(2).unwrap_err().element(), 2);
+    /// ```
+    pub fn try_push(&self, value: T) -> Result<(), BankFullError<T>> {
+        let mut value = Some(value);
+        self.try_with(|bank| bank.try_push(value.take().unwrap()))
+            .unwrap_or_else(|| Err(BankFullError::new(value.take().unwrap())))
+    }
+
+    /// Removes the last element from the bank without waiting, returning
+    /// `None` if it's empty or currently held.
+    #[inline]
+    pub fn try_pop(&self) -> Option<T> {
+        self.try_with(|bank| bank.pop()).flatten()
+    }
+
+    /// Takes the bank's elements, leaving it empty.  Spins until the bank is
+    /// free, like [`with`](StaticBank::with).
+    #[inline]
+    pub fn take(&self) -> BankArr<T, C> {
+        self.with(mem::take)
+    }
+
+    /// Returns a mutable reference to the bank.  No locking is needed, as the
+    /// `&mut` borrow already guarantees exclusive access.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut BankArr<T, C> { self.bank.get_mut() }
+
+    /// Consumes the `StaticBank`, returning the bank within.
+    #[inline]
+    pub fn into_inner(self) -> BankArr<T, C> { self.bank.into_inner() }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use std::{panic, thread};
+    use super::*;
+
+    #[test]
+    fn with() {
+        let mut bank = StaticBank::<String, 2>::new();
+        bank.with(|bank| bank.push("a".to_string()));
+        assert!(bank.try_push("b".to_string()).is_ok());
+        assert_eq!(bank.try_push("c".to_string()).unwrap_err().element(), "c");
+
+        bank.with(|_| {
+            assert_eq!(bank.try_push("d".to_string()).unwrap_err().element(), "d");
+            assert_eq!(bank.try_pop(), None);
+        });
+        assert_eq!(bank.try_pop().unwrap(), "b");
+
+        bank.get_mut().push("e".to_string());
+        assert_eq!(bank.into_inner().as_slice(), ["a", "e"]);
+    }
+
+    #[test]
+    fn unlocks_on_panic() {
+        let bank = StaticBank::<i32, 2>::from(BankArr::from([1]));
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| bank.with(|_| panic!())));
+        assert!(result.is_err());
+        assert_eq!(bank.take().as_slice(), [1]);
+    }
+
+    #[test]
+    fn threaded() {
+        static COUNTS: StaticBank<usize, 4> = StaticBank::new();
+        COUNTS.with(|bank| bank.extend([0; 4]));
+
+        thread::scope(|s| {
+            for i in 0..4 {
+                s.spawn(move || {
+                    for _ in 0..1_000 {
+                        COUNTS.with(|bank| bank.as_mut_slice()[i] += 1);
+                    }
+                });
+            }
+        });
+        assert_eq!(COUNTS.take().as_slice(), [1_000; 4]);
+    }
+}
@@ -0,0 +1,574 @@
+//! Banks over caller-provided memory, see [`StorageBank`].
+
+use std::{fmt, marker::PhantomData, mem::{self, MaybeUninit}, ops::{self, Deref, DerefMut}, ptr::{self, NonNull}, slice};
+use crate::{Bank, drain::{self, Drain, DrainTarget}, errors::{BankFullError, InsertError}, raw::{self, RawBank}};
+
+
+/// Memory a [`StorageBank`] can keep its elements in.
+/// 
+/// # Safety
+/// 
+/// Both methods must return the same memory, with the same length, every time
+/// they're called, as the bank relies on elements it wrote staying put.
+pub unsafe trait Storage<T> {
+    /// Returns the memory as a slice of possibly uninitialized elements.
+    fn as_uninit(&self) -> &[MaybeUninit<T>];
+
+    /// Returns the memory as a mutable slice of possibly uninitialized
+    /// elements.
+    fn as_uninit_mut(&mut self) -> &mut [MaybeUninit<T>];
+}
+
+unsafe impl<T> Storage<T> for &mut [MaybeUninit<T>] {
+    #[inline]
+    fn as_uninit(&self) -> &[MaybeUninit<T>] { self }
+    #[inline]
+    fn as_uninit_mut(&mut self) -> &mut [MaybeUninit<T>] { self }
+}
+
+unsafe impl<T> Storage<T> for Box<[MaybeUninit<T>]> {
+    #[inline]
+    fn as_uninit(&self) -> &[MaybeUninit<T>] { self }
+    #[inline]
+    fn as_uninit_mut(&mut self) -> &mut [MaybeUninit<T>] { self }
+}
+
+unsafe impl<T, const N: usize> Storage<T> for [MaybeUninit<T>; N] {
+    #[inline]
+    fn as_uninit(&self) -> &[MaybeUninit<T>] { self }
+    #[inline]
+    fn as_uninit_mut(&mut self) -> &mut [MaybeUninit<T>] { self }
+}
+
+/// A [`StorageBank`] over borrowed memory, such as an arena allocation or a
+/// DMA buffer.
+pub type BankRef<'a, T> = StorageBank<T, &'a mut [MaybeUninit<T>]>;
+
+/// A [`StorageBank`] over a boxed slice, its capacity chosen at runtime.
+pub type BoxedBank<T> = StorageBank<T, Box<[MaybeUninit<T>]>>;
+
+
+/// A fixed-capacity bank keeping its elements in memory provided by the
+/// caller, rather than an inline array.
+/// 
+/// `StorageBank` behaves like a [`BankArr`](crate::BankArr) whose capacity is
+/// the length of its [`Storage`], and implements the [`Bank`] trait, so code
+/// written against it accepts either.  [`BankRef`] borrows its memory and
+/// [`BoxedBank`] owns a boxed slice.
+/// 
+/// # Examples
+/// ```
+/// use std::mem::MaybeUninit;
+/// use bankarr::BankRef;
+/// 
+/// let mut memory = [const { MaybeUninit::uninit() }; 4];
+/// let mut bank = BankRef::new(&mut memory[..]);
+/// bank.push("a");
+/// bank.push("b");
+/// 
+/// assert_eq!(bank.capacity(), 4);
+/// assert_eq!(bank.as_slice(), ["a", "b"]);
+/// ```
+pub struct StorageBank<T, S: Storage<T>> {
+    storage: S,
+    len: usize,
+    marker: PhantomData<T>,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T: fmt::Debug, S: Storage<T>> fmt::Debug for StorageBank<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StorageBank")
+            .field("data", &self.as_slice())
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
+
+impl<T, S: Storage<T>> Drop for StorageBank<T, S> {
+    fn drop(&mut self) { self.clear() }
+}
+
+impl<T, S: Storage<T>> Deref for StorageBank<T, S> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] { self.as_slice() }
+}
+
+impl<T, S: Storage<T>> DerefMut for StorageBank<T, S> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] { self.as_mut_slice() }
+}
+
+impl<T, S: Storage<T>> AsRef<[T]> for StorageBank<T, S> {
+    #[inline]
+    fn as_ref(&self) -> &[T] { self }
+}
+
+impl<T, S: Storage<T>> AsMut<[T]> for StorageBank<T, S> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] { self }
+}
+
+impl<T: PartialEq, S: Storage<T>> PartialEq for StorageBank<T, S> {
+    fn eq(&self, other: &Self) -> bool { self.as_slice() == other.as_slice() }
+}
+
+impl<T: PartialEq, S: Storage<T>> PartialEq<[T]> for StorageBank<T, S> {
+    fn eq(&self, other: &[T]) -> bool { self.as_slice() == other }
+}
+
+impl<T: PartialEq, S: Storage<T>, const N: usize> PartialEq<[T; N]> for StorageBank<T, S> {
+    fn eq(&self, other: &[T; N]) -> bool { self.as_slice() == other }
+}
+
+impl<T, S: Storage<T>> Extend<T> for StorageBank<T, S> {
+
+    /// # Panics
+    /// 
+    /// Panics if the new length would exceed the capacity.
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        items.into_iter().for_each(|value| self.push(value));
+    }
+}
+
+impl<'a, T, S: Storage<T>> IntoIterator for &'a StorageBank<T, S> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl<'a, T, S: Storage<T>> IntoIterator for &'a mut StorageBank<T, S> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter_mut() }
+}
+
+impl<T> BoxedBank<T> {
+
+    /// Constructs a new, empty `BoxedBank<T>` able to hold `capacity` elements.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::storage::BoxedBank;
+    /// 
+    /// let mut bank = BoxedBank::with_capacity(1024);
+    /// bank.extend(0..1024);
+    /// assert!(bank.try_push(1024).is_err());
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::new(Box::new_uninit_slice(capacity))
+    }
+}
+
+impl<T, S: Storage<T>> StorageBank<T, S> {
+
+    /// Constructs a new, empty bank over `storage`, whose length becomes the
+    /// bank's capacity.  Anything already in the storage is ignored.
+    #[inline]
+    pub const fn new(storage: S) -> Self {
+        Self { storage, len: 0, marker: PhantomData }
+    }
+
+    /// Constructs a bank over `storage` whose first `len` elements are already
+    /// initialized.
+    /// 
+    /// # Safety
+    /// 
+    /// `len` must not exceed the storage's length, and its first `len` elements
+    /// must be initialized.  The bank takes ownership of them, dropping them
+    /// along with itself.
+    #[inline]
+    pub const unsafe fn from_parts(storage: S, len: usize) -> Self {
+        Self { storage, len, marker: PhantomData }
+    }
+
+    /// Releases the storage and the length of its initialized prefix, without
+    /// dropping any elements.
+    #[inline]
+    pub fn into_parts(self) -> (S, usize) {
+        let bank = mem::ManuallyDrop::new(self);
+        // The bank is forgotten, so the storage is read out exactly once
+        (unsafe { ptr::read(&bank.storage) }, bank.len)
+    }
+
+    /// Returns the number of elements in the bank.
+    #[inline]
+    pub const fn len(&self) -> usize { self.len }
+
+    /// Returns `true` if the bank contains no elements.
+    #[inline]
+    pub const fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// Returns the number of elements the storage can hold.
+    #[inline]
+    pub fn capacity(&self) -> usize { self.storage.as_uninit().len() }
+
+    /// Returns the number of elements which can still be pushed.
+    #[inline]
+    pub fn remaining_capacity(&self) -> usize { self.capacity() - self.len }
+
+    /// Extracts a slice containing the entire bank.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        // The first `len` elements are initialized
+        unsafe { slice::from_raw_parts(self.storage.as_uninit().as_ptr().cast(), self.len) }
+    }
+
+    /// Extracts a mutable slice of the entire bank.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.storage.as_uninit_mut().as_mut_ptr().cast(), self.len) }
+    }
+
+    /// Appends an element to the back of the bank.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the bank is full.
+    #[inline]
+    #[track_caller]
+    pub fn push(&mut self, value: T) {
+        if self.try_push(value).is_err() { raw::capacity_exceeded("push", self.capacity()) }
+    }
+
+    /// Attempts to append an element to the back of the bank, handing it back
+    /// inside the error if the bank is full.
+    #[inline]
+    pub fn try_push(&mut self, value: T) -> Result<(), BankFullError<T>> {
+        match self.storage.as_uninit_mut().get_mut(self.len) {
+            Some(slot) => {
+                slot.write(value);
+                self.len += 1;
+                Ok(())
+            }
+            None => Err(BankFullError::new(value)),
+        }
+    }
+
+    /// Removes the last element of the bank and returns it, or `None` if it's
+    /// empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> { self.raw_pop() }
+
+    /// Inserts an element at `index`, shifting all elements after it to the
+    /// right.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index > len` or the bank is full.
+    #[track_caller]
+    pub fn insert(&mut self, index: usize, value: T) {
+        if index > self.len { raw::insert_out_of_bounds(index, self.len) }
+        if self.try_insert(index, value).is_err() { raw::capacity_exceeded("insert", self.capacity()) }
+    }
+
+    /// Attempts to insert an element at `index`, handing it back inside the
+    /// error on failure.
+    pub fn try_insert(&mut self, index: usize, value: T) -> Result<(), InsertError<T>> {
+        if index > self.len { return Err(InsertError::OutOfBounds(value)) }
+        if self.len == self.capacity() { return Err(InsertError::Full(value)) }
+
+        unsafe { self.raw_insert(index, value) }
+        Ok(())
+    }
+
+    /// Removes and returns the element at `index`, shifting all elements after
+    /// it to the left.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index` is out of bounds.
+    #[track_caller]
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "removal index (is {index}) should be < len (is {})", self.len);
+        unsafe { self.raw_remove(index) }
+    }
+
+    /// Removes and returns the element at `index`, shifting all elements after
+    /// it to the left, or returns `None` if `index` is out of bounds.
+    #[inline]
+    pub fn try_remove(&mut self, index: usize) -> Option<T> {
+        (index < self.len).then(|| unsafe { self.raw_remove(index) })
+    }
+
+    /// Removes and returns the element at `index`, replacing it with the last
+    /// element.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `index` is out of bounds.
+    #[track_caller]
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "swap_remove index (is {index}) should be < len (is {})", self.len);
+        unsafe { self.raw_swap_remove(index) }
+    }
+
+    /// Removes and returns the element at `index`, replacing it with the last
+    /// element, or returns `None` if `index` is out of bounds.
+    #[inline]
+    pub fn try_swap_remove(&mut self, index: usize) -> Option<T> {
+        (index < self.len).then(|| unsafe { self.raw_swap_remove(index) })
+    }
+
+    /// Retains only the elements for which `f` returns `true`, keeping their
+    /// order.
+    #[inline]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        self.raw_retain(|value| f(value))
+    }
+
+    /// Removes the elements in `range` from the bank and returns a double-ended
+    /// iterator over them.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the starting point is greater than the end point or if the end
+    /// point is greater than the length of the bank.
+    #[track_caller]
+    pub fn drain<R: ops::RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, Self> {
+        let range = drain::slice_range(range, ..self.len);
+        self.raw_drain(range)
+    }
+
+    /// Removes the elements in `range` from the bank and returns a double-ended
+    /// iterator over them, or returns `None` if the range is out of bounds.
+    pub fn try_drain<R: ops::RangeBounds<usize>>(&mut self, range: R) -> Option<Drain<'_, T, Self>> {
+        let range = drain::try_slice_range(range, ..self.len)?;
+        Some(self.raw_drain(range))
+    }
+
+    /// Moves the elements in `range` onto the back of `dst`, returning how many
+    /// were moved.  A fixed-size destination takes as many as it has room for.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the starting point is greater than the end point or if the end
+    /// point is greater than the length of the bank.
+    #[track_caller]
+    pub fn drain_into<R, D>(&mut self, range: R, dst: &mut D) -> usize
+    where
+        R: ops::RangeBounds<usize>,
+        D: DrainTarget<T>,
+    {
+        let range = drain::slice_range(range, ..self.len);
+        self.raw_drain_into(range, dst)
+    }
+
+    /// Moves the elements in `range` onto the back of `dst` as
+    /// [`drain_into`](Self::drain_into) does, or returns `None` if the range is
+    /// out of bounds.
+    pub fn try_drain_into<R, D>(&mut self, range: R, dst: &mut D) -> Option<usize>
+    where
+        R: ops::RangeBounds<usize>,
+        D: DrainTarget<T>,
+    {
+        let range = drain::try_slice_range(range, ..self.len)?;
+        Some(self.raw_drain_into(range, dst))
+    }
+
+    /// Shortens the bank to `len` elements, dropping the rest.  Has no effect
+    /// if `len` is greater than the bank's length.
+    pub fn truncate(&mut self, len: usize) { self.raw_truncate(len) }
+
+    /// Removes all elements from the bank, dropping them.
+    #[inline]
+    pub fn clear(&mut self) { self.truncate(0) }
+}
+
+impl<T: Copy, S: Storage<T>> StorageBank<T, S> {
+
+    /// Copies all elements of `other` onto the back of the bank.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `other` doesn't fit in the remaining capacity.
+    #[inline]
+    #[track_caller]
+    pub fn extend_from_slice(&mut self, other: &[T]) {
+        if self.try_extend_from_slice(other).is_err() {
+            raw::capacity_exceeded("extend_from_slice", self.capacity())
+        }
+    }
+
+    /// Attempts to copy all elements of `other` onto the back of the bank,
+    /// leaving it untouched and returning an error if `other` doesn't fit.
+    #[inline]
+    pub fn try_extend_from_slice(&mut self, other: &[T]) -> Result<(), BankFullError> {
+        if other.len() > self.remaining_capacity() { return Err(BankFullError::new(())) }
+        unsafe { self.raw_extend_from_slice(other) }
+        Ok(())
+    }
+}
+
+unsafe impl<T, S: Storage<T>> RawBank<T> for StorageBank<T, S> {
+    #[inline]
+    fn raw_ptr(&self) -> *const T { self.storage.as_uninit().as_ptr().cast() }
+
+    #[inline]
+    fn raw_parts(&mut self) -> (NonNull<T>, &mut usize, usize) {
+        let buf = self.storage.as_uninit_mut();
+        let cap = buf.len();
+        (NonNull::from(buf).cast(), &mut self.len, cap)
+    }
+}
+
+impl<T, S: Storage<T>> Bank<T> for StorageBank<T, S> {
+    type PushError = BankFullError<T>;
+
+    #[inline]
+    fn len(&self) -> usize { self.len() }
+
+    #[inline]
+    fn capacity(&self) -> usize { self.capacity() }
+
+    #[inline]
+    fn as_slice(&self) -> &[T] { self.as_slice() }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] { self.as_mut_slice() }
+
+    #[inline]
+    #[track_caller]
+    fn push(&mut self, value: T) { self.push(value) }
+
+    #[inline]
+    fn try_push(&mut self, value: T) -> Result<(), BankFullError<T>> { self.try_push(value) }
+
+    #[inline]
+    fn pop(&mut self) -> Option<T> { self.pop() }
+
+    #[inline]
+    #[track_caller]
+    fn insert(&mut self, index: usize, value: T) { self.insert(index, value) }
+
+    #[inline]
+    fn try_insert(&mut self, index: usize, value: T) -> Result<(), InsertError<T>> {
+        self.try_insert(index, value)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn remove(&mut self, index: usize) -> T { self.remove(index) }
+
+    #[inline]
+    #[track_caller]
+    fn swap_remove(&mut self, index: usize) -> T { self.swap_remove(index) }
+
+    #[inline]
+    fn truncate(&mut self, len: usize) { self.truncate(len) }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use std::rc::Rc;
+    use super::*;
+
+    #[test]
+    fn borrowed() {
+        let mut memory = [const { MaybeUninit::uninit() }; 3];
+        let mut bank = BankRef::new(&mut memory[..]);
+        bank.extend(["b", "d"].map(String::from));
+        bank.insert(0, "a".to_string());
+        assert_eq!(bank.try_push("e".to_string()).unwrap_err().element(), "e");
+        assert_eq!(bank.try_insert(1, "x".to_string()), Err(InsertError::Full("x".to_string())));
+
+        assert_eq!(bank.remove(1), "b");
+        bank.insert(1, "c".to_string());
+        assert_eq!(bank.swap_remove(0), "a");
+        assert_eq!(bank.as_slice(), ["d", "c"]);
+
+        let (memory, len) = bank.into_parts();
+        let bank = unsafe { BankRef::from_parts(memory, len) };
+        assert_eq!(bank.as_slice(), ["d", "c"]);
+    }
+
+    #[test]
+    fn boxed() {
+        let mut bank = BoxedBank::with_capacity(5);
+        bank.extend(0..5);
+        bank.truncate(2);
+        assert_eq!(bank.remaining_capacity(), 3);
+        bank.iter_mut().for_each(|value| *value *= 10);
+        assert_eq!(bank.pop(), Some(10));
+        assert_eq!(bank, [0]);
+
+        let mut inline = StorageBank::<u8, [MaybeUninit<u8>; 2]>::new([MaybeUninit::uninit(); 2]);
+        Bank::push(&mut inline, 1);
+        assert_eq!(Bank::len(&inline), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity exceeded during operation `push`: capacity is 0")]
+    fn push_full() {
+        BoxedBank::<i32>::with_capacity(0).push(1);
+    }
+
+    #[test]
+    fn drain() {
+        let mut bank = BoxedBank::with_capacity(6);
+        bank.extend_from_slice(&[1, 2, 3, 4]);
+        assert!(bank.try_extend_from_slice(&[5, 6, 7]).is_err());
+
+        assert_eq!(bank.drain(1..3).collect::<Vec<_>>(), [2, 3]);
+        assert!(bank.try_drain(..3).is_none());
+        assert_eq!(bank.try_drain(1..).unwrap().next_back(), Some(4));
+        assert_eq!(bank, [1]);
+
+        bank.extend_from_slice(&[2, 3]);
+        let mut dst = crate::BankArr::<i32, 2>::new();
+        assert_eq!(bank.drain_into(.., &mut dst), 2);
+        assert_eq!(bank.try_drain_into(2.., &mut dst), None);
+        assert_eq!((bank.as_slice(), dst.as_slice()), (&[3][..], &[1, 2][..]));
+    }
+
+    #[test]
+    fn try_remove() {
+        let mut bank = BoxedBank::with_capacity(4);
+        bank.extend(["a", "b", "c"]);
+        assert_eq!(bank.try_remove(3), None);
+        assert_eq!(bank.try_remove(0), Some("a"));
+        assert_eq!(bank.try_swap_remove(2), None);
+        assert_eq!(bank.try_swap_remove(0), Some("b"));
+        assert_eq!(bank, ["c"]);
+    }
+
+    #[test]
+    fn retain() {
+        let mut bank = BoxedBank::with_capacity(6);
+        bank.extend((0..6).map(|i| i.to_string()));
+        bank.retain(|s| s != "1" && s != "4");
+        assert_eq!(bank.as_slice(), ["0", "2", "3", "5"]);
+
+        // A panic keeps every element not yet visited
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            bank.retain(|s| match s.as_str() {
+                "2" => false,
+                "3" => panic!(),
+                _ => true,
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(bank.as_slice(), ["0", "3", "5"]);
+    }
+
+    #[test]
+    fn drops() {
+        let rc = Rc::new(());
+        let mut memory = [const { MaybeUninit::uninit() }; 4];
+        let mut bank = BankRef::new(&mut memory[..]);
+        bank.extend([rc.clone(), rc.clone(), rc.clone()]);
+        bank.truncate(1);
+        assert_eq!(Rc::strong_count(&rc), 2);
+        drop(bank);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
@@ -0,0 +1,308 @@
+use std::{fmt, mem::MaybeUninit, slice};
+
+use crate::{BankArr, BankVec, errors::BankFullError};
+
+
+/// A [`fmt::Write`] adapter appending UTF-8 onto the end of a byte bank.
+/// 
+/// Created by [`BankArr::writer`] or [`BankVec::writer`].
+/// 
+/// Each string written is all-or-nothing: when a `BankArr` lacks the room for
+/// a string, nothing is appended and [`fmt::Error`] is returned, so the bank
+/// never holds a partial UTF-8 sequence.
+#[derive(Debug)]
+pub struct FmtWriter<'a, B> {
+    bank: &'a mut B,
+}
+
+impl<const C: usize> fmt::Write for FmtWriter<'_, BankArr<u8, C>> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if s.len() > self.bank.remaining_capacity() { return Err(fmt::Error) }
+        self.bank.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+}
+
+impl<const C: usize> fmt::Write for FmtWriter<'_, BankVec<u8, C>> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.bank.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+}
+
+impl<const C: usize> BankArr<u8, C> {
+
+    /// Returns a [`fmt::Write`] adapter appending formatted UTF-8 to the bank,
+    /// so you can `write!` into it without allocating.
+    /// 
+    /// Writing fails with [`fmt::Error`] once the bank can't fit the next
+    /// string, though everything written before that is kept.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::fmt::Write;
+    /// use bankarr::BankArr;
+    /// 
+    /// let mut bank = BankArr::<u8, 8>::new();
+    /// write!(bank.writer(), "{}:{}", "ab", 80).unwrap();
+    /// assert_eq!(bank, *b"ab:80");
+    /// 
+    /// assert!(write!(bank.writer(), "{}", "toolong").is_err());
+    /// ```
+    #[inline]
+    pub fn writer(&mut self) -> FmtWriter<'_, Self> { FmtWriter { bank: self } }
+}
+
+impl<const C: usize> BankVec<u8, C> {
+
+    /// Returns a [`fmt::Write`] adapter appending formatted UTF-8 to the bank,
+    /// so you can `write!` into it.  Only allocates if the bank spills onto the
+    /// heap.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use std::fmt::Write;
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut bank = BankVec::<u8, 8>::new();
+    /// write!(bank.writer(), "{}:{}", "ab", 80).unwrap();
+    /// assert!(!bank.on_heap());
+    /// assert_eq!(bank, *b"ab:80");
+    /// ```
+    #[inline]
+    pub fn writer(&mut self) -> FmtWriter<'_, Self> { FmtWriter { bank: self } }
+}
+
+
+/// A guard lending out the uninitialized tail of a bank, for APIs which fill
+/// raw buffers, such as reads from a file descriptor or a decoder's output.
+/// 
+/// Created by [`BankArr::tail_writer`] or [`BankVec::tail_writer`].
+/// 
+/// Elements are written into [`spare`](TailWriter::spare) and then marked
+/// initialized with [`commit`](TailWriter::commit), or pushed one at a time
+/// with [`try_push`](TailWriter::try_push).  The bank's length only grows
+/// once the guard is dropped, by the number of elements committed, so a
+/// panic part way through never exposes an uninitialized element.
+/// 
+/// # Examples
+/// ```
+/// use std::mem::MaybeUninit;
+/// use bankarr::BankArr;
+/// 
+/// // Stands in for a syscall or codec writing into a raw buffer
+/// fn fill(buf: &mut [MaybeUninit<u8>]) -> usize {
+///     let msg = b"hello";
+///     let n = msg.len().min(buf.len());
+///     for (slot, &byte) in buf.iter_mut().zip(msg) {
+///         slot.write(byte);
+///     }
+///     n
+/// }
+/// 
+/// let mut bank = BankArr::<u8, 8>::from([b'>']);
+/// let mut tail = bank.tail_writer();
+/// let n = fill(tail.spare());
+/// unsafe { tail.commit(n) };
+/// drop(tail);
+/// 
+/// assert_eq!(bank, *b">hello");
+/// ```
+pub struct TailWriter<'a, T> {
+    spare: &'a mut [MaybeUninit<T>],
+    len: &'a mut usize,
+    committed: usize,
+    // A `BankVec`'s greatest length, kept up to date on drop
+    #[cfg(feature = "instrument")]
+    max_len: Option<&'a mut usize>,
+}
+
+#[cfg(not(tarpaulin_include))]
+impl<T> fmt::Debug for TailWriter<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TailWriter")
+            .field("committed", &self.committed)
+            .field("remaining", &self.remaining())
+            .finish()
+    }
+}
+
+impl<T> Drop for TailWriter<'_, T> {
+    #[inline]
+    fn drop(&mut self) {
+        *self.len += self.committed;
+        #[cfg(feature = "instrument")]
+        if let Some(max_len) = &mut self.max_len { **max_len = (**max_len).max(*self.len) }
+    }
+}
+
+impl<T> TailWriter<'_, T> {
+
+    /// Returns the uninitialized slots past those already committed.
+    #[inline]
+    pub fn spare(&mut self) -> &mut [MaybeUninit<T>] { &mut self.spare[self.committed..] }
+
+    /// Returns the number of elements committed so far.
+    #[inline]
+    pub fn committed(&self) -> usize { self.committed }
+
+    /// Returns the number of slots left to write.
+    #[inline]
+    pub fn remaining(&self) -> usize { self.spare.len() - self.committed }
+
+    /// Marks the first `n` slots of [`spare`](TailWriter::spare) initialized,
+    /// so they join the bank when the guard is dropped.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `n` exceeds [`remaining`](TailWriter::remaining).
+    /// 
+    /// # Safety
+    /// 
+    /// The first `n` slots of `spare` must have been initialized.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn commit(&mut self, n: usize) {
+        assert!(n <= self.remaining(), "commit count (is {n}) should be <= remaining (is {})", self.remaining());
+        self.committed += n;
+    }
+
+    /// Writes an element into the next slot and commits it, handing it back
+    /// inside the error if no slots remain.
+    #[inline]
+    pub fn try_push(&mut self, value: T) -> Result<(), BankFullError<T>> {
+        match self.spare.get_mut(self.committed) {
+            Some(slot) => {
+                slot.write(value);
+                self.committed += 1;
+                Ok(())
+            }
+            None => Err(BankFullError::new(value)),
+        }
+    }
+}
+
+impl<T, const C: usize> BankArr<T, C> {
+
+    /// Returns a [`TailWriter`] lending out the bank's uninitialized tail, its
+    /// remaining `C - len` slots.
+    #[inline]
+    pub fn tail_writer(&mut self) -> TailWriter<'_, T> {
+        TailWriter {
+            spare: &mut self.data[self.len..],
+            len: &mut self.len,
+            committed: 0,
+            #[cfg(feature = "instrument")]
+            max_len: None,
+        }
+    }
+}
+
+impl<T, const C: usize> BankVec<T, C> {
+
+    /// Returns a [`TailWriter`] lending out the bank's uninitialized tail, its
+    /// remaining `capacity - len` slots.  [`reserve`](BankVec::reserve) first
+    /// to write more than that.
+    /// 
+    /// # Examples
+    /// ```
+    /// use bankarr::BankVec;
+    /// 
+    /// let mut bank = BankVec::<u32, 2>::new();
+    /// bank.reserve(4);
+    /// let mut tail = bank.tail_writer();
+    /// assert!(tail.remaining() >= 4);
+    /// for i in 0..4 {
+    ///     tail.try_push(i).unwrap();
+    /// }
+    /// drop(tail);
+    /// 
+    /// assert!(bank.on_heap());
+    /// assert_eq!(bank, [0, 1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn tail_writer(&mut self) -> TailWriter<'_, T> {
+        let (ptr, &mut len, cap) = self.data_buf_mut();
+        // Everything from `len` to `cap` is allocated, and unused until the
+        // guard's drop grows the length
+        let spare = unsafe {
+            slice::from_raw_parts_mut(ptr.as_ptr().add(len).cast::<MaybeUninit<T>>(), cap - len)
+        };
+        TailWriter {
+            spare,
+            len: &mut self.len,
+            committed: 0,
+            #[cfg(feature = "instrument")]
+            max_len: Some(&mut self.stats.max_len),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+    use super::*;
+
+    #[test]
+    fn bankarr_writer() {
+        let mut bank = BankArr::<u8, 6>::new();
+        write!(bank.writer(), "é{}", 12).unwrap();
+        assert_eq!(bank, *"é12".as_bytes());
+
+        // Fits the first string but not the second
+        let umlaut = 'ü';
+        assert!(write!(bank.writer(), "{}{umlaut}", 3).is_err());
+        assert_eq!(bank, *"é123".as_bytes());
+    }
+
+    #[test]
+    fn bankvec_writer() {
+        let mut bank = BankVec::<u8, 2>::new();
+        write!(bank.writer(), "{:>4}", 7).unwrap();
+        assert!(bank.on_heap());
+        assert_eq!(bank, *b"   7");
+    }
+
+    #[test]
+    fn tail_writer() {
+        let mut bank = BankArr::<String, 3>::from(["a".to_string()]);
+        let mut tail = bank.tail_writer();
+        tail.try_push("b".to_string()).unwrap();
+        tail.spare()[0].write("c".to_string());
+        unsafe { tail.commit(1) };
+        assert_eq!(tail.remaining(), 0);
+        assert_eq!(tail.try_push("d".to_string()).unwrap_err().element(), "d");
+        drop(tail);
+        assert_eq!(bank.as_slice(), ["a", "b", "c"]);
+
+        // Uncommitted writes are ignored, and leaked
+        let mut bank = BankVec::<u8, 4>::new();
+        let mut tail = bank.tail_writer();
+        tail.spare()[0].write(1);
+        drop(tail);
+        assert!(bank.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "instrument")]
+    fn tail_writer_stats() {
+        let mut bank = BankVec::<u8, 2>::new();
+        bank.reserve(3);
+        let mut tail = bank.tail_writer();
+        (0..3).for_each(|i| tail.try_push(i).unwrap());
+        drop(tail);
+        assert_eq!(bank.stats().max_len, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "commit count (is 2) should be <= remaining (is 1)")]
+    fn commit_past_end() {
+        let mut bank = BankArr::<u8, 1>::new();
+        unsafe { bank.tail_writer().commit(2) };
+    }
+}